@@ -2,20 +2,20 @@
 
 use std::collections::BTreeMap;
 
-use fj_math::Point;
+use fj_math::{Circle, Line, Point, Scalar};
 
 use crate::{
     geometry::{
-        CurveBoundary, Geometry, GlobalPath, HalfEdgeGeom, SurfaceGeom,
-        SurfacePath,
+        CurveBoundary, Geometry, GlobalPath, HalfEdgeGeom, Helix, SurfaceGeom,
+        SurfacePath, SweepPath,
     },
     storage::Handle,
-    topology::{Curve, Surface},
+    topology::Curve,
 };
 
-use super::{Approx, ApproxPoint, Tolerance};
+use super::{path::PathApproxParams, Approx, ApproxPoint, Tolerance};
 
-impl Approx for (&Handle<Curve>, &HalfEdgeGeom, &Handle<Surface>) {
+impl Approx for (&Handle<Curve>, &HalfEdgeGeom, &SurfaceGeom) {
     type Approximation = CurveApprox;
     type Cache = CurveApproxCache;
 
@@ -32,7 +32,7 @@ impl Approx for (&Handle<Curve>, &HalfEdgeGeom, &Handle<Surface>) {
             None => {
                 let approx = approx_curve(
                     &half_edge.path,
-                    geometry.of_surface(surface),
+                    surface,
                     half_edge.boundary,
                     tolerance,
                     geometry,
@@ -57,13 +57,14 @@ fn approx_curve(
     // This will probably all be unified eventually, as `SurfacePath` and
     // `GlobalPath` grow APIs that are better suited to implementing this code
     // in a more abstract way.
-    let points = match (path, surface.u) {
-        (SurfacePath::Circle(_), GlobalPath::Circle(_)) => {
-            todo!(
-                "Approximating a circle on a curved surface not supported yet."
-            )
-        }
-        (SurfacePath::Circle(_), GlobalPath::Line(_)) => {
+    let points = match (path, surface) {
+        (
+            SurfacePath::Circle(_),
+            SurfaceGeom::Swept {
+                u: GlobalPath::Line(_),
+                v: SweepPath::Line(_),
+            },
+        ) => {
             (path, boundary)
                 .approx_with_cache(tolerance, &mut (), geometry)
                 .into_iter()
@@ -90,17 +91,59 @@ fn approx_curve(
                 })
                 .collect()
         }
-        (SurfacePath::Line(line), _) => {
+        (SurfacePath::Circle(circle), _) => {
+            PathApproxParams::for_circle_on_surface(circle, surface, tolerance)
+                .points(boundary)
+                .map(|point_curve| {
+                    let point_surface =
+                        circle.point_from_circle_coords(point_curve);
+                    let point_global =
+                        surface.point_from_surface_coords(point_surface);
+                    (point_curve, point_global)
+                })
+                .collect()
+        }
+        (SurfacePath::Spline(spline), _) => {
+            PathApproxParams::for_spline_on_surface(spline, surface, tolerance)
+                .points(boundary)
+                .map(|point_curve| {
+                    let point_surface =
+                        spline.point_from_spline_coords(point_curve);
+                    let point_global =
+                        surface.point_from_surface_coords(point_surface);
+                    (point_curve, point_global)
+                })
+                .collect()
+        }
+        (
+            SurfacePath::Line(line),
+            SurfaceGeom::Swept {
+                v: SweepPath::Helix(helix),
+                ..
+            },
+        ) if line.direction().u == Scalar::ZERO => {
+            approx_line_along_helix(line, surface, helix, boundary, tolerance)
+        }
+        (
+            SurfacePath::Line(line),
+            SurfaceGeom::Swept {
+                v: SweepPath::Helix(_),
+                ..
+            },
+        ) if line.direction().v != Scalar::ZERO => {
+            todo!(
+                "Approximating a line on a helical surface is only supported, \
+                if that line is parallel to one of the surface's axes."
+            )
+        }
+        (SurfacePath::Line(line), SurfaceGeom::Swept { u, .. }) => {
             let range_u =
                 CurveBoundary::from(boundary.inner.map(|point_curve| {
                     [path.point_from_path_coords(point_curve).u]
                 }));
 
-            let approx_u = (surface.u, range_u).approx_with_cache(
-                tolerance,
-                &mut (),
-                geometry,
-            );
+            let approx_u =
+                (u, range_u).approx_with_cache(tolerance, &mut (), geometry);
 
             let mut points = Vec::new();
             for (u, _) in approx_u {
@@ -113,6 +156,16 @@ fn approx_curve(
 
             points
         }
+        (SurfacePath::Line(line), SurfaceGeom::Ruled { a, b }) => {
+            approx_line_on_ruled_surface(
+                line,
+                surface,
+                [a, b],
+                boundary,
+                tolerance,
+                geometry,
+            )
+        }
     };
 
     let points = points
@@ -124,6 +177,117 @@ fn approx_curve(
     CurveApprox { points }
 }
 
+/// Approximate a line that is parallel to the v-axis of a helical surface
+///
+/// Such a line is a helix (or a circle arc) in global coordinates. It deviates
+/// from its chords just like a circle around the helix's axis would.
+fn approx_line_along_helix(
+    line: &Line<2>,
+    surface: &SurfaceGeom,
+    helix: &Helix,
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: impl Into<Tolerance>,
+) -> Vec<(Point<1>, Point<3>)> {
+    let tolerance = tolerance.into();
+
+    let radius = helix
+        .distance_from_axis(surface.point_from_surface_coords(line.origin()));
+    if radius <= tolerance.inner() || helix.angle() == Scalar::ZERO {
+        // The line is close enough to the axis, to be considered straight, or
+        // doesn't rotate around it at all.
+        return Vec::new();
+    }
+
+    // The approximation of a circle with the same radius defines which points
+    // we're going to use. These are in terms of the helix' angle.
+    let circle = Circle::from_center_and_radius(Point::<2>::origin(), radius);
+    let params = PathApproxParams::for_circle(&circle, tolerance);
+
+    let angle_from_curve_coords = |point: Point<1>| {
+        (line.origin().v + line.direction().v * point.t) * helix.angle()
+    };
+    let range = CurveBoundary::from(
+        boundary
+            .inner
+            .map(|point_curve| [angle_from_curve_coords(point_curve)]),
+    );
+
+    params
+        .points(range)
+        .map(|angle| {
+            let t = (angle.t / helix.angle() - line.origin().v)
+                / line.direction().v;
+            let point_curve = Point::from([t]);
+            let point_global = surface.point_from_surface_coords(
+                line.point_from_line_coords(point_curve),
+            );
+
+            (point_curve, point_global)
+        })
+        .collect()
+}
+
+/// Approximate a line on a ruled surface
+///
+/// Lines along the v-axis are straight in global coordinates, so they don't
+/// need to be approximated. Lines along the u-axis are blended from the two
+/// paths that define the surface. Approximating them at the points where those
+/// paths are approximated, keeps them within the tolerance.
+fn approx_line_on_ruled_surface(
+    line: &Line<2>,
+    surface: &SurfaceGeom,
+    paths: [&GlobalPath; 2],
+    boundary: CurveBoundary<Point<1>>,
+    tolerance: impl Into<Tolerance>,
+    geometry: &Geometry,
+) -> Vec<(Point<1>, Point<3>)> {
+    let tolerance = tolerance.into();
+
+    if line.direction().u == Scalar::ZERO {
+        return Vec::new();
+    }
+    if line.direction().v != Scalar::ZERO {
+        todo!(
+            "Approximating a line on a ruled surface is only supported, if \
+            that line is parallel to one of the surface's axes."
+        )
+    }
+
+    let range_u = CurveBoundary::from(
+        boundary
+            .inner
+            .map(|point_curve| [line.point_from_line_coords(point_curve).u]),
+    );
+
+    let mut points_u = paths
+        .into_iter()
+        .flat_map(|path| {
+            (path, range_u).approx_with_cache(tolerance, &mut (), geometry)
+        })
+        .map(|(point_path, _)| point_path.t)
+        .collect::<Vec<_>>();
+    points_u.sort();
+    points_u.dedup();
+
+    // The points must be ordered according to the direction of the boundary.
+    if !range_u.is_normalized() {
+        points_u.reverse();
+    }
+
+    points_u
+        .into_iter()
+        .map(|u| {
+            let t = (u - line.origin().u) / line.direction().u;
+            let point_curve = Point::from([t]);
+            let point_global = surface.point_from_surface_coords(
+                line.point_from_line_coords(point_curve),
+            );
+
+            (point_curve, point_global)
+        })
+        .collect()
+}
+
 /// Approximation of [`Curve`], within a specific boundary
 #[derive(Clone)]
 pub struct CurveApprox {
@@ -195,13 +359,20 @@ mod tests {
         let surface = core.layers.topology.surfaces.xz_plane();
         let (path, boundary) =
             SurfacePath::line_from_points([[1., 1.], [2., 1.]]);
-        let curve =
-            Curve::from_path_and_surface(path, surface.clone(), &mut core);
+        let curve = Curve::from_path_and_surface(
+            path.clone(),
+            surface.clone(),
+            &mut core,
+        );
         let boundary = CurveBoundary::from(boundary);
         let half_edge = HalfEdgeGeom { path, boundary };
 
         let tolerance = 1.;
-        let approx = (&curve, &half_edge, &surface)
+        let approx = (
+            &curve,
+            &half_edge,
+            core.layers.geometry.of_surface(&surface),
+        )
             .approx(tolerance, &core.layers.geometry);
 
         assert_eq!(approx.points, vec![]);
@@ -218,13 +389,20 @@ mod tests {
         );
         let (path, boundary) =
             SurfacePath::line_from_points([[1., 1.], [2., 1.]]);
-        let curve =
-            Curve::from_path_and_surface(path, surface.clone(), &mut core);
+        let curve = Curve::from_path_and_surface(
+            path.clone(),
+            surface.clone(),
+            &mut core,
+        );
         let boundary = CurveBoundary::from(boundary);
         let half_edge = HalfEdgeGeom { path, boundary };
 
         let tolerance = 1.;
-        let approx = (&curve, &half_edge, &surface)
+        let approx = (
+            &curve,
+            &half_edge,
+            core.layers.geometry.of_surface(&surface),
+        )
             .approx(tolerance, &core.layers.geometry);
 
         assert_eq!(approx.points, vec![]);
@@ -235,21 +413,32 @@ mod tests {
         let mut core = Core::new();
 
         let global_path = GlobalPath::circle_from_radius(1.);
-        let surface = Surface::from_uv(global_path, [0., 0., 1.], &mut core);
+        let surface =
+            Surface::from_uv(global_path.clone(), [0., 0., 1.], &mut core);
         let path = SurfacePath::line_from_points_with_coords([
             ([0.], [0., 1.]),
             ([TAU], [TAU, 1.]),
         ]);
-        let curve =
-            Curve::from_path_and_surface(path, surface.clone(), &mut core);
+        let curve = Curve::from_path_and_surface(
+            path.clone(),
+            surface.clone(),
+            &mut core,
+        );
         let boundary = CurveBoundary::from([[0.], [TAU]]);
-        let half_edge = HalfEdgeGeom { path, boundary };
+        let half_edge = HalfEdgeGeom {
+            path: path.clone(),
+            boundary,
+        };
 
         let tolerance = 1.;
-        let approx = (&curve, &half_edge, &surface)
+        let approx = (
+            &curve,
+            &half_edge,
+            core.layers.geometry.of_surface(&surface),
+        )
             .approx(tolerance, &core.layers.geometry);
 
-        let expected_approx = (global_path, boundary)
+        let expected_approx = (&global_path, boundary)
             .approx(tolerance, &core.layers.geometry)
             .into_iter()
             .map(|(point_local, _)| {
@@ -271,13 +460,23 @@ mod tests {
 
         let surface = core.layers.topology.surfaces.xz_plane();
         let path = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
-        let curve =
-            Curve::from_path_and_surface(path, surface.clone(), &mut core);
+        let curve = Curve::from_path_and_surface(
+            path.clone(),
+            surface.clone(),
+            &mut core,
+        );
         let boundary = CurveBoundary::from([[0.], [TAU]]);
-        let half_edge = HalfEdgeGeom { path, boundary };
+        let half_edge = HalfEdgeGeom {
+            path: path.clone(),
+            boundary,
+        };
 
         let tolerance = 1.;
-        let approx = (&curve, &half_edge, &surface)
+        let approx = (
+            &curve,
+            &half_edge,
+            core.layers.geometry.of_surface(&surface),
+        )
             .approx(tolerance, &core.layers.geometry);
 
         let expected_approx = (&path, boundary)
@@ -295,4 +494,62 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(approx.points, expected_approx);
     }
+
+    #[test]
+    fn approx_circle_on_curved_surface() {
+        let mut core = Core::new();
+
+        let surface = Surface::from_uv(
+            GlobalPath::circle_from_radius(1.),
+            [0., 0., 1.],
+            &mut core,
+        );
+        let path = SurfacePath::circle_from_center_and_radius([1., 1.], 0.5);
+        let curve = Curve::from_path_and_surface(
+            path.clone(),
+            surface.clone(),
+            &mut core,
+        );
+        let boundary = CurveBoundary::from([[0.], [TAU]]);
+        let half_edge = HalfEdgeGeom {
+            path: path.clone(),
+            boundary,
+        };
+
+        let tolerance = 0.01;
+        let approx = (
+            &curve,
+            &half_edge,
+            core.layers.geometry.of_surface(&surface),
+        )
+            .approx(tolerance, &core.layers.geometry);
+
+        // The points of the approximation must be on the curve, and the
+        // chords between them must not deviate from it by more than the
+        // tolerance.
+        let surface = core.layers.geometry.of_surface(&surface);
+        let point_global = |t: f64| {
+            surface.point_from_surface_coords(path.point_from_path_coords([t]))
+        };
+
+        let mut points = vec![(0., point_global(0.))];
+        points.extend(
+            approx.points.iter().map(|point| {
+                (point.local_form.t.into_f64(), point.global_form)
+            }),
+        );
+        points.push((TAU, point_global(TAU)));
+
+        assert!(points.len() > 3);
+        for window in points.windows(2) {
+            let [(t_a, a), (t_b, b)] = [window[0], window[1]];
+
+            assert!((point_global(t_a) - a).magnitude() < 1e-12.into());
+
+            let deviation = (point_global((t_a + t_b) / 2.)
+                - (a + (b - a) / 2.))
+                .magnitude();
+            assert!(deviation <= tolerance.into());
+        }
+    }
 }
@@ -5,9 +5,8 @@
 use fj_math::Segment;
 
 use crate::{
-    geometry::Geometry,
-    storage::Handle,
-    topology::{Cycle, Surface},
+    geometry::{Geometry, SurfaceGeom},
+    topology::Cycle,
 };
 
 use super::{
@@ -15,7 +14,7 @@ use super::{
     Approx, ApproxPoint, Tolerance,
 };
 
-impl Approx for (&Cycle, &Handle<Surface>) {
+impl Approx for (&Cycle, &SurfaceGeom) {
     type Approximation = CycleApprox;
     type Cache = HalfEdgeApproxCache;
 
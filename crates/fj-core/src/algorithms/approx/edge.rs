@@ -6,9 +6,9 @@
 //! the caller doesn't have to deal with duplicate vertices.
 
 use crate::{
-    geometry::Geometry,
+    geometry::{Geometry, SurfaceGeom},
     storage::Handle,
-    topology::{HalfEdge, Surface},
+    topology::HalfEdge,
 };
 
 use super::{
@@ -16,7 +16,7 @@ use super::{
     Tolerance,
 };
 
-impl Approx for (&Handle<HalfEdge>, &Handle<Surface>) {
+impl Approx for (&Handle<HalfEdge>, &SurfaceGeom) {
     type Approximation = HalfEdgeApprox;
     type Cache = HalfEdgeApproxCache;
 
@@ -35,8 +35,7 @@ impl Approx for (&Handle<HalfEdge>, &Handle<Surface>) {
             match cache.start_position.get(half_edge.start_vertex()) {
                 Some(position) => position,
                 None => {
-                    let position_global = geometry
-                        .of_surface(surface)
+                    let position_global = surface
                         .point_from_surface_coords(start_position_surface);
                     cache.start_position.insert(
                         half_edge.start_vertex().clone(),
@@ -4,16 +4,19 @@
 
 use std::{collections::BTreeSet, ops::Deref};
 
+use fj_math::{Circle, Point, Scalar, Winding};
+
 use crate::{
-    geometry::Geometry,
+    algorithms::triangulate::polygon::Polygon,
+    geometry::{CurveBoundary, Geometry, GlobalPath, SurfaceGeom, SweepPath},
     storage::Handle,
-    topology::{Face, Handedness, ObjectSet},
+    topology::{Face, Handedness, ObjectSet, Region},
     validation::ValidationConfig,
 };
 
 use super::{
-    cycle::CycleApprox, edge::HalfEdgeApproxCache, Approx, ApproxPoint,
-    Tolerance,
+    cycle::CycleApprox, edge::HalfEdgeApproxCache, path::PathApproxParams,
+    Approx, ApproxPoint, Tolerance,
 };
 
 impl Approx for &ObjectSet<Face> {
@@ -75,36 +78,72 @@ impl Approx for Handle<Face> {
         cache: &mut Self::Cache,
         geometry: &Geometry,
     ) -> Self::Approximation {
+        let RegionApprox {
+            region: _,
+            exterior,
+            interiors,
+            surface_points,
+            coord_handedness,
+        } = (self.region(), geometry.of_surface(self.surface()))
+            .approx_with_cache(tolerance, cache, geometry);
+
+        FaceApprox {
+            face: self,
+            exterior,
+            interiors,
+            surface_points,
+            coord_handedness,
+        }
+    }
+}
+
+impl Approx for (&Handle<Region>, &SurfaceGeom) {
+    type Approximation = RegionApprox;
+    type Cache = HalfEdgeApproxCache;
+
+    fn approx_with_cache(
+        self,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
+        geometry: &Geometry,
+    ) -> Self::Approximation {
+        let (region, surface) = self;
         let tolerance = tolerance.into();
 
-        // Curved faces whose curvature is not fully defined by their edges
-        // are not supported yet. For that reason, we can fully ignore `face`'s
-        // `surface` field and just pass the edges to `Self::for_edges`.
+        // For most faces, the curvature is fully defined by their edges. An
+        // example of that is the cylinder. Its curvature is fully defined be
+        // the edges (circles) that border it. The circle approximations are
+        // sufficient to triangulate the surface.
         //
-        // An example of a curved face that is supported, is the cylinder. Its
-        // curvature is fully defined be the edges (circles) that border it. The
-        // circle approximations are sufficient to triangulate the surface.
-        //
-        // An example of a curved face that is currently not supported, and thus
-        // doesn't need to be handled here, is a sphere. A spherical face would
-        // would need to provide its own approximation, as the edges that bound
-        // it have nothing to do with its curvature.
+        // Faces on surfaces that are curved in two directions, like the ones
+        // created by sweeping a circle along a circle arc, are different. The
+        // edges that bound them have little to do with their curvature, so we
+        // need additional points within the face. Those are computed below,
+        // after the edges have been approximated.
 
-        let exterior = (self.region().exterior().deref(), self.surface())
+        let exterior = (region.exterior().deref(), surface)
             .approx_with_cache(tolerance, cache, geometry);
 
         let mut interiors = BTreeSet::new();
-        for cycle in self.region().interiors() {
-            let cycle = (cycle.deref(), self.surface())
+        for cycle in region.interiors() {
+            let cycle = (cycle.deref(), surface)
                 .approx_with_cache(tolerance, cache, geometry);
             interiors.insert(cycle);
         }
 
-        let coord_handedness = self.coord_handedness(geometry);
-        FaceApprox {
-            face: self,
+        let surface_points = approx_surface_within_face(
+            surface, &exterior, &interiors, tolerance, geometry,
+        );
+
+        let coord_handedness = match region.exterior().winding(geometry) {
+            Winding::Ccw => Handedness::RightHanded,
+            Winding::Cw => Handedness::LeftHanded,
+        };
+        RegionApprox {
+            region: region.clone(),
             exterior,
             interiors,
+            surface_points,
             coord_handedness,
         }
     }
@@ -122,6 +161,12 @@ pub struct FaceApprox {
     /// Approximations of the interior cycles
     pub interiors: BTreeSet<CycleApprox>,
 
+    /// Additional points within the face, that approximate its surface
+    ///
+    /// This is empty, unless the face is located on a surface that is curved
+    /// in two directions.
+    pub surface_points: BTreeSet<ApproxPoint<2>>,
+
     /// The handedness of the approximated face's front-side coordinate system
     pub coord_handedness: Handedness,
 }
@@ -137,6 +182,190 @@ impl FaceApprox {
             points.extend(cycle_approx.points());
         }
 
+        points.extend(self.surface_points.iter().copied());
+
         points
     }
 }
+
+/// An approximation of a [`Region`] on a surface
+///
+/// This is used to approximate the regions of a [`Sketch`], which aren't part
+/// of a [`Face`].
+///
+/// [`Sketch`]: crate::topology::Sketch
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct RegionApprox {
+    /// The [`Region`], that this approximates
+    pub region: Handle<Region>,
+
+    /// Approximation of the exterior cycle
+    pub exterior: CycleApprox,
+
+    /// Approximations of the interior cycles
+    pub interiors: BTreeSet<CycleApprox>,
+
+    /// Additional points within the region, that approximate its surface
+    ///
+    /// This is empty, unless the region is located on a surface that is curved
+    /// in two directions.
+    pub surface_points: BTreeSet<ApproxPoint<2>>,
+
+    /// The handedness of the approximated region's coordinate system
+    pub coord_handedness: Handedness,
+}
+
+/// Approximate the surface of a face, within the face's boundary
+///
+/// Only faces on surfaces, whose curvature is not defined by their edges, need
+/// this. Those are helical surfaces with a curved u-axis, and ruled surfaces
+/// that are not flat. For those, we create a grid of points within the face.
+fn approx_surface_within_face(
+    surface: &SurfaceGeom,
+    exterior: &CycleApprox,
+    interiors: &BTreeSet<CycleApprox>,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> BTreeSet<ApproxPoint<2>> {
+    let boundary = exterior.points();
+    let Some(first) = boundary.first() else {
+        return BTreeSet::new();
+    };
+
+    let mut min = first.local_form;
+    let mut max = first.local_form;
+
+    for point in &boundary {
+        min = Point::from([
+            min.u.min(point.local_form.u),
+            min.v.min(point.local_form.v),
+        ]);
+        max = Point::from([
+            max.u.max(point.local_form.u),
+            max.v.max(point.local_form.v),
+        ]);
+    }
+
+    let (points_u, points_v) = match surface {
+        SurfaceGeom::Swept {
+            u:
+                u @ (GlobalPath::Circle(_)
+                | GlobalPath::Ellipse(_)
+                | GlobalPath::Spline(_)),
+            v: SweepPath::Helix(helix),
+        } => {
+            let radius = boundary
+                .iter()
+                .map(|point| helix.distance_from_axis(point.global_form))
+                .max()
+                .unwrap_or(Scalar::ZERO);
+
+            if radius <= tolerance.inner() || helix.angle() == Scalar::ZERO {
+                // The surface is not actually curved in the direction of the
+                // helix.
+                return BTreeSet::new();
+            }
+
+            let points_u = (u, CurveBoundary::from([[min.u], [max.u]]))
+                .approx(tolerance, geometry)
+                .into_iter()
+                .map(|(point, _)| point.t)
+                .collect::<Vec<_>>();
+
+            // Points move around the helix' axis on a circle (if we ignore
+            // that they might also move along the axis, which doesn't affect
+            // the approximation). The largest of those circles determines the
+            // points we need along the v-axis.
+            let points_v = {
+                let circle = Circle::from_center_and_radius(
+                    Point::<2>::origin(),
+                    radius,
+                );
+                PathApproxParams::for_circle(&circle, tolerance)
+                    .points([[min.v * helix.angle()], [max.v * helix.angle()]])
+                    .map(|angle| angle.t / helix.angle())
+                    .collect::<Vec<_>>()
+            };
+
+            (points_u, points_v)
+        }
+        SurfaceGeom::Ruled { a, b } => {
+            let num_points = match (a, b) {
+                (GlobalPath::Line(a), GlobalPath::Line(b)) => {
+                    // If the lines don't lie within a common plane, the
+                    // surface is twisted. Its deviation from a flat triangle
+                    // depends on the size of the triangle, which we choose
+                    // accordingly.
+                    let normal =
+                        a.direction().cross(&(b.origin() - a.origin()));
+                    if normal.magnitude() == Scalar::ZERO {
+                        return BTreeSet::new();
+                    }
+
+                    let twist = (b.direction() - a.direction())
+                        .dot(&normal.normalize())
+                        .abs()
+                        * (max.u - min.u);
+                    if twist <= tolerance.inner() {
+                        return BTreeSet::new();
+                    }
+
+                    let num_points =
+                        (twist / (tolerance.inner() * 4.)).into_f64().sqrt();
+                    Scalar::from(num_points).ceil()
+                }
+                _ => {
+                    let mut points_u = [a, b]
+                        .into_iter()
+                        .flat_map(|path| {
+                            (path, CurveBoundary::from([[min.u], [max.u]]))
+                                .approx(tolerance, geometry)
+                        })
+                        .map(|(point, _)| point.t)
+                        .collect::<Vec<_>>();
+                    points_u.sort();
+                    points_u.dedup();
+
+                    Scalar::from_u64(points_u.len() as u64) + 1.
+                }
+            };
+
+            // Placing the points in a regular grid makes sure, that the
+            // triangles follow the straight lines along the v-axis.
+            let points = |min: Scalar, max: Scalar| {
+                (1..num_points.into_u64())
+                    .map(|i| {
+                        min + (max - min) * Scalar::from_u64(i) / num_points
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            (points(min.u, max.u), points(min.v, max.v))
+        }
+        _ => {
+            return BTreeSet::new();
+        }
+    };
+
+    let polygon = Polygon::new()
+        .with_exterior(boundary.iter().map(|point| point.local_form))
+        .with_interiors(interiors.iter().map(|interior| {
+            interior.points().into_iter().map(|point| point.local_form)
+        }));
+
+    let mut points = BTreeSet::new();
+
+    for &u in &points_u {
+        for &v in &points_v {
+            let point_surface = Point::from([u, v]);
+
+            if polygon.contains_point(point_surface) {
+                let point_global =
+                    surface.point_from_surface_coords(point_surface);
+                points.insert(ApproxPoint::new(point_surface, point_global));
+            }
+        }
+    }
+
+    points
+}
@@ -30,9 +30,11 @@
 
 use std::iter;
 
-use fj_math::{Circle, Point, Scalar, Sign};
+use fj_math::{Circle, Ellipse, Point, Scalar, Sign, Spline};
 
-use crate::geometry::{CurveBoundary, Geometry, GlobalPath, SurfacePath};
+use crate::geometry::{
+    CurveBoundary, Geometry, GlobalPath, SurfaceGeom, SurfacePath,
+};
 
 use super::{Approx, Tolerance};
 
@@ -53,11 +55,14 @@ impl Approx for (&SurfacePath, CurveBoundary<Point<1>>) {
                 approx_circle(circle, range, tolerance.into())
             }
             SurfacePath::Line(_) => vec![],
+            SurfacePath::Spline(spline) => {
+                approx_spline(spline, range, tolerance.into())
+            }
         }
     }
 }
 
-impl Approx for (GlobalPath, CurveBoundary<Point<1>>) {
+impl Approx for (&GlobalPath, CurveBoundary<Point<1>>) {
     type Approximation = Vec<(Point<1>, Point<3>)>;
     type Cache = ();
 
@@ -71,9 +76,15 @@ impl Approx for (GlobalPath, CurveBoundary<Point<1>>) {
 
         match path {
             GlobalPath::Circle(circle) => {
-                approx_circle(&circle, range, tolerance.into())
+                approx_circle(circle, range, tolerance.into())
+            }
+            GlobalPath::Ellipse(ellipse) => {
+                approx_ellipse(ellipse, range, tolerance.into())
             }
             GlobalPath::Line(_) => vec![],
+            GlobalPath::Spline(spline) => {
+                approx_spline(spline, range, tolerance.into())
+            }
         }
     }
 }
@@ -100,7 +111,55 @@ fn approx_circle<const D: usize>(
     points
 }
 
-struct PathApproxParams {
+/// Approximate an ellipse
+///
+/// Uses the same approximation as a circle, whose radius is the largest radius
+/// of the ellipse. That circle's curvature, in relation to the path
+/// coordinate, is an upper bound for the ellipse's.
+fn approx_ellipse(
+    ellipse: &Ellipse<3>,
+    boundary: impl Into<CurveBoundary<Point<1>>>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<3>)> {
+    let boundary = boundary.into();
+
+    let circle = Circle::from_center_and_radius(
+        Point::<2>::origin(),
+        ellipse.max_radius(),
+    );
+    let params = PathApproxParams::for_circle(&circle, tolerance);
+
+    params
+        .points(boundary)
+        .map(|point_curve| {
+            (point_curve, ellipse.point_from_ellipse_coords(point_curve))
+        })
+        .collect()
+}
+
+/// Approximate a spline
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the spline.
+fn approx_spline<const D: usize>(
+    spline: &Spline<D>,
+    boundary: impl Into<CurveBoundary<Point<1>>>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<D>)> {
+    let boundary = boundary.into();
+
+    let params = PathApproxParams::for_spline(spline, tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(boundary) {
+        let point_global = spline.point_from_spline_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
+pub(super) struct PathApproxParams {
     increment: Scalar,
 }
 
@@ -123,17 +182,154 @@ impl PathApproxParams {
         Self { increment }
     }
 
+    /// Compute the parameters for approximating a circle on a curved surface
+    ///
+    /// In global coordinates, such a circle is no longer a circle, and how much
+    /// it bends depends on the surface. The increment is derived from the
+    /// strongest bend anywhere along the full circle, which keeps the
+    /// approximation deterministic, regardless of the range that is being
+    /// approximated.
+    pub fn for_circle_on_surface(
+        circle: &Circle<2>,
+        surface: &SurfaceGeom,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        Self::from_second_derivative(
+            [Scalar::ZERO, Scalar::TAU],
+            360,
+            3,
+            |t| {
+                surface.point_from_surface_coords(
+                    circle.point_from_circle_coords([t]),
+                )
+            },
+            tolerance,
+        )
+    }
+
+    /// Compute the parameters for approximating a spline
+    ///
+    /// Like with circles on curved surfaces, the increment is derived from the
+    /// strongest bend anywhere within the domain of the spline.
+    pub fn for_spline<const D: usize>(
+        spline: &Spline<D>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        Self::from_second_derivative(
+            spline.domain(),
+            num_spline_samples(spline),
+            spline.control_points().len() - 1,
+            |t| spline.point_from_spline_coords([t]),
+            tolerance,
+        )
+    }
+
+    /// Compute the parameters for approximating a spline on a curved surface
+    pub fn for_spline_on_surface(
+        spline: &Spline<2>,
+        surface: &SurfaceGeom,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        Self::from_second_derivative(
+            spline.domain(),
+            num_spline_samples(spline),
+            spline.control_points().len() - 1,
+            |t| {
+                surface.point_from_surface_coords(
+                    spline.point_from_spline_coords([t]),
+                )
+            },
+            tolerance,
+        )
+    }
+
+    /// Compute the parameters from the second derivative of a curve
+    ///
+    /// Samples the curve within the provided range, to estimate the largest
+    /// magnitude of its second derivative. The range is then divided into
+    /// equal increments, no fewer than `min_increments`, that are small enough
+    /// to stay within the tolerance.
+    fn from_second_derivative<const D: usize>(
+        range: [Scalar; 2],
+        num_samples: usize,
+        min_increments: usize,
+        point_global: impl Fn(Scalar) -> Point<D>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        let [start, end] = range;
+        let length = end - start;
+
+        // Estimate the magnitude of the second derivative of the curve in
+        // global coordinates, using finite differences.
+        let step = length / num_samples as f64;
+        let max_second_derivative = (0..num_samples)
+            .map(|i| {
+                let t = start + step * i as f64;
+                let [a, b, c] = [t - step, t, t + step].map(&point_global);
+
+                ((a - b) + (c - b)).magnitude() / (step * step)
+            })
+            .max()
+            .unwrap_or(Scalar::ZERO);
+
+        // A chord of length `h` deviates from the curve by no more than
+        // `max_second_derivative * h² / 8`.
+        let min_increments = Scalar::from(min_increments as f64);
+        let num_increments = if max_second_derivative > Scalar::ZERO {
+            let max_increment = Scalar::from(
+                (tolerance.into().inner() * 8. / max_second_derivative)
+                    .into_f64()
+                    .sqrt(),
+            );
+            Scalar::max(length / max_increment, min_increments).ceil()
+        } else {
+            min_increments
+        };
+
+        let increment = length / num_increments;
+
+        Self { increment }
+    }
+
+    /// The precision of a path coordinate, when converted into increments
+    ///
+    /// This is relative to the number of increments (or `1`, for boundaries
+    /// close to zero), and covers the rounding errors that typically occur
+    /// when computing boundaries and increments.
+    const RELATIVE_PRECISION: f64 = f64::EPSILON * 16.;
+
     pub fn increment(&self) -> Scalar {
         self.increment
     }
 
+    /// Convert a path coordinate into a multiple of the increment
+    ///
+    /// Boundaries that are meant to be located on a point of the
+    /// approximation (like the end of a full circle) are often off by a few
+    /// bits, due to numerical inaccuracy. Without correction, that would result
+    /// in an additional point right next to the boundary. To prevent this, the
+    /// result is rounded to the nearest whole multiple, if it is within
+    /// [`Self::RELATIVE_PRECISION`] of it.
+    fn to_increments(&self, t: Scalar) -> Scalar {
+        let increments = t / self.increment();
+        let rounded = increments.round();
+
+        let precision =
+            increments.abs().max(Scalar::ONE) * Self::RELATIVE_PRECISION;
+        if (increments - rounded).abs() <= precision {
+            rounded
+        } else {
+            increments
+        }
+    }
+
     pub fn points(
         &self,
         boundary: impl Into<CurveBoundary<Point<1>>>,
     ) -> impl Iterator<Item = Point<1>> + '_ {
         let boundary = boundary.into();
 
-        let [a, b] = boundary.inner.map(|point| point.t / self.increment());
+        let [a, b] = boundary.inner.map(|point| self.to_increments(point.t));
         let direction = (b - a).sign();
         let [min, max] = if a < b { [a, b] } else { [b, a] };
 
@@ -166,13 +362,35 @@ impl PathApproxParams {
     }
 }
 
+/// The number of samples used to estimate the bend of a spline
+///
+/// Kinks, where the spline is less smooth, can only be located at knots. The
+/// samples are spread over the spans between the knots, to not miss any.
+fn num_spline_samples<const D: usize>(spline: &Spline<D>) -> usize {
+    const SAMPLES_PER_SPAN: usize = 64;
+
+    let [start, end] = spline.domain();
+    let num_spans = spline
+        .knots()
+        .windows(2)
+        .filter(|knots| knots[0] >= start && knots[1] <= end)
+        .filter(|knots| knots[0] < knots[1])
+        .count();
+
+    num_spans * SAMPLES_PER_SPAN
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::TAU;
 
-    use fj_math::{Circle, Point, Scalar};
+    use fj_math::{Circle, Point, Scalar, Spline};
 
-    use crate::algorithms::approx::{path::CurveBoundary, Tolerance};
+    use crate::{
+        algorithms::approx::{path::CurveBoundary, Approx, Tolerance},
+        geometry::GlobalPath,
+        Core,
+    };
 
     use super::PathApproxParams;
 
@@ -221,6 +439,12 @@ mod tests {
         test_path([[TAU], [2.]], [3., 2.]);
         test_path([[TAU - 2.], [0.]], [2., 1.]);
 
+        // Boundaries that are a few bits off, due to numerical inaccuracy,
+        // must not result in points right next to them.
+        let tau = TAU * (1. + f64::EPSILON * 2.);
+        test_path([[-f64::EPSILON], [tau]], [1., 2., 3.]);
+        test_path([[tau], [-f64::EPSILON]], [3., 2., 1.]);
+
         fn test_path(
             boundary: impl Into<CurveBoundary<Point<1>>>,
             expected_coords: impl IntoIterator<Item = impl Into<Scalar>>,
@@ -243,4 +467,38 @@ mod tests {
             assert_eq!(points, expected_points);
         }
     }
+
+    #[test]
+    fn approx_spline() {
+        let spline = Spline::from_weighted_control_points(
+            3,
+            [
+                ([0., 0., 0.], 1.),
+                ([1., 2., 0.], 2.),
+                ([2., -1., 1.], 1.),
+                ([3., 1., 0.], 0.5),
+                ([4., 0., 0.], 1.),
+            ],
+        );
+        let path = GlobalPath::Spline(spline.clone());
+
+        let tolerance = Scalar::from(0.01);
+        let core = Core::new();
+        let approx = (&path, CurveBoundary::from([[0.], [1.]]))
+            .approx(tolerance, &core.layers.geometry);
+
+        let mut coords = vec![Scalar::ZERO];
+        coords.extend(approx.iter().map(|(point, _)| point.t));
+        coords.push(Scalar::ONE);
+
+        for coords in coords.windows(2) {
+            let [a, b] = [coords[0], coords[1]]
+                .map(|t| spline.point_from_spline_coords([t]));
+            let middle =
+                spline.point_from_spline_coords([(coords[0] + coords[1]) / 2.]);
+
+            let deviation = (middle - (a + (b - a) / 2.)).magnitude();
+            assert!(deviation <= tolerance);
+        }
+    }
 }
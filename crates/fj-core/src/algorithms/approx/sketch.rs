@@ -4,18 +4,33 @@ use std::collections::BTreeSet;
 
 use crate::{geometry::Geometry, topology::Sketch};
 
-use super::{edge::HalfEdgeApproxCache, face::FaceApprox, Approx, Tolerance};
+use super::{edge::HalfEdgeApproxCache, face::RegionApprox, Approx, Tolerance};
 
 impl Approx for &Sketch {
-    type Approximation = BTreeSet<FaceApprox>;
+    type Approximation = BTreeSet<RegionApprox>;
     type Cache = HalfEdgeApproxCache;
 
     fn approx_with_cache(
         self,
-        _tolerance: impl Into<Tolerance>,
-        _cache: &mut Self::Cache,
-        _: &Geometry,
+        tolerance: impl Into<Tolerance>,
+        cache: &mut Self::Cache,
+        geometry: &Geometry,
     ) -> Self::Approximation {
-        todo!()
+        let tolerance = tolerance.into();
+
+        // Sketches in 2D space aren't located anywhere in 3D space. To make
+        // them visible anyway, we place them in the xy-plane.
+        let surface = if geometry.is_space_2d(self.surface()) {
+            geometry.xy_plane()
+        } else {
+            geometry.of_surface(self.surface())
+        };
+
+        self.regions()
+            .iter()
+            .map(|region| {
+                (region, surface).approx_with_cache(tolerance, cache, geometry)
+            })
+            .collect()
     }
 }
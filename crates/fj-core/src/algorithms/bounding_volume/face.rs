@@ -1,33 +1,130 @@
-use fj_math::Aabb;
+use fj_math::{Aabb, Scalar, Vector};
 
 use crate::{
-    geometry::{Geometry, GlobalPath},
-    topology::Face,
+    algorithms::bounding_volume::BoundingVolume,
+    geometry::{Geometry, GlobalPath, Helix, SurfaceGeom, SweepPath},
+    topology::{Face, Region},
 };
 
-impl super::BoundingVolume<3> for &Face {
+impl BoundingVolume<3> for &Face {
     fn aabb(self, geometry: &Geometry) -> Option<Aabb<3>> {
-        self.region().exterior().aabb(geometry).map(|aabb2| {
-            let surface = geometry.of_surface(self.surface());
-
-            match surface.u {
-                GlobalPath::Circle(circle) => {
-                    // This is not the most precise way to calculate the AABB,
-                    // doing it for the whole circle, but it should do.
-
-                    let aabb_bottom = circle.aabb();
-                    let aabb_top = Aabb {
-                        min: aabb_bottom.min + surface.v,
-                        max: aabb_bottom.max + surface.v,
-                    };
-
-                    aabb_bottom.merged(&aabb_top)
-                }
-                GlobalPath::Line(_) => Aabb {
-                    min: surface.point_from_surface_coords(aabb2.min),
-                    max: surface.point_from_surface_coords(aabb2.max),
-                },
+        aabb_of_region(
+            self.region(),
+            geometry.of_surface(self.surface()),
+            geometry,
+        )
+    }
+}
+
+/// Compute an AABB for a region on the provided surface
+pub(super) fn aabb_of_region(
+    region: &Region,
+    surface: &SurfaceGeom,
+    geometry: &Geometry,
+) -> Option<Aabb<3>> {
+    region.exterior().aabb(geometry).map(|aabb2| {
+        match surface {
+            SurfaceGeom::Swept {
+                u:
+                    u @ (GlobalPath::Circle(_)
+                    | GlobalPath::Ellipse(_)
+                    | GlobalPath::Spline(_)),
+                v: SweepPath::Line(v),
+            } => {
+                // This is not the most precise way to calculate the AABB,
+                // doing it for the whole curve, but it should do.
+
+                let aabb_bottom = aabb_of_path(u, [aabb2.min.u, aabb2.max.u]);
+                let aabb_top = Aabb {
+                    min: aabb_bottom.min + *v,
+                    max: aabb_bottom.max + *v,
+                };
+
+                aabb_bottom.merged(&aabb_top)
             }
-        })
+            SurfaceGeom::Swept {
+                u: GlobalPath::Line(_),
+                v: SweepPath::Line(_),
+            } => Aabb {
+                min: surface.point_from_surface_coords(aabb2.min),
+                max: surface.point_from_surface_coords(aabb2.max),
+            },
+            SurfaceGeom::Swept {
+                u,
+                v: SweepPath::Helix(helix),
+            } => aabb_of_helical_sweep(
+                aabb_of_path(u, [aabb2.min.u, aabb2.max.u]),
+                [aabb2.min.v, aabb2.max.v],
+                helix,
+            ),
+            SurfaceGeom::Ruled { a, b } => {
+                // Any point on the surface is located on a straight line
+                // between the two paths. It must be within an AABB that
+                // contains both.
+                let range = [aabb2.min.u, aabb2.max.u];
+                aabb_of_path(a, range).merged(&aabb_of_path(b, range))
+            }
+        }
+    })
+}
+
+/// Compute an AABB that contains a path within the provided range
+fn aabb_of_path(path: &GlobalPath, range: [Scalar; 2]) -> Aabb<3> {
+    match path {
+        GlobalPath::Circle(circle) => circle.aabb(),
+        GlobalPath::Ellipse(ellipse) => ellipse.aabb(),
+        GlobalPath::Line(_) => Aabb::<3>::from_points(
+            range.map(|t| path.point_from_path_coords([t])),
+        ),
+        GlobalPath::Spline(spline) => {
+            // Within its domain, a spline is contained in the convex hull of
+            // its control points. Including the points at the ends of the
+            // range covers the case of the range being outside of the domain,
+            // at least roughly.
+            Aabb::<3>::from_points(
+                spline
+                    .control_points()
+                    .iter()
+                    .copied()
+                    .chain(range.map(|t| spline.point_from_spline_coords([t]))),
+            )
+        }
     }
 }
+
+/// Compute an AABB that contains another AABB, swept along a helix
+///
+/// This is not precise. The result is the AABB of a cylinder around the axis of
+/// the helix, that contains the whole sweep.
+fn aabb_of_helical_sweep(
+    aabb: Aabb<3>,
+    range: [Scalar; 2],
+    helix: &Helix,
+) -> Aabb<3> {
+    let origin = helix.axis().origin();
+    let direction = helix.axis().direction();
+
+    let mut radius = Scalar::ZERO;
+    let mut heights = Vec::new();
+
+    for vertex in aabb.vertices() {
+        radius = radius.max(helix.distance_from_axis(vertex));
+
+        for t in range {
+            heights
+                .push(direction.dot(&(vertex - origin)) + helix.advance() * t);
+        }
+    }
+
+    let min = heights.iter().copied().min().unwrap_or(Scalar::ZERO);
+    let max = heights.iter().copied().max().unwrap_or(Scalar::ZERO);
+
+    // The extent of a circle around the axis, along each of the global axes.
+    let extent = Vector::from(
+        [Vector::unit_x(), Vector::unit_y(), Vector::unit_z()]
+            .map(|axis| direction.cross(&axis).magnitude() * radius),
+    );
+
+    let [a, b] = [min, max].map(|height| origin + direction * height);
+    Aabb::<3>::from_points([a - extent, a + extent, b - extent, b + extent])
+}
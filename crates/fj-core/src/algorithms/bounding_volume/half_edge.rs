@@ -11,7 +11,7 @@ impl super::BoundingVolume<2> for &Handle<HalfEdge> {
         let half_edge = self;
 
         let half_edge_geom = geometry.of_half_edge(half_edge);
-        let path = half_edge_geom.path;
+        let path = &half_edge_geom.path;
 
         match path {
             SurfacePath::Circle(circle) => {
@@ -33,6 +33,13 @@ impl super::BoundingVolume<2> for &Handle<HalfEdge> {
 
                 Some(Aabb::<2>::from_points(points))
             }
+            SurfacePath::Spline(spline) => {
+                // Within its domain, a spline is contained in the convex hull
+                // of its control points.
+                Some(Aabb::<2>::from_points(
+                    spline.control_points().iter().copied(),
+                ))
+            }
         }
     }
 }
@@ -4,6 +4,7 @@ mod cycle;
 mod face;
 mod half_edge;
 mod shell;
+mod sketch;
 mod solid;
 
 use fj_math::Aabb;
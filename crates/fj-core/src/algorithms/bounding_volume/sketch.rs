@@ -0,0 +1,29 @@
+use fj_math::Aabb;
+
+use crate::{geometry::Geometry, topology::Sketch};
+
+use super::face::aabb_of_region;
+
+impl super::BoundingVolume<3> for &Sketch {
+    fn aabb(self, geometry: &Geometry) -> Option<Aabb<3>> {
+        // See the approximation of sketches, which places sketches in 2D space
+        // in the xy-plane.
+        let surface = if geometry.is_space_2d(self.surface()) {
+            geometry.xy_plane()
+        } else {
+            geometry.of_surface(self.surface())
+        };
+
+        let mut aabb: Option<Aabb<3>> = None;
+
+        for region in self.regions() {
+            let new_aabb = aabb_of_region(region, surface, geometry);
+            aabb = aabb.map_or(new_aabb, |aabb| match new_aabb {
+                Some(new_aabb) => Some(aabb.merged(&new_aabb)),
+                None => Some(aabb),
+            });
+        }
+
+        aabb
+    }
+}
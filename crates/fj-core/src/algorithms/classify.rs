@@ -0,0 +1,178 @@
+//! Classify points in relation to an object
+//!
+//! See [`ClassifyPoint`].
+
+use fj_math::{Point, Scalar, Triangle, Vector};
+
+use crate::{geometry::Geometry, topology::Solid};
+
+use super::approx::{Approx, Tolerance};
+
+/// Classify a point in relation to an object
+pub trait ClassifyPoint {
+    /// Determine whether the point is inside, outside, or on the boundary
+    ///
+    /// The object is approximated, using the provided tolerance. Since that
+    /// approximation can deviate from the object's actual boundary by up to
+    /// the tolerance, any point within the tolerance of the approximated
+    /// boundary is classified as being on the boundary.
+    fn classify_point(
+        self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> PointClassification;
+}
+
+impl ClassifyPoint for &Solid {
+    fn classify_point(
+        self,
+        point: impl Into<Point<3>>,
+        tolerance: impl Into<Tolerance>,
+        geometry: &Geometry,
+    ) -> PointClassification {
+        let point = point.into();
+        let tolerance = tolerance.into();
+
+        let triangles = self
+            .approx(tolerance, geometry)
+            .into_iter()
+            .flat_map(|face| face.triangles());
+
+        classify_point_by_triangles(point, triangles, tolerance.inner())
+    }
+}
+
+/// Classify a point in relation to the triangles that bound a solid
+///
+/// This is the implementation of [`ClassifyPoint`], for callers that classify
+/// many points against the same solid, and don't want to approximate it each
+/// time. Any point within `tolerance` of the triangles is classified as being
+/// on the boundary.
+pub(crate) fn classify_point_by_triangles(
+    point: Point<3>,
+    triangles: impl IntoIterator<Item = [Point<3>; 3]>,
+    tolerance: Scalar,
+) -> PointClassification {
+    // To decide whether the point is inside or outside, we compute the winding
+    // number of the solid's boundary around it, by summing up the solid angles
+    // of all triangles, as seen from the point. Unlike casting a ray and
+    // counting the intersections, this doesn't have to deal with rays that hit
+    // edges or vertices.
+    let mut solid_angle = Scalar::ZERO;
+
+    for points in triangles {
+        let Ok(triangle) = Triangle::from_points(points) else {
+            // Degenerate triangles don't contribute to the solid angle.
+            continue;
+        };
+
+        if triangle.distance_to_point(point) <= tolerance {
+            return PointClassification::OnBoundary;
+        }
+
+        solid_angle += solid_angle_of_triangle(points.map(|p| p - point));
+    }
+
+    let winding_number = solid_angle / (Scalar::PI * 4.);
+
+    if winding_number > Scalar::from(0.5) {
+        PointClassification::Inside
+    } else {
+        PointClassification::Outside
+    }
+}
+
+/// The result of classifying a point in relation to an object
+///
+/// See [`ClassifyPoint`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum PointClassification {
+    /// The point is inside of the object
+    Inside,
+
+    /// The point is outside of the object
+    Outside,
+
+    /// The point is on the boundary of the object
+    OnBoundary,
+}
+
+/// Compute the signed solid angle of a triangle, as seen from the origin
+///
+/// The triangle is defined by the vectors from the origin to its vertices. The
+/// solid angle is positive, if the triangle's front side faces away from the
+/// origin.
+fn solid_angle_of_triangle([a, b, c]: [Vector<3>; 3]) -> Scalar {
+    // See Van Oosterom and Strackee, "The Solid Angle of a Plane Triangle".
+    let [len_a, len_b, len_c] = [a, b, c].map(|v| v.magnitude());
+
+    let numerator = a.dot(&b.cross(&c));
+    let denominator = len_a * len_b * len_c
+        + a.dot(&b) * len_c
+        + a.dot(&c) * len_b
+        + b.dot(&c) * len_a;
+
+    Scalar::atan2(numerator, denominator) * 2.
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::approx::Tolerance,
+        operations::{
+            fixtures::{cylinder, solid_with_hole},
+            insert::Insert,
+        },
+        Core,
+    };
+
+    use super::{ClassifyPoint, PointClassification};
+
+    #[test]
+    fn classify_point() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let solid = solid_with_hole(&mut core).insert(&mut core);
+        let classify = |point: [f64; 3]| {
+            solid.classify_point(point, tolerance, &core.layers.geometry)
+        };
+
+        assert_eq!(classify([0.25, 0.25, 0.5]), PointClassification::Inside);
+        assert_eq!(classify([1.75, 1., 0.99]), PointClassification::Inside);
+
+        // in the hole
+        assert_eq!(classify([1., 1., 0.5]), PointClassification::Outside);
+        assert_eq!(classify([3., 1., 0.5]), PointClassification::Outside);
+        assert_eq!(classify([1., 1., 2.]), PointClassification::Outside);
+
+        // on faces, edges, and vertices
+        assert_eq!(classify([0.25, 1., 1.]), PointClassification::OnBoundary);
+        assert_eq!(classify([0.5, 1., 0.5]), PointClassification::OnBoundary);
+        assert_eq!(classify([2., 2., 0.5]), PointClassification::OnBoundary);
+        assert_eq!(classify([0., 0., 0.]), PointClassification::OnBoundary);
+
+        Ok(())
+    }
+
+    #[test]
+    fn classify_point_near_curved_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let cylinder =
+            cylinder([0., 0., 0.], 1., 1., &mut core).insert(&mut core);
+
+        let classify = |point: [f64; 3]| {
+            cylinder.classify_point(point, tolerance, &core.layers.geometry)
+        };
+
+        assert_eq!(classify([0.99, 0., 0.5]), PointClassification::Inside);
+        assert_eq!(classify([1., 0., 0.5]), PointClassification::OnBoundary);
+        assert_eq!(classify([0., -1., 0.5]), PointClassification::OnBoundary);
+        assert_eq!(classify([0.71, 0.71, 0.5]), PointClassification::Outside);
+
+        Ok(())
+    }
+}
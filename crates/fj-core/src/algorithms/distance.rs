@@ -0,0 +1,614 @@
+//! Compute the minimum distance between objects
+//!
+//! See [`Distance`].
+
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{
+        minimize::{minimize, Domain},
+        Geometry, GlobalPath, SurfaceGeom, SurfacePath, SweepPath,
+    },
+    storage::Handle,
+    topology::{Face, HalfEdge, Solid, Surface},
+};
+
+use super::{
+    approx::{Approx, Tolerance},
+    bounding_volume::BoundingVolume,
+    triangulate::polygon::Polygon,
+};
+
+/// Compute the minimum distance between two objects
+///
+/// Half-edges are defined in the coordinates of a surface, so they need to be
+/// provided together with that surface.
+///
+/// The results are computed from the geometry of the objects, not from an
+/// approximation. Where no closed-form solution is available, the closest points
+/// are located numerically, to about the square root of floating point
+/// precision (around `1e-8`, relative to the size of the objects). The distance
+/// is minimal there, so its own error is much smaller.
+///
+/// # Implementation Note
+///
+/// The closest points between the interiors of two curved faces, or between a
+/// point and the interior of a curved face, are found by projecting points onto
+/// the faces' surfaces. This only finds the closest points, if they are also
+/// the closest points between the whole surfaces. For the surfaces that are
+/// typically used to bound solids, like planes, cylinders, and spheres, that is
+/// the case, unless the objects are very close to each other.
+pub trait Distance {
+    /// Compute the minimum distance, and the points where it occurs
+    ///
+    /// Returns `None`, if any of the objects is empty.
+    fn distance(self, geometry: &Geometry) -> Option<ClosestPoints>;
+}
+
+impl Distance for (&Point<3>, &Handle<Face>) {
+    fn distance(self, geometry: &Geometry) -> Option<ClosestPoints> {
+        let (point, face) = self;
+        let face = FaceGeom::new(face, geometry)?;
+
+        Some(face.closest_to_point(*point).swap())
+    }
+}
+
+impl Distance
+    for (
+        (&Handle<HalfEdge>, &Handle<Surface>),
+        (&Handle<HalfEdge>, &Handle<Surface>),
+    )
+{
+    fn distance(self, geometry: &Geometry) -> Option<ClosestPoints> {
+        let ((half_edge_a, surface_a), (half_edge_b, surface_b)) = self;
+
+        let a = EdgeGeom::new(
+            half_edge_a,
+            geometry.of_surface(surface_a).clone(),
+            geometry,
+        );
+        let b = EdgeGeom::new(
+            half_edge_b,
+            geometry.of_surface(surface_b).clone(),
+            geometry,
+        );
+
+        Some(a.closest_to_edge(&b))
+    }
+}
+
+/// Compute the minimum distance between the boundaries of two solids
+///
+/// If one solid is located inside of the other, their boundaries don't touch,
+/// and the distance is not zero. Use [`ClassifyPoint`] to detect that case.
+///
+/// [`ClassifyPoint`]: super::classify::ClassifyPoint
+impl Distance for (&Solid, &Solid) {
+    fn distance(self, geometry: &Geometry) -> Option<ClosestPoints> {
+        let (a, b) = self;
+
+        let faces = [a, b].map(|solid| {
+            solid
+                .shells()
+                .iter()
+                .flat_map(|shell| shell.faces())
+                .filter_map(|face| FaceGeom::new(face, geometry))
+                .collect::<Vec<_>>()
+        });
+        let [faces_a, faces_b] = &faces;
+
+        // Check the pairs of faces that are closest to each other first. Once
+        // the remaining pairs are further apart than the closest points we've
+        // found, we can stop.
+        let mut pairs = Vec::new();
+        for face_a in faces_a {
+            for face_b in faces_b {
+                let distance =
+                    distance_between_aabbs(&face_a.aabb, &face_b.aabb);
+                pairs.push((distance, face_a, face_b));
+            }
+        }
+        pairs.sort_by_key(|&(distance, _, _)| distance);
+
+        let mut closest: Option<ClosestPoints> = None;
+
+        for (distance, face_a, face_b) in pairs {
+            if let Some(closest) = &closest {
+                if distance >= closest.distance {
+                    break;
+                }
+            }
+
+            let candidate = face_a.closest_to_face(face_b);
+            closest = ClosestPoints::closer(closest, Some(candidate));
+        }
+
+        closest
+    }
+}
+
+/// The result of a minimum distance query
+///
+/// See [`Distance`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct ClosestPoints {
+    /// The minimum distance between the objects
+    pub distance: Scalar,
+
+    /// The points where the minimum distance occurs
+    ///
+    /// The order matches the order of the objects that were passed to the
+    /// query.
+    pub points: [Point<3>; 2],
+}
+
+impl ClosestPoints {
+    fn new(points: [Point<3>; 2]) -> Self {
+        let [a, b] = points;
+
+        Self {
+            distance: (b - a).magnitude(),
+            points,
+        }
+    }
+
+    fn swap(self) -> Self {
+        let [a, b] = self.points;
+
+        Self {
+            distance: self.distance,
+            points: [b, a],
+        }
+    }
+
+    fn closer(a: Option<Self>, b: Option<Self>) -> Option<Self> {
+        match (a, b) {
+            (Some(a), Some(b)) => {
+                if b.distance < a.distance {
+                    Some(b)
+                } else {
+                    Some(a)
+                }
+            }
+            (a, None) => a,
+            (None, b) => b,
+        }
+    }
+}
+
+struct EdgeGeom {
+    path: SurfacePath,
+    surface: SurfaceGeom,
+    range: [Scalar; 2],
+}
+
+impl EdgeGeom {
+    fn new(
+        half_edge: &Handle<HalfEdge>,
+        surface: SurfaceGeom,
+        geometry: &Geometry,
+    ) -> Self {
+        let half_edge = geometry.of_half_edge(half_edge);
+
+        let [a, b] = half_edge.boundary.inner.map(|point| point.t);
+        let range = if a <= b { [a, b] } else { [b, a] };
+
+        Self {
+            path: half_edge.path.clone(),
+            surface,
+            range,
+        }
+    }
+
+    fn point_at(&self, t: Scalar) -> Point<3> {
+        self.surface
+            .point_from_surface_coords(self.path.point_from_path_coords([t]))
+    }
+
+    /// Access the edge as a line segment, if it is one
+    fn as_segment(&self) -> Option<[Point<3>; 2]> {
+        let is_segment = matches!(
+            (&self.path, &self.surface),
+            (
+                SurfacePath::Line(_),
+                SurfaceGeom::Swept {
+                    u: GlobalPath::Line(_),
+                    v: SweepPath::Line(_),
+                }
+            )
+        );
+
+        is_segment.then(|| self.range.map(|t| self.point_at(t)))
+    }
+
+    fn closest_to_point(&self, point: Point<3>) -> ClosestPoints {
+        if let Some([a, b]) = self.as_segment() {
+            let direction = b - a;
+            let length_squared = direction.dot(&direction);
+
+            let s = if length_squared == Scalar::ZERO {
+                Scalar::ZERO
+            } else {
+                clamp((point - a).dot(&direction) / length_squared)
+            };
+
+            return ClosestPoints::new([a + direction * s, point]);
+        }
+
+        let t = minimize(
+            |t| {
+                let distance = self.point_at(t) - point;
+                distance.dot(&distance)
+            },
+            self.range,
+            Domain::Bounded,
+        );
+
+        ClosestPoints::new([self.point_at(t), point])
+    }
+
+    fn closest_to_edge(&self, other: &Self) -> ClosestPoints {
+        if let (Some(a), Some(b)) = (self.as_segment(), other.as_segment()) {
+            return closest_between_segments(a, b);
+        }
+
+        let t = minimize(
+            |t| other.closest_to_point(self.point_at(t)).distance,
+            self.range,
+            Domain::Bounded,
+        );
+
+        other.closest_to_point(self.point_at(t)).swap()
+    }
+}
+
+struct FaceGeom {
+    surface: SurfaceGeom,
+    edges: Vec<EdgeGeom>,
+    polygon: Polygon,
+    aabb: Aabb<3>,
+}
+
+impl FaceGeom {
+    fn new(face: &Handle<Face>, geometry: &Geometry) -> Option<Self> {
+        let aabb = face.aabb(geometry)?;
+        let surface = geometry.of_surface(face.surface()).clone();
+
+        let edges = face
+            .region()
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+            .map(|half_edge| {
+                EdgeGeom::new(half_edge, surface.clone(), geometry)
+            })
+            .collect();
+
+        // We need a polygon that represents the face in surface coordinates, to
+        // check whether points on the surface are within the face. Its accuracy
+        // only matters for points right at the boundary of the face, and for
+        // those, the closest point on the boundary is computed exactly anyway.
+        let size = aabb.size().magnitude().max(Scalar::from(1.));
+        let tolerance = Tolerance::from_scalar(size * 1e-4)
+            .expect("Tolerance is derived from non-zero size");
+        let approx = face.clone().approx(tolerance, geometry);
+        let polygon = Polygon::new()
+            .with_exterior(
+                approx
+                    .exterior
+                    .points()
+                    .into_iter()
+                    .map(|point| point.local_form),
+            )
+            .with_interiors(approx.interiors.iter().map(|interior| {
+                interior.points().into_iter().map(|point| point.local_form)
+            }));
+
+        Some(Self {
+            surface,
+            edges,
+            polygon,
+            aabb,
+        })
+    }
+
+    /// Project the point into the face's interior, if possible
+    fn project_into_interior(&self, point: Point<3>) -> Option<Point<3>> {
+        let point_surface = self.surface.project_global_point(point);
+
+        self.polygon
+            .contains_point(point_surface)
+            .then(|| self.surface.point_from_surface_coords(point_surface))
+    }
+
+    fn closest_to_point(&self, point: Point<3>) -> ClosestPoints {
+        let interior = self
+            .project_into_interior(point)
+            .map(|closest| ClosestPoints::new([closest, point]));
+        let boundary = self
+            .edges
+            .iter()
+            .map(|edge| edge.closest_to_point(point))
+            .min_by_key(|closest| closest.distance);
+
+        ClosestPoints::closer(interior, boundary)
+            .expect("Face has at least one edge")
+    }
+
+    fn closest_to_face(&self, other: &Self) -> ClosestPoints {
+        let mut closest = None;
+
+        for edge in &self.edges {
+            for other_edge in &other.edges {
+                let candidate = edge.closest_to_edge(other_edge);
+                closest = ClosestPoints::closer(closest, Some(candidate));
+            }
+
+            let candidate = other
+                .closest_to_edge_in_interior(edge)
+                .map(ClosestPoints::swap);
+            closest = ClosestPoints::closer(closest, candidate);
+        }
+        for other_edge in &other.edges {
+            let candidate = self.closest_to_edge_in_interior(other_edge);
+            closest = ClosestPoints::closer(closest, candidate);
+        }
+
+        let closest = closest.expect("Faces have at least one edge");
+
+        let candidate = self.closest_between_interiors(other, closest);
+        ClosestPoints::closer(Some(closest), candidate)
+            .expect("Just passed `Some`")
+    }
+
+    /// Find the closest points between an edge and the face's interior
+    fn closest_to_edge_in_interior(
+        &self,
+        edge: &EdgeGeom,
+    ) -> Option<ClosestPoints> {
+        let distance_to_surface = |t| {
+            let point = edge.point_at(t);
+            let point_surface = self.surface.project_global_point(point);
+            let distance =
+                self.surface.point_from_surface_coords(point_surface) - point;
+            distance.dot(&distance)
+        };
+        let t = minimize(distance_to_surface, edge.range, Domain::Bounded);
+
+        let point = edge.point_at(t);
+        self.project_into_interior(point)
+            .map(|closest| ClosestPoints::new([closest, point]))
+    }
+
+    /// Find the closest points between the interiors of two faces
+    ///
+    /// Starting from a pair of points, this alternately projects each point
+    /// onto the other face's surface, until they stop moving.
+    fn closest_between_interiors(
+        &self,
+        other: &Self,
+        start: ClosestPoints,
+    ) -> Option<ClosestPoints> {
+        const MAX_ITERATIONS: usize = 64;
+
+        let project = |surface: &SurfaceGeom, point| {
+            surface
+                .point_from_surface_coords(surface.project_global_point(point))
+        };
+
+        let [mut a, _] = start.points;
+        let mut b = project(&other.surface, a);
+
+        for _ in 0..MAX_ITERATIONS {
+            let next_a = project(&self.surface, b);
+            let next_b = project(&other.surface, next_a);
+
+            let moved = (next_a - a).magnitude() + (next_b - b).magnitude();
+            a = next_a;
+            b = next_b;
+
+            if moved <= start.distance * Scalar::from(1e-12) {
+                break;
+            }
+        }
+
+        let a = self.project_into_interior(a)?;
+        let b = other.project_into_interior(b)?;
+
+        Some(ClosestPoints::new([a, b]))
+    }
+}
+
+/// Compute the closest points between two line segments
+///
+/// See Ericson, "Real-Time Collision Detection", section 5.1.9.
+fn closest_between_segments(
+    [start_a, end_a]: [Point<3>; 2],
+    [start_b, end_b]: [Point<3>; 2],
+) -> ClosestPoints {
+    let direction_a = end_a - start_a;
+    let direction_b = end_b - start_b;
+    let r = start_a - start_b;
+
+    let a = direction_a.dot(&direction_a);
+    let e = direction_b.dot(&direction_b);
+    let f = direction_b.dot(&r);
+
+    let (s, t) = if a == Scalar::ZERO && e == Scalar::ZERO {
+        (Scalar::ZERO, Scalar::ZERO)
+    } else if a == Scalar::ZERO {
+        (Scalar::ZERO, clamp(f / e))
+    } else {
+        let c = direction_a.dot(&r);
+
+        if e == Scalar::ZERO {
+            (clamp(-c / a), Scalar::ZERO)
+        } else {
+            let b = direction_a.dot(&direction_b);
+            let denominator = a * e - b * b;
+
+            // If the segments are parallel, any point on the first one will
+            // do, to start with.
+            let s = if denominator == Scalar::ZERO {
+                Scalar::ZERO
+            } else {
+                clamp((b * f - c * e) / denominator)
+            };
+            let t = (b * s + f) / e;
+
+            if t < Scalar::ZERO {
+                (clamp(-c / a), Scalar::ZERO)
+            } else if t > Scalar::ONE {
+                (clamp((b - c) / a), Scalar::ONE)
+            } else {
+                (s, t)
+            }
+        }
+    };
+
+    ClosestPoints::new([start_a + direction_a * s, start_b + direction_b * t])
+}
+
+fn clamp(value: Scalar) -> Scalar {
+    value.max(Scalar::ZERO).min(Scalar::ONE)
+}
+
+fn distance_between_aabbs(a: &Aabb<3>, b: &Aabb<3>) -> Scalar {
+    let mut gap = Vector::<3>::default();
+
+    for (i, component) in gap.components.iter_mut().enumerate() {
+        let gap_ab = b.min.coords.components[i] - a.max.coords.components[i];
+        let gap_ba = a.min.coords.components[i] - b.max.coords.components[i];
+
+        *component = gap_ab.max(gap_ba).max(Scalar::ZERO);
+    }
+
+    gap.magnitude()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        operations::{
+            build::{BuildFace, BuildRegion},
+            fixtures::{cuboid, cylinder},
+            insert::Insert,
+            transform::TransformObject,
+            update::UpdateFace,
+        },
+        storage::Handle,
+        topology::{Face, Region},
+        Core,
+    };
+
+    use super::{ClosestPoints, Distance};
+
+    #[test]
+    fn point_face() {
+        let mut core = Core::new();
+
+        let face = square(&mut core);
+
+        let closest = (&Point::from([1., 1., 3.]), &face)
+            .distance(&core.layers.geometry)
+            .unwrap();
+        assert_close(&closest, 3., [[1., 1., 3.], [1., 1., 0.]]);
+
+        let closest = (&Point::from([3., 1., 1.]), &face)
+            .distance(&core.layers.geometry)
+            .unwrap();
+        assert_close(&closest, 2_f64.sqrt(), [[3., 1., 1.], [2., 1., 0.]]);
+    }
+
+    #[test]
+    fn edge_edge() {
+        let mut core = Core::new();
+
+        let a = square(&mut core);
+        let b = square(&mut core)
+            .translate([0.5, 0.5, 1.], &mut core)
+            .insert(&mut core);
+
+        // The first edge of each square goes along the x-axis. Pick the second
+        // edge of the other square, so the edges are skew.
+        let edge_a = a.region().exterior().half_edges().first();
+        let edge_b = b.region().exterior().half_edges().nth(1).unwrap();
+
+        let closest = ((edge_a, a.surface()), (edge_b, b.surface()))
+            .distance(&core.layers.geometry)
+            .unwrap();
+        assert_close(&closest, 1.5_f64.sqrt(), [[2., 0., 0.], [2.5, 0.5, 1.]]);
+    }
+
+    #[test]
+    fn solid_solid() {
+        let mut core = Core::new();
+
+        let cube = cuboid([0., 0., 0.], [1.; 3], &mut core);
+        let other = cube.translate([3., 0.5, -0.5], &mut core);
+
+        let closest = (&cube, &other).distance(&core.layers.geometry).unwrap();
+        assert!(
+            (closest.distance - Scalar::from(2.)).abs() < Scalar::from(1e-6)
+        );
+        assert!((closest.points[0].x - Scalar::ONE).abs() < Scalar::from(1e-6));
+        assert!(
+            (closest.points[1].x - Scalar::from(3.)).abs() < Scalar::from(1e-6)
+        );
+    }
+
+    #[test]
+    fn solid_solid_curved() {
+        let mut core = Core::new();
+
+        let cylinder = cylinder([0., 0., 0.], 1., 1., &mut core);
+
+        let cube = cuboid([2., -0.5, 0.], [1.; 3], &mut core);
+
+        let closest =
+            (&cylinder, &cube).distance(&core.layers.geometry).unwrap();
+        assert!((closest.distance - Scalar::ONE).abs() < Scalar::from(1e-6));
+        assert!(
+            (closest.points[0]
+                - Point::from([1., 0., closest.points[0].z.into_f64()]))
+            .magnitude()
+                < Scalar::from(1e-6)
+        );
+    }
+
+    /// A 2x2 square on the xy-plane, with one corner at the origin
+    fn square(core: &mut Core) -> Handle<Face> {
+        let surface = core.layers.topology.surfaces.xy_plane();
+
+        Face::unbound(surface.clone(), core)
+            .update_region(
+                |_, core| {
+                    Region::polygon(
+                        [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+                        surface,
+                        core,
+                    )
+                },
+                core,
+            )
+            .insert(core)
+    }
+
+    fn assert_close(
+        closest: &ClosestPoints,
+        distance: f64,
+        points: [[f64; 3]; 2],
+    ) {
+        let epsilon = Scalar::from(1e-6);
+
+        assert!((closest.distance - distance).abs() < epsilon, "{closest:?}");
+        for (point, expected) in closest.points.iter().zip(points) {
+            assert!(
+                (*point - Point::from(expected)).magnitude() < epsilon,
+                "{closest:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,155 @@
+//! Intersection between a circle and a plane in 3D
+
+use fj_math::{Circle, Plane, Point, Scalar};
+
+use super::{Intersect, PathIntersectionPoint};
+
+impl Intersect for (&Circle<3>, &Plane) {
+    type Intersection = CirclePlaneIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (circle, plane) = self;
+
+        let normal = plane.normal();
+
+        // The signed distance of a point on the circle from the plane is
+        // `a * cos(t) + b * sin(t) + c`.
+        let a = normal.dot(&circle.a());
+        let b = normal.dot(&circle.b());
+        let c = normal.dot(&(circle.center() - plane.origin()));
+
+        let Some(angles) = angles_of_zeros(a, b, c) else {
+            // Circle and plane are parallel.
+
+            if c == Scalar::ZERO {
+                return Some(CirclePlaneIntersection::Coincident);
+            }
+
+            return None;
+        };
+        if angles.is_empty() {
+            return None;
+        }
+
+        let points = angles
+            .into_iter()
+            .map(|angle| {
+                let point_on_path = Point::from([angle]);
+
+                PathIntersectionPoint {
+                    point_on_path,
+                    point: circle.point_from_circle_coords(point_on_path),
+                }
+            })
+            .collect();
+
+        Some(CirclePlaneIntersection::Points(points))
+    }
+}
+
+/// Find the angles, at which `a * cos(t) + b * sin(t) + c` is zero
+///
+/// Returns `None`, if `a` and `b` are both zero, as then the expression is
+/// constant. Otherwise, returns up to two angles, normalized into the range
+/// `0..TAU` and sorted.
+pub(super) fn angles_of_zeros(
+    a: Scalar,
+    b: Scalar,
+    c: Scalar,
+) -> Option<Vec<Scalar>> {
+    if a == Scalar::ZERO && b == Scalar::ZERO {
+        return None;
+    }
+
+    // We can rewrite the expression as `r * cos(t - phase) + c`, which makes
+    // it straight-forward to find the angles where it is zero.
+    let r = Scalar::from((a * a + b * b).into_f64().sqrt());
+    let phase = Scalar::atan2(b, a);
+
+    let cos = -c / r;
+    if cos.abs() > Scalar::ONE {
+        return Some(Vec::new());
+    }
+    let offset = cos.acos();
+
+    let mut angles = vec![phase - offset];
+    if offset != Scalar::ZERO && offset != Scalar::PI {
+        angles.push(phase + offset);
+    }
+
+    let mut angles = angles
+        .into_iter()
+        .map(|angle| angle - Scalar::TAU * (angle / Scalar::TAU).floor())
+        .collect::<Vec<_>>();
+    angles.sort();
+
+    Some(angles)
+}
+
+/// An intersection between a [`Circle`] and a [`Plane`]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum CirclePlaneIntersection {
+    /// The circle intersects the plane in one or two points
+    ///
+    /// If there's only one point, the circle touches the plane. The points are
+    /// sorted by their circle coordinate, which is in the range `0..TAU`.
+    Points(Vec<PathIntersectionPoint>),
+
+    /// The circle lies within the plane
+    Coincident,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Plane, Point, Scalar, Vector};
+
+    use crate::algorithms::intersect::Intersect;
+
+    use super::CirclePlaneIntersection;
+
+    #[test]
+    fn circle_plane() {
+        let plane = Plane::from_parametric(
+            [0., 0., 1.],
+            Vector::unit_x(),
+            Vector::unit_y(),
+        );
+
+        let circle = Circle::new([0., 0., 1.], [0., 0., 2.], [2., 0., 0.]);
+        let Some(CirclePlaneIntersection::Points(points)) =
+            (&circle, &plane).intersect()
+        else {
+            panic!("Expected circle to intersect plane");
+        };
+        let expected = [
+            (Scalar::PI / 2., [2., 0., 1.]),
+            (Scalar::PI * 1.5, [-2., 0., 1.]),
+        ];
+        assert_eq!(points.len(), expected.len());
+        for (point, (t, expected)) in points.into_iter().zip(expected) {
+            assert!((point.point_on_path.t - t).abs() < Scalar::from(1e-12));
+            assert!(
+                (point.point - Point::from(expected)).magnitude()
+                    < Scalar::from(1e-12)
+            );
+        }
+
+        let touching = Circle::new([0., 0., 2.], [0., 0., -1.], [1., 0., 0.]);
+        let Some(CirclePlaneIntersection::Points(points)) =
+            (&touching, &plane).intersect()
+        else {
+            panic!("Expected circle to touch plane");
+        };
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].point_on_path, Point::from([0.]));
+
+        let missing = Circle::new([0., 0., 3.], [0., 0., 1.], [1., 0., 0.]);
+        assert_eq!((&missing, &plane).intersect(), None);
+
+        let coincident = Circle::from_center_and_radius([0., 0., 1.], 1.);
+        assert_eq!(
+            (&coincident, &plane).intersect(),
+            Some(CirclePlaneIntersection::Coincident)
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! Intersection between a line and a plane in 3D
+
+use fj_math::{Line, Plane, Point, Scalar};
+
+use super::{Intersect, PathIntersectionPoint};
+
+impl Intersect for (&Line<3>, &Plane) {
+    type Intersection = LinePlaneIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (line, plane) = self;
+
+        let normal = plane.normal();
+
+        // The signed distance of the line's origin from the plane, and the
+        // rate at which that distance changes, when moving along the line.
+        let distance = normal.dot(&(line.origin() - plane.origin()));
+        let rate = normal.dot(&line.direction());
+
+        if rate == Scalar::ZERO {
+            // Line and plane are parallel.
+
+            if distance == Scalar::ZERO {
+                return Some(LinePlaneIntersection::Coincident);
+            }
+
+            return None;
+        }
+
+        let point_on_path = Point::from([-distance / rate]);
+        let point = line.point_from_line_coords(point_on_path);
+
+        Some(LinePlaneIntersection::Point(PathIntersectionPoint {
+            point_on_path,
+            point,
+        }))
+    }
+}
+
+/// An intersection between a [`Line`] and a [`Plane`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum LinePlaneIntersection {
+    /// The line intersects the plane in a single point
+    Point(PathIntersectionPoint),
+
+    /// The line lies within the plane
+    Coincident,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Line, Plane, Point, Vector};
+
+    use crate::algorithms::intersect::{Intersect, PathIntersectionPoint};
+
+    use super::LinePlaneIntersection;
+
+    #[test]
+    fn line_plane() {
+        let plane = Plane::from_parametric(
+            [0., 0., 1.],
+            Vector::unit_x(),
+            Vector::unit_y(),
+        );
+
+        let line = Line::from_points_with_line_coords([
+            ([0.], [1., 2., 3.]),
+            ([1.], [1., 2., 2.]),
+        ]);
+        assert_eq!(
+            (&line, &plane).intersect(),
+            Some(LinePlaneIntersection::Point(PathIntersectionPoint {
+                point_on_path: Point::from([2.]),
+                point: Point::from([1., 2., 1.]),
+            }))
+        );
+
+        let parallel = Line::from_origin_and_direction(
+            Point::from([0., 0., 2.]),
+            Vector::unit_x(),
+        );
+        assert_eq!((&parallel, &plane).intersect(), None);
+
+        let coincident = Line::from_origin_and_direction(
+            Point::from([0., 0., 1.]),
+            Vector::from([1., 1., 0.]),
+        );
+        assert_eq!(
+            (&coincident, &plane).intersect(),
+            Some(LinePlaneIntersection::Coincident)
+        );
+    }
+}
@@ -1,10 +1,17 @@
 //! Intersection algorithms
 
+pub mod circle_plane;
+pub mod line_plane;
+pub mod path_path;
+pub mod path_surface;
+pub mod plane_plane;
+pub mod ray_face;
 pub mod ray_segment;
+pub mod surface_surface;
 
 mod line_segment;
 
-use fj_math::{Point, Vector};
+use fj_math::{Point, Scalar, Vector};
 
 pub use self::line_segment::LineSegmentIntersection;
 
@@ -22,6 +29,16 @@ pub trait Intersect {
     fn intersect(self) -> Option<Self::Intersection>;
 }
 
+/// A point where a path intersects another object
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PathIntersectionPoint {
+    /// The intersection point, in path coordinates
+    pub point_on_path: Point<1>,
+
+    /// The intersection point, in global coordinates
+    pub point: Point<3>,
+}
+
 /// A horizontal ray that goes to the right
 ///
 /// For in-kernel use, we don't need anything more flexible, and being exactly
@@ -51,3 +68,34 @@ where
         }
     }
 }
+
+/// A ray in 3D space
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ray {
+    /// The point where the ray originates
+    pub origin: Point<3>,
+
+    /// The direction of the ray
+    ///
+    /// Distances along the ray are measured in units of this vector. If it is
+    /// normalized, they are the actual distances from the origin.
+    pub direction: Vector<3>,
+}
+
+impl Ray {
+    /// Construct a ray from its origin and direction
+    pub fn new(
+        origin: impl Into<Point<3>>,
+        direction: impl Into<Vector<3>>,
+    ) -> Self {
+        Self {
+            origin: origin.into(),
+            direction: direction.into(),
+        }
+    }
+
+    /// Compute the point at the provided distance along the ray
+    pub fn point_at(&self, distance: impl Into<Scalar>) -> Point<3> {
+        self.origin + self.direction * distance.into()
+    }
+}
@@ -0,0 +1,306 @@
+//! Intersection between two paths in surface coordinates
+
+use fj_math::{Circle, Line, Point, Scalar, Vector};
+
+use crate::geometry::SurfacePath;
+
+use super::Intersect;
+
+/// Intersect two paths that are defined in the same surface coordinates
+///
+/// # Implementation Note
+///
+/// Only intersections between lines and circles are supported so far. Trying to
+/// intersect a spline results in a panic.
+impl Intersect for (&SurfacePath, &SurfacePath) {
+    type Intersection = PathPathIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (a, b) = self;
+
+        let swap = |intersection: PathPathIntersection| match intersection {
+            PathPathIntersection::Points(points) => {
+                PathPathIntersection::Points(
+                    points
+                        .into_iter()
+                        .map(|point| {
+                            let [a, b] = point.point_on_paths;
+                            PathPathPoint {
+                                point_on_paths: [b, a],
+                                point: point.point,
+                            }
+                        })
+                        .collect(),
+                )
+            }
+            PathPathIntersection::Coincident => {
+                PathPathIntersection::Coincident
+            }
+        };
+
+        match (a, b) {
+            (SurfacePath::Line(a), SurfacePath::Line(b)) => {
+                intersect_lines(a, b)
+            }
+            (SurfacePath::Line(line), SurfacePath::Circle(circle)) => {
+                intersect_line_and_circle(line, circle)
+            }
+            (SurfacePath::Circle(circle), SurfacePath::Line(line)) => {
+                intersect_line_and_circle(line, circle).map(swap)
+            }
+            (SurfacePath::Circle(a), SurfacePath::Circle(b)) => {
+                intersect_circles(a, b)
+            }
+            (SurfacePath::Spline(_), _) | (_, SurfacePath::Spline(_)) => {
+                todo!(
+                    "Only intersections between lines and circles are \
+                    supported."
+                )
+            }
+        }
+    }
+}
+
+/// An intersection between two paths in surface coordinates
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum PathPathIntersection {
+    /// The paths intersect in one or more points
+    Points(Vec<PathPathPoint>),
+
+    /// The paths are coincident
+    Coincident,
+}
+
+/// A point where two paths intersect
+///
+/// See [`PathPathIntersection`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PathPathPoint {
+    /// The intersection point, in the coordinates of each path
+    ///
+    /// The order matches the order of the intersected paths. Circle coordinates
+    /// are in the range `0..TAU`.
+    pub point_on_paths: [Point<1>; 2],
+
+    /// The intersection point, in surface coordinates
+    pub point: Point<2>,
+}
+
+/// The precision used to decide whether paths are parallel or tangent
+///
+/// This is relative to the size of the compared paths.
+const RELATIVE_EPSILON: f64 = 1e-12;
+
+fn intersect_lines(a: &Line<2>, b: &Line<2>) -> Option<PathPathIntersection> {
+    let denominator = a.direction().cross2d(&b.direction());
+    let a_to_b = b.origin() - a.origin();
+
+    if denominator.abs()
+        <= a.direction().magnitude()
+            * b.direction().magnitude()
+            * RELATIVE_EPSILON
+    {
+        // The lines are parallel.
+
+        let distance =
+            a_to_b.cross2d(&a.direction()).abs() / a.direction().magnitude();
+        let scale = a.origin().coords.magnitude()
+            + b.origin().coords.magnitude()
+            + a.direction().magnitude()
+            + b.direction().magnitude();
+        if distance <= scale * RELATIVE_EPSILON {
+            return Some(PathPathIntersection::Coincident);
+        }
+
+        return None;
+    }
+
+    let t = a_to_b.cross2d(&b.direction()) / denominator;
+    let s = a_to_b.cross2d(&a.direction()) / denominator;
+
+    Some(PathPathIntersection::Points(vec![PathPathPoint {
+        point_on_paths: [Point::from([t]), Point::from([s])],
+        point: a.point_from_line_coords([t]),
+    }]))
+}
+
+fn intersect_line_and_circle(
+    line: &Line<2>,
+    circle: &Circle<2>,
+) -> Option<PathPathIntersection> {
+    // The points on the line, whose distance from the circle's center is equal
+    // to its radius, are the solutions of a quadratic equation.
+    let direction = line.direction();
+    let center_to_origin = line.origin() - circle.center();
+    let radius = circle.radius();
+
+    let a = direction.dot(&direction);
+    let b = direction.dot(&center_to_origin);
+    let c = center_to_origin.dot(&center_to_origin) - radius * radius;
+
+    let discriminant = b * b - a * c;
+    let tangent = discriminant.abs() <= b * b * RELATIVE_EPSILON
+        || discriminant.abs() <= a * radius * radius * RELATIVE_EPSILON;
+
+    let ts = if tangent {
+        vec![-b / a]
+    } else if discriminant < Scalar::ZERO {
+        return None;
+    } else {
+        let root = Scalar::from(discriminant.into_f64().sqrt());
+        vec![(-b - root) / a, (-b + root) / a]
+    };
+
+    let points = ts
+        .into_iter()
+        .map(|t| {
+            let point = line.point_from_line_coords([t]);
+            PathPathPoint {
+                point_on_paths: [
+                    Point::from([t]),
+                    SurfacePath::Circle(*circle).project_point(point),
+                ],
+                point,
+            }
+        })
+        .collect();
+
+    Some(PathPathIntersection::Points(points))
+}
+
+fn intersect_circles(
+    a: &Circle<2>,
+    b: &Circle<2>,
+) -> Option<PathPathIntersection> {
+    let [radius_a, radius_b] = [a, b].map(|circle| circle.radius());
+    let epsilon = radius_a.max(radius_b) * RELATIVE_EPSILON;
+
+    let a_to_b = b.center() - a.center();
+    let distance = a_to_b.magnitude();
+
+    if distance <= epsilon {
+        if (radius_a - radius_b).abs() <= epsilon {
+            return Some(PathPathIntersection::Coincident);
+        }
+
+        return None;
+    }
+
+    // The intersection points are on the line perpendicular to `a_to_b`, that
+    // is at `along` distance from the center of `a`.
+    let along = (radius_a * radius_a - radius_b * radius_b
+        + distance * distance)
+        / (distance * 2.);
+    let across_squared = radius_a * radius_a - along * along;
+
+    let across = if across_squared.abs() <= radius_a * epsilon {
+        Scalar::ZERO
+    } else if across_squared < Scalar::ZERO {
+        return None;
+    } else {
+        Scalar::from(across_squared.into_f64().sqrt())
+    };
+
+    let direction = a_to_b / distance;
+    let perpendicular = Vector::from([-direction.v, direction.u]);
+    let base = a.center() + direction * along;
+
+    let mut points = vec![base + perpendicular * across];
+    if across != Scalar::ZERO {
+        points.push(base - perpendicular * across);
+    }
+
+    let points = points
+        .into_iter()
+        .map(|point| PathPathPoint {
+            point_on_paths: [a, b].map(|circle| {
+                SurfacePath::Circle(*circle).project_point(point)
+            }),
+            point,
+        })
+        .collect();
+
+    Some(PathPathIntersection::Points(points))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Point, Scalar};
+
+    use crate::{algorithms::intersect::Intersect, geometry::SurfacePath};
+
+    use super::PathPathIntersection;
+
+    #[test]
+    fn line_line() {
+        let (a, _) = SurfacePath::line_from_points([[0., 0.], [2., 2.]]);
+        let (b, _) = SurfacePath::line_from_points([[0., 2.], [2., 0.]]);
+        check_points(&a, &b, 1);
+
+        let (c, _) = SurfacePath::line_from_points([[0., 1.], [2., 3.]]);
+        assert_eq!((&a, &c).intersect(), None);
+
+        let (d, _) = SurfacePath::line_from_points([[3., 3.], [4., 4.]]);
+        assert_eq!(
+            (&a, &d).intersect(),
+            Some(PathPathIntersection::Coincident)
+        );
+    }
+
+    #[test]
+    fn line_circle() {
+        let circle = SurfacePath::circle_from_center_and_radius([1., 1.], 1.);
+
+        let (secant, _) = SurfacePath::line_from_points([[0., 1.], [1., 1.]]);
+        check_points(&secant, &circle, 2);
+        check_points(&circle, &secant, 2);
+
+        let (tangent, _) = SurfacePath::line_from_points([[0., 2.], [1., 2.]]);
+        check_points(&tangent, &circle, 1);
+
+        let (outside, _) = SurfacePath::line_from_points([[0., 3.], [1., 3.]]);
+        assert_eq!((&outside, &circle).intersect(), None);
+    }
+
+    #[test]
+    fn circle_circle() {
+        let a = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+        let b = SurfacePath::Circle(
+            Circle::from_center_and_radius([1., 1.], 1.).reverse(),
+        );
+        check_points(&a, &b, 2);
+
+        let touching = SurfacePath::circle_from_center_and_radius([2., 0.], 1.);
+        check_points(&a, &touching, 1);
+
+        let inside = SurfacePath::circle_from_center_and_radius([0., 0.], 0.5);
+        assert_eq!((&a, &inside).intersect(), None);
+        assert_eq!(
+            (&a, &a).intersect(),
+            Some(PathPathIntersection::Coincident)
+        );
+    }
+
+    fn check_points(a: &SurfacePath, b: &SurfacePath, num_points: usize) {
+        let Some(PathPathIntersection::Points(points)) = (a, b).intersect()
+        else {
+            panic!("Expected paths to intersect in points");
+        };
+        assert_eq!(points.len(), num_points);
+
+        for point in points {
+            for (path, point_on_path) in
+                [a, b].into_iter().zip(point.point_on_paths)
+            {
+                assert_close(
+                    path.point_from_path_coords(point_on_path),
+                    point.point,
+                );
+            }
+        }
+    }
+
+    fn assert_close(a: Point<2>, b: Point<2>) {
+        assert!((a - b).magnitude() < Scalar::from(1e-12), "{a:?} != {b:?}");
+    }
+}
@@ -0,0 +1,126 @@
+//! Intersection between a path and a surface
+
+use fj_math::Point;
+
+use crate::geometry::{GlobalPath, SurfaceGeom};
+
+use super::{
+    circle_plane::CirclePlaneIntersection, line_plane::LinePlaneIntersection,
+    surface_surface::as_plane, Intersect,
+};
+
+/// Intersect a path with a surface
+///
+/// # Implementation Note
+///
+/// Only intersections between lines or circles, and planes, are supported so
+/// far. Trying to intersect anything else results in a panic.
+impl Intersect for (&GlobalPath, &SurfaceGeom) {
+    type Intersection = PathSurfaceIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (path, surface) = self;
+
+        let Some(plane) = as_plane(surface) else {
+            todo!("Intersecting paths with curved surfaces is not supported.")
+        };
+
+        let points = match path {
+            GlobalPath::Line(line) => match (line, &plane).intersect()? {
+                LinePlaneIntersection::Point(point) => vec![point],
+                LinePlaneIntersection::Coincident => {
+                    return Some(PathSurfaceIntersection::Coincident);
+                }
+            },
+            GlobalPath::Circle(circle) => {
+                match (circle, &plane).intersect()? {
+                    CirclePlaneIntersection::Points(points) => points,
+                    CirclePlaneIntersection::Coincident => {
+                        return Some(PathSurfaceIntersection::Coincident);
+                    }
+                }
+            }
+            GlobalPath::Ellipse(_) | GlobalPath::Spline(_) => {
+                todo!(
+                    "Only intersections between lines or circles, and \
+                    planes, are supported."
+                )
+            }
+        };
+
+        let points = points
+            .into_iter()
+            .map(|point| PathSurfacePoint {
+                point_on_path: point.point_on_path,
+                point_on_surface: plane.project_point(point.point),
+                point: point.point,
+            })
+            .collect();
+
+        Some(PathSurfaceIntersection::Points(points))
+    }
+}
+
+/// An intersection between a path and a surface
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum PathSurfaceIntersection {
+    /// The path intersects the surface in one or more points
+    Points(Vec<PathSurfacePoint>),
+
+    /// The path lies within the surface
+    Coincident,
+}
+
+/// A point where a path intersects a surface
+///
+/// See [`PathSurfaceIntersection`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct PathSurfacePoint {
+    /// The intersection point, in path coordinates
+    pub point_on_path: Point<1>,
+
+    /// The intersection point, in surface coordinates
+    pub point_on_surface: Point<2>,
+
+    /// The intersection point, in global coordinates
+    pub point: Point<3>,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Point, Vector};
+
+    use crate::{
+        algorithms::intersect::Intersect,
+        geometry::{GlobalPath, SurfaceGeom, SweepPath},
+    };
+
+    use super::{PathSurfaceIntersection, PathSurfacePoint};
+
+    #[test]
+    fn path_surface() {
+        let surface = SurfaceGeom::Swept {
+            u: GlobalPath::line_from_points([[0., 0., 1.], [2., 0., 1.]]).0,
+            v: SweepPath::Line(Vector::unit_y()),
+        };
+
+        let line = GlobalPath::line_from_points([[1., 1., 0.], [1., 1., 2.]]).0;
+        assert_eq!(
+            (&line, &surface).intersect(),
+            Some(PathSurfaceIntersection::Points(vec![PathSurfacePoint {
+                point_on_path: Point::from([0.5]),
+                point_on_surface: Point::from([0.5, 1.]),
+                point: Point::from([1., 1., 1.]),
+            }]))
+        );
+
+        let circle = GlobalPath::Circle(Circle::from_center_and_radius(
+            [0., 0., 1.],
+            1.,
+        ));
+        assert_eq!(
+            (&circle, &surface).intersect(),
+            Some(PathSurfaceIntersection::Coincident)
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Intersection between two planes
+
+use fj_math::{Line, Plane, Point, Scalar, Vector};
+
+use super::Intersect;
+
+impl Intersect for (&Plane, &Plane) {
+    type Intersection = PlanePlaneIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (a, b) = self;
+
+        let (distance_a, normal_a) = a.constant_normal_form();
+        let (distance_b, normal_b) = b.constant_normal_form();
+
+        let direction = normal_a.cross(&normal_b);
+
+        if direction == Vector::default() {
+            // The planes are parallel.
+
+            if normal_a.dot(&(b.origin() - a.origin())) == Scalar::ZERO {
+                return Some(PlanePlaneIntersection::Coincident);
+            }
+
+            return None;
+        }
+
+        // The point on the intersection line that is closest to the global
+        // origin is a linear combination of the two normals.
+        let normals_dot = normal_a.dot(&normal_b);
+        let denominator = direction.dot(&direction);
+        let origin = (normal_a * (distance_a - distance_b * normals_dot)
+            + normal_b * (distance_b - distance_a * normals_dot))
+            / denominator;
+
+        Some(PlanePlaneIntersection::Line(
+            Line::from_origin_and_direction(
+                Point::origin() + origin,
+                direction.normalize(),
+            ),
+        ))
+    }
+}
+
+/// An intersection between two [`Plane`]s
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum PlanePlaneIntersection {
+    /// The planes intersect in a line
+    ///
+    /// The direction of the line is normalized, and is the cross product of
+    /// the first plane's normal and the second plane's normal.
+    Line(Line<3>),
+
+    /// The planes are coincident
+    Coincident,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Plane, Point, Scalar, Vector};
+
+    use crate::algorithms::intersect::Intersect;
+
+    use super::PlanePlaneIntersection;
+
+    #[test]
+    fn plane_plane() {
+        let xy = Plane::from_parametric(
+            [0., 0., 1.],
+            Vector::unit_x(),
+            Vector::unit_y(),
+        );
+        let xz = Plane::from_parametric(
+            [2., 3., 4.],
+            Vector::unit_x(),
+            Vector::from([0., 0., 2.]),
+        );
+
+        let Some(PlanePlaneIntersection::Line(line)) = (&xy, &xz).intersect()
+        else {
+            panic!("Expected planes to intersect in a line");
+        };
+        assert_eq!(line.direction(), Vector::unit_x());
+        for t in [-1., 0., 1.] {
+            let point = line.point_from_line_coords([t]);
+            assert_eq!(point.y, Scalar::from(3.));
+            assert_eq!(point.z, Scalar::ONE);
+        }
+
+        let parallel = Plane::from_parametric(
+            [0., 0., 2.],
+            Vector::unit_y(),
+            Vector::unit_x(),
+        );
+        assert_eq!((&xy, &parallel).intersect(), None);
+
+        let coincident = Plane::from_parametric(
+            Point::from([1., 1., 1.]),
+            Vector::unit_y(),
+            Vector::unit_x(),
+        );
+        assert_eq!(
+            (&xy, &coincident).intersect(),
+            Some(PlanePlaneIntersection::Coincident)
+        );
+    }
+}
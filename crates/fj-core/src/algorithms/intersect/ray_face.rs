@@ -0,0 +1,114 @@
+//! Intersection between a ray and a face in 3D
+
+use fj_math::{Point, Scalar, Triangle, Vector};
+
+use crate::algorithms::approx::face::FaceApprox;
+
+use super::{Intersect, Ray};
+
+/// Intersect a ray with an approximated face
+///
+/// The face is intersected in the form of its triangulation. This is exact for
+/// planar faces. For curved faces, the intersection deviates from the actual
+/// face by no more than the tolerance of the approximation.
+impl Intersect for (&Ray, &FaceApprox) {
+    type Intersection = RayFaceIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (ray, face) = self;
+
+        face.triangles()
+            .into_iter()
+            .filter_map(|points| {
+                // Degenerate triangles have no area, so the ray can't hit
+                // them.
+                let triangle = Triangle::from_points(points).ok()?;
+
+                let distance = triangle.cast_local_ray(
+                    ray.origin,
+                    ray.direction,
+                    f64::INFINITY,
+                    true,
+                )?;
+
+                Some(RayFaceIntersection {
+                    distance,
+                    point: ray.point_at(distance),
+                    normal: triangle.normal(),
+                })
+            })
+            .min_by_key(|intersection| intersection.distance)
+    }
+}
+
+/// The intersection between a [`Ray`] and a face
+///
+/// If the ray hits the face multiple times, this is the hit that is closest to
+/// the ray's origin.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RayFaceIntersection {
+    /// The distance from the ray's origin, in units of its direction
+    pub distance: Scalar,
+
+    /// The point where the ray hits the face
+    pub point: Point<3>,
+
+    /// The normal of the face, where the ray hits it
+    ///
+    /// This points to the front side of the face, and is normalized.
+    pub normal: Vector<3>,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::{Approx, Tolerance},
+            intersect::{Intersect, Ray},
+        },
+        operations::{
+            build::{BuildFace, BuildRegion},
+            insert::Insert,
+            update::UpdateFace,
+        },
+        topology::{Face, Region},
+        Core,
+    };
+
+    #[test]
+    fn ray_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::unbound(surface.clone(), &mut core)
+            .update_region(
+                |_, core| {
+                    Region::polygon(
+                        [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+                        surface,
+                        core,
+                    )
+                },
+                &mut core,
+            )
+            .insert(&mut core);
+        let face =
+            face.approx(Tolerance::from_scalar(0.01)?, &core.layers.geometry);
+
+        let hit = (&Ray::new([1., 1., 2.], [0., 0., -2.]), &face).intersect();
+        let hit = hit.expect("Expected ray to hit face");
+        assert_eq!(hit.distance, Scalar::ONE);
+        assert_eq!(hit.point, Point::from([1., 1., 0.]));
+        assert_eq!(hit.normal, Vector::unit_z());
+
+        let miss = (&Ray::new([3., 1., 2.], [0., 0., -1.]), &face).intersect();
+        assert_eq!(miss, None);
+
+        let away = (&Ray::new([1., 1., 2.], [0., 0., 1.]), &face).intersect();
+        assert_eq!(away, None);
+
+        Ok(())
+    }
+}
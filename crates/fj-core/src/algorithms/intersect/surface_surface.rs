@@ -0,0 +1,558 @@
+//! Intersection between two surfaces
+
+use fj_math::{Circle, Ellipse, Line, Plane, Point, Scalar, Vector};
+
+use crate::geometry::{GlobalPath, SurfaceGeom, SurfacePath, SweepPath};
+
+use super::{
+    circle_plane::angles_of_zeros, path_path::PathPathIntersection,
+    plane_plane::PlanePlaneIntersection, Intersect,
+};
+
+/// Intersect two surfaces
+///
+/// # Implementation Note
+///
+/// Only intersections between planes, between planes and cylinders (a circle
+/// or ellipse, swept along a line), and between circular cylinders with
+/// parallel axes are supported so far. Trying to intersect any other surfaces
+/// results in a panic.
+impl Intersect for (&SurfaceGeom, &SurfaceGeom) {
+    type Intersection = SurfaceSurfaceIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (a, b) = self;
+
+        match (SurfaceKind::of(a), SurfaceKind::of(b)) {
+            (Some(SurfaceKind::Plane(a)), Some(SurfaceKind::Plane(b))) => {
+                intersect_planes(&a, &b)
+            }
+            (Some(SurfaceKind::Plane(plane)), Some(cylinder)) => {
+                intersect_plane_and_cylinder(&plane, &cylinder)
+            }
+            (Some(cylinder), Some(SurfaceKind::Plane(plane))) => {
+                intersect_plane_and_cylinder(&plane, &cylinder).map(
+                    |intersection| match intersection {
+                        SurfaceSurfaceIntersection::Curves(curves) => {
+                            SurfaceSurfaceIntersection::Curves(
+                                curves
+                                    .into_iter()
+                                    .map(|curve| {
+                                        let [a, b] = curve.local;
+                                        IntersectionCurve {
+                                            global: curve.global,
+                                            local: [b, a],
+                                        }
+                                    })
+                                    .collect(),
+                            )
+                        }
+                        SurfaceSurfaceIntersection::Coincident => {
+                            SurfaceSurfaceIntersection::Coincident
+                        }
+                    },
+                )
+            }
+            (Some(a), Some(b)) => intersect_cylinders(&a, &b),
+            _ => {
+                todo!(
+                    "Only intersections between planes, and between planes \
+                    and cylinders, are supported."
+                )
+            }
+        }
+    }
+}
+
+/// An intersection between two surfaces
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SurfaceSurfaceIntersection {
+    /// The surfaces intersect in one or more curves
+    Curves(Vec<IntersectionCurve>),
+
+    /// The surfaces are coincident
+    Coincident,
+}
+
+/// A curve along which two surfaces intersect
+///
+/// See [`SurfaceSurfaceIntersection`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct IntersectionCurve {
+    /// The curve, in global coordinates
+    pub global: GlobalPath,
+
+    /// The curve, in the coordinates of each surface
+    ///
+    /// The order matches the order of the intersected surfaces. A local path
+    /// is `None`, if the curve can't be represented as a [`SurfacePath`] on
+    /// that surface. An ellipse on a plane is an example of that.
+    ///
+    /// Each local path has the same parameterization as the global path. A
+    /// point on the global path, and the point with the same path coordinate
+    /// on a local path, are the same point.
+    pub local: [Option<SurfacePath>; 2],
+}
+
+/// The kinds of surfaces that support intersection
+enum SurfaceKind {
+    Plane(Plane),
+    Cylinder {
+        section: Ellipse<3>,
+        axis: Vector<3>,
+    },
+}
+
+impl SurfaceKind {
+    fn of(surface: &SurfaceGeom) -> Option<Self> {
+        match surface {
+            SurfaceGeom::Swept {
+                u: GlobalPath::Line(line),
+                v: SweepPath::Line(axis),
+            } => Some(Self::Plane(Plane::from_parametric(
+                line.origin(),
+                line.direction(),
+                *axis,
+            ))),
+            SurfaceGeom::Swept {
+                u: GlobalPath::Circle(circle),
+                v: SweepPath::Line(axis),
+            } => Some(Self::Cylinder {
+                section: (*circle).into(),
+                axis: *axis,
+            }),
+            SurfaceGeom::Swept {
+                u: GlobalPath::Ellipse(ellipse),
+                v: SweepPath::Line(axis),
+            } => Some(Self::Cylinder {
+                section: *ellipse,
+                axis: *axis,
+            }),
+            _ => None,
+        }
+    }
+}
+
+fn intersect_planes(
+    a: &Plane,
+    b: &Plane,
+) -> Option<SurfaceSurfaceIntersection> {
+    let line = match (a, b).intersect()? {
+        PlanePlaneIntersection::Line(line) => line,
+        PlanePlaneIntersection::Coincident => {
+            return Some(SurfaceSurfaceIntersection::Coincident);
+        }
+    };
+
+    Some(SurfaceSurfaceIntersection::Curves(vec![
+        IntersectionCurve {
+            global: GlobalPath::Line(line),
+            local: [a, b].map(|plane| {
+                Some(SurfacePath::Line(plane.project_line(&line)))
+            }),
+        },
+    ]))
+}
+
+/// The precision used to decide whether directions are parallel
+///
+/// This is relative to the magnitude of the vectors that are compared.
+const RELATIVE_EPSILON: f64 = 1e-12;
+
+fn intersect_plane_and_cylinder(
+    plane: &Plane,
+    cylinder: &SurfaceKind,
+) -> Option<SurfaceSurfaceIntersection> {
+    let SurfaceKind::Cylinder { section, axis } = cylinder else {
+        unreachable!("Expected a cylinder");
+    };
+
+    let normal = plane.normal();
+
+    // A point on the cylinder has the signed distance
+    // `a * cos(u) + b * sin(u) + c + d * v` from the plane.
+    let a = normal.dot(&section.a());
+    let b = normal.dot(&section.b());
+    let c = normal.dot(&(section.center() - plane.origin()));
+    let d = normal.dot(axis);
+
+    // Planes that are parallel or perpendicular to the axis, up to numerical
+    // inaccuracy, must be treated as such. Otherwise we'd end up with huge
+    // ellipses instead of lines, or a missing local curve on the cylinder.
+    let is_negligible = |value: Scalar, vector: Vector<3>| {
+        value.abs()
+            <= normal.magnitude() * vector.magnitude() * RELATIVE_EPSILON
+    };
+
+    if is_negligible(d, *axis) {
+        // The plane is parallel to the cylinder's axis. It intersects the
+        // cylinder in up to two lines along that axis.
+        let angles = angles_of_zeros(a, b, c)?;
+        if angles.is_empty() {
+            return None;
+        }
+
+        let curves = angles
+            .into_iter()
+            .map(|u| {
+                let line = Line::from_origin_and_direction(
+                    section.point_from_ellipse_coords([u]),
+                    *axis,
+                );
+
+                IntersectionCurve {
+                    global: GlobalPath::Line(line),
+                    local: [
+                        Some(SurfacePath::Line(plane.project_line(&line))),
+                        Some(SurfacePath::Line(
+                            Line::from_origin_and_direction(
+                                Point::from([u, Scalar::ZERO]),
+                                Vector::unit_v(),
+                            ),
+                        )),
+                    ],
+                }
+            })
+            .collect();
+
+        return Some(SurfaceSurfaceIntersection::Curves(curves));
+    }
+
+    // The plane is not parallel to the cylinder's axis. For every `u`, there's
+    // exactly one `v` where the distance is zero. Plugging that into the
+    // definition of the cylinder results in an ellipse, that has the same
+    // parameterization as the cylinder's section.
+    let center = section.center() - *axis * (c / d);
+    let ellipse_a = section.a() - *axis * (a / d);
+    let ellipse_b = section.b() - *axis * (b / d);
+
+    let global = match Circle::try_new(center, ellipse_a, ellipse_b) {
+        Some(circle) => GlobalPath::Circle(circle),
+        None => GlobalPath::Ellipse(Ellipse::new(center, ellipse_a, ellipse_b)),
+    };
+
+    let local_plane = Circle::try_new(
+        plane.project_point(center),
+        plane.project_vector(ellipse_a),
+        plane.project_vector(ellipse_b),
+    )
+    .map(SurfacePath::Circle);
+
+    // If the plane is perpendicular to the cylinder's section, `v` is
+    // constant along the intersection.
+    let is_perpendicular =
+        is_negligible(a, section.a()) && is_negligible(b, section.b());
+    let local_cylinder = is_perpendicular.then(|| {
+        SurfacePath::Line(Line::from_origin_and_direction(
+            Point::from([Scalar::ZERO, -c / d]),
+            Vector::unit_u(),
+        ))
+    });
+
+    Some(SurfaceSurfaceIntersection::Curves(vec![
+        IntersectionCurve {
+            global,
+            local: [local_plane, local_cylinder],
+        },
+    ]))
+}
+
+fn intersect_cylinders(
+    a: &SurfaceKind,
+    b: &SurfaceKind,
+) -> Option<SurfaceSurfaceIntersection> {
+    let (
+        SurfaceKind::Cylinder {
+            section: section_a,
+            axis: axis_a,
+        },
+        SurfaceKind::Cylinder {
+            section: section_b,
+            axis: axis_b,
+        },
+    ) = (a, b)
+    else {
+        unreachable!("Expected two cylinders");
+    };
+
+    let is_negligible = |value: Scalar, a: Vector<3>, b: Vector<3>| {
+        value.abs() <= a.magnitude() * b.magnitude() * RELATIVE_EPSILON
+    };
+    let is_right_circular = |section: &Ellipse<3>, axis: &Vector<3>| {
+        Circle::try_new(section.center(), section.a(), section.b()).is_some()
+            && is_negligible(section.a().dot(axis), section.a(), *axis)
+            && is_negligible(section.b().dot(axis), section.b(), *axis)
+    };
+
+    let axes_are_parallel = axis_a.cross(axis_b).magnitude()
+        <= axis_a.magnitude() * axis_b.magnitude() * RELATIVE_EPSILON;
+    if !axes_are_parallel
+        || !is_right_circular(section_a, axis_a)
+        || !is_right_circular(section_b, axis_b)
+    {
+        todo!(
+            "Only intersections between circular cylinders with parallel axes \
+            are supported."
+        )
+    }
+
+    // Both sections are circles in planes that are perpendicular to the axes.
+    // Where they intersect, when projected into the same plane, the cylinders
+    // intersect in lines along their axes.
+    let plane = Plane::from_parametric(
+        section_a.center(),
+        section_a.a(),
+        section_a.b(),
+    );
+    let circle_a =
+        SurfacePath::Circle(Circle::new([0., 0.], [1., 0.], [0., 1.]));
+    let circle_b = SurfacePath::Circle(Circle::new(
+        plane.project_point(section_b.center()),
+        plane.project_vector(section_b.a()),
+        plane.project_vector(section_b.b()),
+    ));
+
+    let points = match (&circle_a, &circle_b).intersect()? {
+        PathPathIntersection::Points(points) => points,
+        PathPathIntersection::Coincident => {
+            return Some(SurfaceSurfaceIntersection::Coincident);
+        }
+    };
+
+    let curves = points
+        .into_iter()
+        .map(|point| {
+            let [u_a, u_b] = point.point_on_paths.map(|point| point.t);
+
+            let line = Line::from_origin_and_direction(
+                section_a.point_from_ellipse_coords([u_a]),
+                *axis_a,
+            );
+
+            // The line is parameterized like the v-axis of the first cylinder.
+            // Along the second one, it might run at a different rate.
+            let v_b = (line.origin() - section_b.center()).dot(axis_b)
+                / axis_b.dot(axis_b);
+            let rate_b = axis_a.dot(axis_b) / axis_b.dot(axis_b);
+
+            IntersectionCurve {
+                global: GlobalPath::Line(line),
+                local: [
+                    Some(SurfacePath::Line(Line::from_origin_and_direction(
+                        Point::from([u_a, Scalar::ZERO]),
+                        Vector::unit_v(),
+                    ))),
+                    Some(SurfacePath::Line(Line::from_origin_and_direction(
+                        Point::from([u_b, v_b]),
+                        Vector::from([Scalar::ZERO, rate_b]),
+                    ))),
+                ],
+            }
+        })
+        .collect();
+
+    Some(SurfaceSurfaceIntersection::Curves(curves))
+}
+
+/// Access the plane that a surface represents, if it is one
+pub(super) fn as_plane(surface: &SurfaceGeom) -> Option<Plane> {
+    match SurfaceKind::of(surface)? {
+        SurfaceKind::Plane(plane) => Some(plane),
+        SurfaceKind::Cylinder { .. } => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Point, Scalar, Transform, Vector};
+
+    use crate::geometry::{GlobalPath, SurfaceGeom, SurfacePath, SweepPath};
+
+    use super::{Intersect, IntersectionCurve, SurfaceSurfaceIntersection};
+
+    #[test]
+    fn plane_plane() {
+        let xy = SurfaceGeom::Swept {
+            u: GlobalPath::x_axis(),
+            v: SweepPath::Line(Vector::unit_y()),
+        };
+        let xz = SurfaceGeom::Swept {
+            u: GlobalPath::x_axis(),
+            v: SweepPath::Line(Vector::unit_z()),
+        };
+
+        let curves = expect_curves((&xy, &xz).intersect());
+        assert_eq!(curves.len(), 1);
+        check_curve(&curves[0], [&xy, &xz]);
+
+        assert_eq!(
+            (&xy, &xy).intersect(),
+            Some(SurfaceSurfaceIntersection::Coincident)
+        );
+    }
+
+    #[test]
+    fn plane_cylinder_along_axis() {
+        let cylinder = cylinder();
+        let plane = SurfaceGeom::Swept {
+            u: GlobalPath::line_from_points([[0.5, 0., 0.], [0.5, 1., 0.]]).0,
+            v: SweepPath::Line(Vector::unit_z()),
+        };
+
+        let curves = expect_curves((&plane, &cylinder).intersect());
+        assert_eq!(curves.len(), 2);
+        for curve in &curves {
+            assert!(matches!(curve.global, GlobalPath::Line(_)));
+            assert!(curve.local.iter().all(Option::is_some));
+            check_curve(curve, [&plane, &cylinder]);
+        }
+
+        let missing = SurfaceGeom::Swept {
+            u: GlobalPath::line_from_points([[2., 0., 0.], [2., 1., 0.]]).0,
+            v: SweepPath::Line(Vector::unit_z()),
+        };
+        assert_eq!((&missing, &cylinder).intersect(), None);
+    }
+
+    #[test]
+    fn plane_cylinder_along_axis_with_rounding_error() {
+        // Rotating the plane by a full turn should leave it where it was, but
+        // introduces small numerical errors.
+        let rotation = Transform::rotation(
+            Vector::from([1., 2., 3.]).normalize() * Scalar::TAU,
+        );
+
+        let cylinder = cylinder();
+        let plane = SurfaceGeom::Swept {
+            u: GlobalPath::line_from_points([[0.5, 0., 0.], [0.5, 1., 0.]]).0,
+            v: SweepPath::Line(rotation.transform_vector(&Vector::unit_z())),
+        };
+
+        let curves = expect_curves((&plane, &cylinder).intersect());
+        assert_eq!(curves.len(), 2);
+        for curve in &curves {
+            assert!(matches!(curve.global, GlobalPath::Line(_)));
+        }
+    }
+
+    #[test]
+    fn plane_cylinder_across_axis_with_rounding_error() {
+        let rotation = Transform::rotation(
+            Vector::from([1., 2., 3.]).normalize() * Scalar::TAU,
+        );
+
+        let cylinder = cylinder();
+        let plane = SurfaceGeom::Swept {
+            u: GlobalPath::line_from_points([[0., 0., 3.], [1., 0., 3.]]).0,
+            v: SweepPath::Line(rotation.transform_vector(&Vector::unit_y())),
+        };
+
+        let curves = expect_curves((&cylinder, &plane).intersect());
+        assert_eq!(curves.len(), 1);
+        assert!(curves[0].local.iter().all(Option::is_some));
+        check_curve(&curves[0], [&cylinder, &plane]);
+    }
+
+    #[test]
+    fn plane_cylinder_across_axis() {
+        let cylinder = cylinder();
+        let plane = SurfaceGeom::Swept {
+            u: GlobalPath::line_from_points([[0., 0., 3.], [1., 0., 3.]]).0,
+            v: SweepPath::Line(Vector::unit_y()),
+        };
+
+        let curves = expect_curves((&cylinder, &plane).intersect());
+        assert_eq!(curves.len(), 1);
+        assert!(matches!(curves[0].global, GlobalPath::Circle(_)));
+        assert!(curves[0].local.iter().all(Option::is_some));
+        check_curve(&curves[0], [&cylinder, &plane]);
+    }
+
+    #[test]
+    fn plane_cylinder_oblique() {
+        let cylinder = cylinder();
+        let plane = SurfaceGeom::Swept {
+            u: GlobalPath::line_from_points([[0., 0., 3.], [1., 0., 4.]]).0,
+            v: SweepPath::Line(Vector::unit_y()),
+        };
+
+        let curves = expect_curves((&plane, &cylinder).intersect());
+        assert_eq!(curves.len(), 1);
+        assert!(matches!(curves[0].global, GlobalPath::Ellipse(_)));
+
+        // The plane's u-vector has the same length as the major axis of the
+        // ellipse, so in the plane's coordinates, it's a circle.
+        assert!(matches!(curves[0].local[0], Some(SurfacePath::Circle(_))));
+        assert_eq!(curves[0].local[1], None);
+        check_curve(&curves[0], [&plane, &cylinder]);
+    }
+
+    #[test]
+    fn cylinder_cylinder() {
+        let a = cylinder();
+        let b = SurfaceGeom::Swept {
+            u: GlobalPath::Circle(Circle::from_center_and_radius(
+                [1., 0., 0.],
+                1.,
+            )),
+            v: SweepPath::Line(Vector::from([0., 0., -2.])),
+        };
+
+        let curves = expect_curves((&a, &b).intersect());
+        assert_eq!(curves.len(), 2);
+        for curve in &curves {
+            assert!(matches!(curve.global, GlobalPath::Line(_)));
+            check_curve(curve, [&a, &b]);
+        }
+
+        assert_eq!(
+            (&a, &a).intersect(),
+            Some(SurfaceSurfaceIntersection::Coincident)
+        );
+    }
+
+    /// A cylinder of radius 1 around the z-axis
+    fn cylinder() -> SurfaceGeom {
+        SurfaceGeom::Swept {
+            u: GlobalPath::circle_from_radius(1.),
+            v: SweepPath::Line(Vector::unit_z()),
+        }
+    }
+
+    fn expect_curves(
+        intersection: Option<SurfaceSurfaceIntersection>,
+    ) -> Vec<IntersectionCurve> {
+        let Some(SurfaceSurfaceIntersection::Curves(curves)) = intersection
+        else {
+            panic!("Expected surfaces to intersect in curves");
+        };
+        curves
+    }
+
+    /// Check that the curve lies on both surfaces, and that its local forms
+    /// match the global one
+    fn check_curve(curve: &IntersectionCurve, surfaces: [&SurfaceGeom; 2]) {
+        for t in [-1., 0., 0.5, 2., 4.] {
+            let point = curve.global.point_from_path_coords([t]);
+
+            for (surface, local) in surfaces.into_iter().zip(&curve.local) {
+                let projected = surface.point_from_surface_coords(
+                    surface.project_global_point(point),
+                );
+                assert_close(projected, point);
+
+                if let Some(local) = local {
+                    let local = local.point_from_path_coords([t]);
+                    assert_close(
+                        surface.point_from_surface_coords(local),
+                        point,
+                    );
+                }
+            }
+        }
+    }
+
+    fn assert_close(a: Point<3>, b: Point<3>) {
+        assert!((a - b).magnitude() < Scalar::from(1e-6), "{a:?} != {b:?}");
+    }
+}
@@ -0,0 +1,636 @@
+//! Compute mass properties of an object
+//!
+//! See [`ComputeMassProperties`].
+//!
+//! ## Implementation
+//!
+//! The integrals over the volume of the object are converted into integrals
+//! over its faces, using the divergence theorem. The integral over each face
+//! is then converted into an integral over the boundary of the face in surface
+//! coordinates, using Green's theorem. What remains are integrals along the
+//! paths of the face's half-edges, whose integrands are themselves integrals
+//! along the u-axis of the surface. Both are computed using Gauss-Legendre
+//! quadrature.
+//!
+//! Before the quadrature is applied, each range of integration is split at the
+//! knots of splines, and into sections that cover no more than an eighth of a
+//! turn of a circle, an ellipse, or a helix. Within those sections, the
+//! integrands are either polynomials of low degree, which the quadrature
+//! integrates exactly, or smooth enough for it to converge to floating-point
+//! precision.
+
+use fj_math::{Line, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{
+        CurveBoundary, Geometry, GlobalPath, SurfaceGeom, SurfacePath,
+        SweepPath,
+    },
+    storage::Handle,
+    topology::{Face, Solid},
+};
+
+/// Compute the mass properties of an object
+pub trait ComputeMassProperties {
+    /// Compute the mass properties, assuming a uniform density
+    ///
+    /// The properties are computed from the exact geometry of the object, not
+    /// from an approximation of it. The results are accurate to floating-point
+    /// precision, for all faces whose surfaces are built from lines, circles,
+    /// ellipses, helices, and non-rational splines of up to cubic degree.
+    ///
+    /// Other splines are integrated using the same method, which converges
+    /// quickly for them, but is not exact.
+    fn mass_properties(
+        self,
+        density: impl Into<Scalar>,
+        geometry: &Geometry,
+    ) -> MassProperties;
+}
+
+impl ComputeMassProperties for &Solid {
+    fn mass_properties(
+        self,
+        density: impl Into<Scalar>,
+        geometry: &Geometry,
+    ) -> MassProperties {
+        let density = density.into();
+        let quadrature = Quadrature::new();
+
+        let mut integrals = Integrals::default();
+        for face in self.shells().iter().flat_map(|shell| shell.faces()) {
+            let mut face = integrate_face(face, &quadrature, geometry);
+
+            // The sign of the area depends on the orientation of the face's
+            // boundary in surface coordinates. Unlike the other integrals, it
+            // doesn't depend on the direction of the surface normal, so this
+            // needs to be corrected here.
+            face.area = face.area.abs();
+
+            integrals.add(face, Scalar::ONE);
+        }
+
+        let Integrals {
+            area,
+            volume,
+            first_moment,
+            second_moment,
+        } = integrals;
+
+        let mass = volume * density;
+        let center_of_mass = if volume == Scalar::ZERO {
+            Point::origin()
+        } else {
+            Point {
+                coords: first_moment / volume,
+            }
+        };
+
+        // The second moment is relative to the origin. Move it to the center of
+        // mass, using the parallel axis theorem, then derive the inertia tensor
+        // from it.
+        let c = center_of_mass.coords;
+        let mut inertia = [[Scalar::ZERO; 3]; 3];
+        for (i, row) in inertia.iter_mut().enumerate() {
+            for (j, element) in row.iter_mut().enumerate() {
+                let moment = |i: usize, j: usize| {
+                    second_moment[i][j]
+                        - volume * c.components[i] * c.components[j]
+                };
+
+                let trace = moment(0, 0) + moment(1, 1) + moment(2, 2);
+                let identity = if i == j { trace } else { Scalar::ZERO };
+
+                *element = (identity - moment(i, j)) * density;
+            }
+        }
+
+        MassProperties {
+            volume,
+            area,
+            mass,
+            center_of_mass,
+            inertia,
+        }
+    }
+}
+
+/// The mass properties of an object
+///
+/// Returned by [`ComputeMassProperties::mass_properties`].
+#[derive(Clone, Copy, Debug)]
+pub struct MassProperties {
+    /// The volume of the object
+    pub volume: Scalar,
+
+    /// The area of the object's surface
+    pub area: Scalar,
+
+    /// The mass of the object
+    pub mass: Scalar,
+
+    /// The center of mass of the object
+    pub center_of_mass: Point<3>,
+
+    /// The inertia tensor of the object, relative to its center of mass
+    ///
+    /// The tensor is given in the global coordinate system. Its diagonal
+    /// elements are the moments of inertia around the x-, y-, and z-axis.
+    pub inertia: [[Scalar; 3]; 3],
+}
+
+/// Integrate over a face, using its boundary in surface coordinates
+///
+/// By Green's theorem, the integral of `g(u, v)` over the face equals the
+/// integral of `G(u, v)` along its boundary, with respect to `v`. Here, `G` is
+/// the integral of `g` along the u-axis, from an arbitrary reference point.
+///
+/// `g` is the integrand of the surface integral, times the length of the
+/// surface normal that [`SurfaceGeom::derivatives_at`] implies. The result
+/// is signed: The boundary of a face runs counter-clockwise in surface
+/// coordinates, if the front of the face is the side that the normal points
+/// to. Otherwise it runs clockwise, and the result is negated. Either way, the
+/// integrals are taken with respect to the normal pointing out of the front of
+/// the face.
+fn integrate_face(
+    face: &Handle<Face>,
+    quadrature: &Quadrature,
+    geometry: &Geometry,
+) -> Integrals {
+    let surface = geometry.of_surface(face.surface());
+    let [partition_u, partition_v] = Partition::of_surface(surface);
+
+    let mut integrals = Integrals::default();
+
+    for cycle in face.region().all_cycles() {
+        let half_edges = cycle
+            .half_edges()
+            .iter()
+            .map(|half_edge| geometry.of_half_edge(half_edge))
+            .collect::<Vec<_>>();
+
+        let Some(first) = half_edges.first() else {
+            continue;
+        };
+        let u_ref = first.start_position().u;
+
+        let integral_along_u = |point: Point<2>| {
+            let sections = partition_u.sections([u_ref, point.u]);
+            quadrature.integrate(sections, |u| {
+                let point = Point::from([u, point.v]);
+
+                let [du, dv] = surface.derivatives_at(point);
+                Integrals::at(
+                    surface.point_from_surface_coords(point),
+                    du.cross(&dv),
+                )
+            })
+        };
+
+        for (i, half_edge) in half_edges.iter().enumerate() {
+            let next = half_edges[(i + 1) % half_edges.len()];
+
+            // Half-edges that are adjacent in 3D are not necessarily adjacent
+            // in surface coordinates, where the surface is degenerate, like at
+            // the apex of a cone. Those gaps still need to be closed, for
+            // Green's theorem to apply.
+            let [_, end] = half_edge.boundary.inner;
+            let end = half_edge.path.point_from_path_coords(end);
+            let start_of_next = next.start_position();
+            let gap = (end != start_of_next).then(|| {
+                let (line, coords) = Line::from_points([end, start_of_next]);
+                (SurfacePath::Line(line), CurveBoundary { inner: coords })
+            });
+
+            let segments = [(half_edge.path.clone(), half_edge.boundary)]
+                .into_iter()
+                .chain(gap);
+
+            for (path, boundary) in segments {
+                let boundary = boundary.inner.map(|point| point.t);
+                let sections = Partition::of_boundary_segment(
+                    &path,
+                    boundary,
+                    &partition_u,
+                    &partition_v,
+                )
+                .sections(boundary);
+
+                let integral = quadrature.integrate(sections, |t| {
+                    let [derivative, _] = path.derivatives_at([t]);
+                    if derivative.v == Scalar::ZERO {
+                        return Integrals::default();
+                    }
+
+                    let mut integral =
+                        integral_along_u(path.point_from_path_coords([t]));
+                    integral.scale(derivative.v);
+                    integral
+                });
+
+                integrals.add(integral, Scalar::ONE);
+            }
+        }
+    }
+
+    integrals
+}
+
+/// The integrals that make up the mass properties
+///
+/// Except for the area, those are integrals over the volume of the object. To
+/// make integration over the surface possible, each of them is expressed as
+/// the divergence of a vector field, whose flux through the surface is then
+/// integrated. [`Integrals::at`] computes that flux at a point.
+#[derive(Clone, Copy, Default)]
+struct Integrals {
+    area: Scalar,
+    volume: Scalar,
+    first_moment: Vector<3>,
+    second_moment: [[Scalar; 3]; 3],
+}
+
+impl Integrals {
+    /// Compute the integrands at a point, with the provided surface normal
+    ///
+    /// The normal is not normalized. Its length is the local scale of the
+    /// surface coordinates.
+    fn at(point: Point<3>, normal: Vector<3>) -> Self {
+        let p = point.coords.components;
+        let n = normal.components;
+
+        let mut first_moment = Vector::default();
+        let mut second_moment = [[Scalar::ZERO; 3]; 3];
+
+        for i in 0..3 {
+            first_moment.components[i] = p[i] * p[i] * n[i] / 2.;
+            second_moment[i][i] = p[i] * p[i] * p[i] * n[i] / 3.;
+
+            for j in i + 1..3 {
+                let moment = p[i] * p[i] * p[j] * n[i] / 2.;
+
+                second_moment[i][j] = moment;
+                second_moment[j][i] = moment;
+            }
+        }
+
+        Self {
+            area: normal.magnitude(),
+            volume: point.coords.dot(&normal) / 3.,
+            first_moment,
+            second_moment,
+        }
+    }
+
+    fn add(&mut self, other: Self, weight: Scalar) {
+        self.area += other.area * weight;
+        self.volume += other.volume * weight;
+        self.first_moment = self.first_moment + other.first_moment * weight;
+
+        for (row, other) in
+            self.second_moment.iter_mut().zip(other.second_moment)
+        {
+            for (element, other) in row.iter_mut().zip(other) {
+                *element += other * weight;
+            }
+        }
+    }
+
+    fn scale(&mut self, factor: Scalar) {
+        let integrals = *self;
+
+        *self = Self::default();
+        self.add(integrals, factor);
+    }
+}
+
+/// The points, at which a range of integration must be split
+///
+/// Integrands are only smooth between the knots of splines, and to keep the
+/// number of quadrature nodes low, periodic integrands are split into sections
+/// that cover a fraction of their period.
+#[derive(Default)]
+struct Partition {
+    step: Option<Scalar>,
+    knots: Vec<Scalar>,
+}
+
+impl Partition {
+    /// The angle that a section of a circle, ellipse, or helix may cover
+    const ANGLE: f64 = std::f64::consts::FRAC_PI_4;
+
+    fn of_surface(surface: &SurfaceGeom) -> [Self; 2] {
+        match surface {
+            SurfaceGeom::Swept { u, v } => {
+                let v = match v {
+                    SweepPath::Line(_) => Self::default(),
+                    SweepPath::Helix(helix) => Self {
+                        step: (helix.angle() != Scalar::ZERO).then(|| {
+                            Scalar::from(Self::ANGLE) / helix.angle().abs()
+                        }),
+                        knots: Vec::new(),
+                    },
+                };
+
+                [Self::of_global_path(u), v]
+            }
+            SurfaceGeom::Ruled { a, b } => {
+                let mut u = Self::of_global_path(a);
+                u.merge(Self::of_global_path(b));
+
+                [u, Self::default()]
+            }
+        }
+    }
+
+    fn of_global_path(path: &GlobalPath) -> Self {
+        match path {
+            GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => Self {
+                step: Some(Scalar::from(Self::ANGLE)),
+                knots: Vec::new(),
+            },
+            GlobalPath::Line(_) => Self::default(),
+            GlobalPath::Spline(spline) => Self {
+                step: None,
+                knots: spline.knots().to_vec(),
+            },
+        }
+    }
+
+    /// Partition a segment of a path in surface coordinates
+    ///
+    /// Where possible, the partitions of the surface are carried over to the
+    /// path. For lines, that is exact. For circles, the sections are shortened
+    /// instead, so they don't cover more than a section of the surface.
+    fn of_boundary_segment(
+        path: &SurfacePath,
+        boundary: [Scalar; 2],
+        partition_u: &Self,
+        partition_v: &Self,
+    ) -> Self {
+        match path {
+            SurfacePath::Circle(circle) => {
+                let step = [partition_u.step, partition_v.step]
+                    .into_iter()
+                    .flatten()
+                    .map(|step| step / circle.radius())
+                    .fold(
+                        Scalar::from(Self::ANGLE),
+                        |a, b| if b < a { b } else { a },
+                    );
+
+                Self {
+                    step: Some(step),
+                    knots: Vec::new(),
+                }
+            }
+            SurfacePath::Line(_) => {
+                let [start, end] =
+                    boundary.map(|t| path.point_from_path_coords([t]));
+
+                let mut knots = Vec::new();
+                for (partition, start, end) in [
+                    (partition_u, start.u, end.u),
+                    (partition_v, start.v, end.v),
+                ] {
+                    if start == end {
+                        continue;
+                    }
+
+                    knots.extend(partition.breakpoints([start, end]).map(
+                        |breakpoint| {
+                            boundary[0]
+                                + (boundary[1] - boundary[0])
+                                    * ((breakpoint - start) / (end - start))
+                        },
+                    ));
+                }
+
+                Self { step: None, knots }
+            }
+            SurfacePath::Spline(spline) => Self {
+                step: None,
+                knots: spline.knots().to_vec(),
+            },
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.step = match (self.step, other.step) {
+            (Some(a), Some(b)) => Some(if a < b { a } else { b }),
+            (a, b) => a.or(b),
+        };
+        self.knots.extend(other.knots);
+    }
+
+    /// Compute the points within the range, at which it must be split
+    fn breakpoints(
+        &self,
+        range: [Scalar; 2],
+    ) -> impl Iterator<Item = Scalar> + '_ {
+        let [min, max] = if range[0] < range[1] {
+            range
+        } else {
+            [range[1], range[0]]
+        };
+
+        let steps = self.step.into_iter().flat_map(move |step| {
+            let first = (min / step).floor().into_f64() as i64 + 1;
+            let last = (max / step).ceil().into_f64() as i64 - 1;
+
+            (first..=last).map(move |i| step * Scalar::from(i as f64))
+        });
+
+        self.knots
+            .iter()
+            .copied()
+            .chain(steps)
+            .filter(move |&t| min < t && t < max)
+    }
+
+    /// Split the range into sections
+    ///
+    /// The sections are returned in the order from the start of the range to
+    /// its end, which might be reversed.
+    fn sections(&self, range: [Scalar; 2]) -> Vec<[Scalar; 2]> {
+        let mut breakpoints = self.breakpoints(range).collect::<Vec<_>>();
+        breakpoints.sort();
+        breakpoints.dedup();
+        if range[0] > range[1] {
+            breakpoints.reverse();
+        }
+
+        let points = [range[0]]
+            .into_iter()
+            .chain(breakpoints)
+            .chain([range[1]])
+            .collect::<Vec<_>>();
+
+        points.windows(2).map(|pair| [pair[0], pair[1]]).collect()
+    }
+}
+
+/// Gauss-Legendre quadrature
+struct Quadrature {
+    /// The nodes within `-1..1`, and their weights
+    nodes: [(Scalar, Scalar); Self::NUM_NODES],
+}
+
+impl Quadrature {
+    /// The number of nodes per section
+    ///
+    /// This integrates polynomials up to degree 23 exactly, which covers all
+    /// integrands on surfaces that are built from non-rational cubic splines.
+    const NUM_NODES: usize = 12;
+
+    /// Compute the nodes, as the roots of the Legendre polynomial
+    fn new() -> Self {
+        let n = Self::NUM_NODES as f64;
+
+        let nodes = std::array::from_fn(|i| {
+            let mut x =
+                (std::f64::consts::PI * (i as f64 + 0.75) / (n + 0.5)).cos();
+
+            loop {
+                let (p, dp) = legendre(Self::NUM_NODES, x);
+
+                let dx = p / dp;
+                x -= dx;
+
+                if dx.abs() <= f64::EPSILON {
+                    let (_, dp) = legendre(Self::NUM_NODES, x);
+                    let weight = 2. / ((1. - x * x) * dp * dp);
+
+                    break (Scalar::from(x), Scalar::from(weight));
+                }
+            }
+        });
+
+        Self { nodes }
+    }
+
+    fn integrate(
+        &self,
+        sections: Vec<[Scalar; 2]>,
+        mut f: impl FnMut(Scalar) -> Integrals,
+    ) -> Integrals {
+        let mut integral = Integrals::default();
+
+        for [a, b] in sections {
+            let center = (a + b) / 2.;
+            let half_length = (b - a) / 2.;
+
+            for &(x, weight) in &self.nodes {
+                integral.add(f(center + half_length * x), weight * half_length);
+            }
+        }
+
+        integral
+    }
+}
+
+/// Evaluate the Legendre polynomial of degree `n` and its derivative
+fn legendre(n: usize, x: f64) -> (f64, f64) {
+    let mut previous = 1.;
+    let mut current = x;
+
+    for k in 2..=n {
+        let k = k as f64;
+        let next = ((2. * k - 1.) * x * current - (k - 1.) * previous) / k;
+
+        previous = current;
+        current = next;
+    }
+
+    let derivative = n as f64 * (x * current - previous) / (x * x - 1.);
+
+    (current, derivative)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        operations::{
+            fixtures::{self, assert_close},
+            insert::Insert,
+        },
+        Core,
+    };
+
+    use super::{ComputeMassProperties, MassProperties};
+
+    #[test]
+    fn cuboid() {
+        let mut core = Core::new();
+
+        let solid = fixtures::cuboid([1., 1., 0.], [2., 3., 4.], &mut core)
+            .insert(&mut core);
+
+        let properties = solid.mass_properties(2., &core.layers.geometry);
+
+        // The cuboid has a size of 2x3x4.
+        let mass = Scalar::from(2. * 3. * 4. * 2.);
+        assert_close(properties.volume, 24.);
+        assert_close(properties.area, 2. * (2. * 3. + 2. * 4. + 3. * 4.));
+        assert_close(properties.mass, mass);
+        assert_close_to_point(&properties, [2., 2.5, 2.]);
+        assert_inertia(
+            &properties,
+            [
+                mass * (3. * 3. + 4. * 4.) / 12.,
+                mass * (2. * 2. + 4. * 4.) / 12.,
+                mass * (2. * 2. + 3. * 3.) / 12.,
+            ],
+        );
+    }
+
+    #[test]
+    fn cylinder() {
+        let mut core = Core::new();
+
+        let solid = fixtures::cylinder([1., 2., 0.], 1., 2., &mut core)
+            .insert(&mut core);
+
+        let properties = solid.mass_properties(3., &core.layers.geometry);
+
+        // The cylinder has a radius of 1 and a height of 2.
+        let volume = Scalar::PI * 2.;
+        let mass = volume * 3.;
+        assert_close(properties.volume, volume);
+        assert_close(properties.area, Scalar::PI * (2. * 2. + 2.));
+        assert_close(properties.mass, mass);
+        assert_close_to_point(&properties, [1., 2., 1.]);
+        assert_inertia(
+            &properties,
+            [
+                mass * (3. + 2. * 2.) / 12.,
+                mass * (3. + 2. * 2.) / 12.,
+                mass / 2.,
+            ],
+        );
+    }
+
+    fn assert_close_to_point(
+        properties: &MassProperties,
+        point: impl Into<Point<3>>,
+    ) {
+        let point = point.into();
+        let center_of_mass = properties.center_of_mass;
+
+        assert!(
+            (center_of_mass - point).magnitude() < Scalar::from(1e-9),
+            "{center_of_mass:?} != {point:?}"
+        );
+    }
+
+    fn assert_inertia(properties: &MassProperties, diagonal: [Scalar; 3]) {
+        for (i, row) in properties.inertia.iter().enumerate() {
+            for (j, &element) in row.iter().enumerate() {
+                let expected = if i == j { diagonal[i] } else { Scalar::ZERO };
+                assert_close(element, expected);
+            }
+        }
+    }
+}
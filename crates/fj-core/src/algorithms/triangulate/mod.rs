@@ -1,16 +1,22 @@
 //! Shape triangulation
 
 mod delaunay;
-mod polygon;
+pub(crate) mod polygon;
+
+use std::collections::BTreeSet;
 
 use fj_interop::Mesh;
 use fj_math::Point;
 
-use crate::{operations::presentation::GetColor, Core};
+use crate::{operations::presentation::GetColor, topology::Handedness, Core};
 
 use self::polygon::Polygon;
 
-use super::approx::{face::FaceApprox, Approx, Tolerance};
+use super::approx::{
+    cycle::CycleApprox,
+    face::{FaceApprox, RegionApprox},
+    Approx, ApproxPoint, Tolerance,
+};
 
 /// Triangulate a shape
 pub trait Triangulate: Sized {
@@ -31,7 +37,8 @@ pub trait Triangulate: Sized {
 impl<T> Triangulate for (T, Tolerance)
 where
     T: Approx,
-    T::Approximation: IntoIterator<Item = FaceApprox>,
+    T::Approximation: IntoIterator,
+    <T::Approximation as IntoIterator>::Item: Triangulate,
 {
     fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>, core: &mut Core) {
         let (approx, tolerance) = self;
@@ -46,34 +53,78 @@ where
 
 impl Triangulate for FaceApprox {
     fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>, core: &mut Core) {
-        let face_as_polygon = Polygon::new()
-            .with_exterior(
-                self.exterior
-                    .points()
-                    .into_iter()
-                    .map(|point| point.local_form),
-            )
-            .with_interiors(self.interiors.iter().map(|interior| {
-                interior.points().into_iter().map(|point| point.local_form)
-            }));
-
-        let cycles = [self.exterior].into_iter().chain(self.interiors);
-        let mut triangles =
-            delaunay::triangulate(cycles, self.coord_handedness);
-        triangles.retain(|triangle| {
-            face_as_polygon
-                .contains_triangle(triangle.map(|point| point.point_surface))
-        });
-
         let color = self.face.region().get_color(core).unwrap_or_default();
 
-        for triangle in triangles {
-            let points = triangle.map(|point| point.point_global);
-            mesh.push_triangle(points, color);
+        for triangle in self.triangles() {
+            mesh.push_triangle(triangle, color);
         }
     }
 }
 
+impl Triangulate for RegionApprox {
+    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>, core: &mut Core) {
+        let color = self.region.get_color(core).unwrap_or_default();
+
+        for triangle in triangulate_cycles(
+            &self.exterior,
+            &self.interiors,
+            &self.surface_points,
+            self.coord_handedness,
+        ) {
+            mesh.push_triangle(triangle, color);
+        }
+    }
+}
+
+impl FaceApprox {
+    /// Triangulate the face approximation
+    ///
+    /// Returns the triangles in global coordinates. Their winding matches the
+    /// orientation of the face.
+    ///
+    /// Most callers should prefer [`Triangulate`], which also takes care of
+    /// approximating the face and adding the triangles to a mesh.
+    pub fn triangles(&self) -> Vec<[Point<3>; 3]> {
+        triangulate_cycles(
+            &self.exterior,
+            &self.interiors,
+            &self.surface_points,
+            self.coord_handedness,
+        )
+    }
+}
+
+fn triangulate_cycles(
+    exterior: &CycleApprox,
+    interiors: &BTreeSet<CycleApprox>,
+    surface_points: &BTreeSet<ApproxPoint<2>>,
+    coord_handedness: Handedness,
+) -> Vec<[Point<3>; 3]> {
+    let face_as_polygon = Polygon::new()
+        .with_exterior(
+            exterior.points().into_iter().map(|point| point.local_form),
+        )
+        .with_interiors(interiors.iter().map(|interior| {
+            interior.points().into_iter().map(|point| point.local_form)
+        }));
+
+    let cycles = [exterior].into_iter().chain(interiors);
+    let mut triangles = delaunay::triangulate(
+        cycles,
+        surface_points.iter().copied(),
+        coord_handedness,
+    );
+    triangles.retain(|triangle| {
+        face_as_polygon
+            .contains_triangle(triangle.map(|point| point.point_surface))
+    });
+
+    triangles
+        .into_iter()
+        .map(|triangle| triangle.map(|point| point.point_global))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use fj_interop::Mesh;
@@ -81,13 +132,17 @@ mod tests {
 
     use crate::{
         algorithms::approx::{Approx, Tolerance},
+        geometry::GlobalPath,
         operations::{
-            build::{BuildCycle, BuildFace},
+            build::{
+                BuildCycle, BuildFace, BuildRegion, BuildSketch, BuildSurface,
+            },
             insert::Insert,
-            update::{UpdateFace, UpdateRegion},
+            reverse::Reverse,
+            update::{UpdateFace, UpdateRegion, UpdateSketch},
         },
         storage::Handle,
-        topology::{Cycle, Face},
+        topology::{Cycle, Face, Region, Sketch, Surface},
         Core,
     };
 
@@ -304,6 +359,150 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn hole_in_curved_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = Surface::from_uv(
+            GlobalPath::circle_from_radius(1.),
+            [0., 0., 2.],
+            &mut core,
+        );
+
+        let face = Face::unbound(surface.clone(), &mut core)
+            .update_region(
+                |region, core| {
+                    region
+                        .update_exterior(
+                            |_, core| {
+                                Cycle::polygon(
+                                    [[0., 0.], [3., 0.], [3., 1.], [0., 1.]],
+                                    surface.clone(),
+                                    core,
+                                )
+                            },
+                            core,
+                        )
+                        .add_interiors(
+                            [Cycle::circle(
+                                [1.5, 0.5],
+                                0.25,
+                                surface.clone(),
+                                core,
+                            )
+                            .reverse(core)],
+                            core,
+                        )
+                },
+                &mut core,
+            )
+            .insert(&mut core);
+
+        let triangles = triangulate(face, &mut core)?;
+        assert!(triangles.triangles().count() > 0);
+
+        // All points of the triangulation must be on the cylinder.
+        for triangle in triangles.triangles() {
+            for point in triangle.inner.points() {
+                let distance_from_axis = point.coords.xy().magnitude();
+                assert!((distance_from_axis - 1.).abs() < 1e-12.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn patch_of_sphere() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let center = Point::from([0., 0., 1.]);
+        let radius = 2.;
+        let surface = Surface::sphere(center, radius, &mut core);
+
+        let face = Face::unbound(surface.clone(), &mut core)
+            .update_region(
+                |region, core| {
+                    region.update_exterior(
+                        |_, core| {
+                            Cycle::polygon(
+                                [
+                                    [0.5, 0.],
+                                    [2.5, 0.],
+                                    [2.5, 0.25],
+                                    [0.5, 0.25],
+                                ],
+                                surface,
+                                core,
+                            )
+                        },
+                        core,
+                    )
+                },
+                &mut core,
+            )
+            .insert(&mut core);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let triangles = face
+            .approx(tolerance, &core.layers.geometry)
+            .triangulate(&mut core);
+
+        // The sphere is curved in both directions, so the face must be
+        // approximated with points in its interior.
+        assert!(triangles.triangles().count() > 2);
+
+        // All points of the triangulation must be on the sphere.
+        for triangle in triangles.triangles() {
+            for point in triangle.inner.points() {
+                let distance_from_center = (point - center).magnitude();
+                assert!((distance_from_center - radius).abs() < 1e-12.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn sketch() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let sketch = Sketch::empty(&core.layers.topology).add_regions(
+            [
+                Region::polygon(
+                    [[0., 0.], [1., 0.], [1., 1.], [0., 1.]],
+                    core.layers.topology.surfaces.space_2d(),
+                    &mut core,
+                ),
+                Region::circle(
+                    [3., 0.],
+                    1.,
+                    core.layers.topology.surfaces.space_2d(),
+                    &mut core,
+                ),
+            ],
+            &mut core,
+        );
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let triangles = (&sketch, tolerance).triangulate(&mut core);
+
+        // Sketches in 2D space are placed in the xy-plane.
+        let [a, b, c, d] = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]]
+            .map(|point| Point::from(point).to_xyz());
+        assert!(
+            triangles.contains_triangle([a, b, c])
+                || triangles.contains_triangle([a, b, d])
+        );
+        assert!(triangles.triangles().all(|triangle| triangle
+            .inner
+            .points()
+            .iter()
+            .all(|point| point.z == Scalar::ZERO)));
+
+        Ok(())
+    }
+
     fn triangulate(
         face: Handle<Face>,
         core: &mut Core,
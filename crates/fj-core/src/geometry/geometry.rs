@@ -7,7 +7,9 @@ use crate::{
     topology::{Curve, HalfEdge, Surface, Topology},
 };
 
-use super::{CurveGeom, GlobalPath, HalfEdgeGeom, LocalCurveGeom, SurfaceGeom};
+use super::{
+    CurveGeom, GlobalPath, HalfEdgeGeom, LocalCurveGeom, SurfaceGeom, SweepPath,
+};
 
 /// Geometric data that is associated with topological objects
 pub struct Geometry {
@@ -39,23 +41,23 @@ impl Geometry {
 
         self_.define_surface_inner(
             self_.xy_plane.clone(),
-            SurfaceGeom {
+            SurfaceGeom::Swept {
                 u: GlobalPath::x_axis(),
-                v: Vector::unit_y(),
+                v: SweepPath::Line(Vector::unit_y()),
             },
         );
         self_.define_surface_inner(
             self_.xz_plane.clone(),
-            SurfaceGeom {
+            SurfaceGeom::Swept {
                 u: GlobalPath::x_axis(),
-                v: Vector::unit_z(),
+                v: SweepPath::Line(Vector::unit_z()),
             },
         );
         self_.define_surface_inner(
             self_.yz_plane.clone(),
-            SurfaceGeom {
+            SurfaceGeom::Swept {
                 u: GlobalPath::y_axis(),
-                v: Vector::unit_z(),
+                v: SweepPath::Line(Vector::unit_z()),
             },
         );
 
@@ -134,6 +136,13 @@ impl Geometry {
             .expect("Expected geometry of surface to be defined")
     }
 
+    /// # Indicate whether the provided surface represents 2D space
+    ///
+    /// 2D space is not located in 3D space, and has no geometry.
+    pub fn is_space_2d(&self, surface: &Handle<Surface>) -> bool {
+        surface == &self.space_2d
+    }
+
     /// Access the geometry of the xy-plane
     pub fn xy_plane(&self) -> &SurfaceGeom {
         self.of_surface(&self.xy_plane)
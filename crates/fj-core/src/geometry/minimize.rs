@@ -0,0 +1,130 @@
+//! Numerical minimization of functions of a single variable
+//!
+//! See [`minimize`].
+
+use fj_math::Scalar;
+
+/// The domain of a function that is passed to [`minimize`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Domain {
+    /// The function is only defined within the search range
+    Bounded,
+
+    /// The function repeats, with the search range being one period
+    Periodic,
+
+    /// The function is defined everywhere
+    ///
+    /// The search range is just a starting point. If the minimum seems to be
+    /// outside of it, the range is expanded.
+    Unbounded,
+}
+
+/// Find the global minimum of a function within a range
+///
+/// The function is sampled within the range, to find the neighborhood of its
+/// global minimum. Then the location of the minimum is refined, using a
+/// golden-section search.
+///
+/// This is only reliable for functions that are smooth and don't have multiple
+/// minima between two neighboring samples. This is true for distance functions
+/// of the paths and surfaces that are currently supported.
+///
+/// For periodic functions, the returned location is normalized into the
+/// range.
+pub fn minimize(
+    f: impl Fn(Scalar) -> Scalar,
+    range: [Scalar; 2],
+    domain: Domain,
+) -> Scalar {
+    const NUM_SAMPLES: u64 = 64;
+    const MAX_EXPANSIONS: usize = 64;
+
+    let [mut min, mut max] = range;
+
+    let mut expansions = 0;
+    let (best, step) = loop {
+        let step = (max - min) / Scalar::from_u64(NUM_SAMPLES - 1);
+
+        let (index, best) = (0..NUM_SAMPLES)
+            .map(|i| min + step * Scalar::from_u64(i))
+            .enumerate()
+            .min_by_key(|&(_, x)| f(x))
+            .expect("Sampling range always produces samples");
+
+        let is_at_edge = index == 0 || index as u64 == NUM_SAMPLES - 1;
+        if domain != Domain::Unbounded
+            || !is_at_edge
+            || expansions >= MAX_EXPANSIONS
+        {
+            break (best, step);
+        }
+
+        // The minimum might be outside of the range. Expand the range towards
+        // it, and try again.
+        let width = max - min;
+        min = best - width;
+        max = best + width;
+        expansions += 1;
+    };
+
+    let [mut a, mut b] = [best - step, best + step];
+    if domain == Domain::Bounded {
+        a = a.max(min);
+        b = b.min(max);
+    }
+
+    let ratio = Scalar::from((5_f64.sqrt() - 1.) / 2.);
+    let mut c = b - (b - a) * ratio;
+    let mut d = a + (b - a) * ratio;
+    let mut f_c = f(c);
+    let mut f_d = f(d);
+
+    for _ in 0..128 {
+        if f_c < f_d {
+            b = d;
+            d = c;
+            f_d = f_c;
+            c = b - (b - a) * ratio;
+            f_c = f(c);
+        } else {
+            a = c;
+            c = d;
+            f_c = f_d;
+            d = a + (b - a) * ratio;
+            f_d = f(d);
+        }
+    }
+
+    let x = (a + b) / 2.;
+
+    if domain == Domain::Periodic {
+        let width = max - min;
+        x - width * ((x - min) / width).floor()
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use super::{minimize, Domain};
+
+    #[test]
+    fn minimize_unbounded() {
+        let f = |x: Scalar| (x - 10.) * (x - 10.);
+        let x = minimize(f, [Scalar::ZERO, Scalar::ONE], Domain::Unbounded);
+
+        assert!((x - 10.).abs() < Scalar::from(1e-9));
+    }
+
+    #[test]
+    fn minimize_periodic() {
+        let f = |x: Scalar| -x.cos();
+        let x = minimize(f, [Scalar::PI, Scalar::PI * 3.], Domain::Periodic);
+
+        assert!((x - Scalar::TAU).abs() < Scalar::from(1e-6));
+    }
+}
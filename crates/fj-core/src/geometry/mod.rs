@@ -4,8 +4,10 @@ mod boundary;
 mod curve;
 mod geometry;
 mod half_edge;
+pub(crate) mod minimize;
 mod path;
 mod surface;
+mod sweep_path;
 
 pub use self::{
     boundary::{CurveBoundary, CurveBoundaryElement},
@@ -13,5 +15,6 @@ pub use self::{
     geometry::Geometry,
     half_edge::HalfEdgeGeom,
     path::{GlobalPath, SurfacePath},
-    surface::SurfaceGeom,
+    surface::{Curvature, SurfaceGeom},
+    sweep_path::{Helix, SweepPath},
 };
@@ -2,16 +2,23 @@
 //!
 //! See [`SurfacePath`] and [`GlobalPath`].
 
-use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+use fj_math::{
+    Circle, Ellipse, Line, Point, Scalar, Spline, Transform, Vector,
+};
+
+use super::minimize::{minimize, Domain};
 
 /// A path through surface (2D) space
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum SurfacePath {
     /// A circle
     Circle(Circle<2>),
 
     /// A line
     Line(Line<2>),
+
+    /// A spline
+    Spline(Spline<2>),
 }
 
 impl SurfacePath {
@@ -58,6 +65,16 @@ impl SurfacePath {
         Self::Line(Line::from_points_with_line_coords(points))
     }
 
+    /// Build a spline from the given control points
+    ///
+    /// See [`Spline::from_control_points`].
+    pub fn spline_from_control_points(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<2>>>,
+    ) -> Self {
+        Self::Spline(Spline::from_control_points(degree, control_points))
+    }
+
     /// Convert a point on the path into surface coordinates
     pub fn point_from_path_coords(
         &self,
@@ -66,6 +83,65 @@ impl SurfacePath {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
+            Self::Spline(spline) => spline.point_from_spline_coords(point),
+        }
+    }
+
+    /// Compute the first and second derivative at the provided path coordinate
+    pub fn derivatives_at(&self, point: impl Into<Point<1>>) -> [Vector<2>; 2] {
+        let point = point.into();
+
+        match self {
+            Self::Circle(circle) => {
+                let (sin, cos) = point.t.sin_cos();
+
+                let first = circle.b() * cos - circle.a() * sin;
+                let second = -(circle.a() * cos + circle.b() * sin);
+
+                [first, second]
+            }
+            Self::Line(line) => [line.direction(), Vector::default()],
+            Self::Spline(spline) => spline.derivatives_at(point),
+        }
+    }
+
+    /// Find the point on the path that is closest to the provided point
+    ///
+    /// Returns the path coordinate of that point. For circles, that coordinate
+    /// is in the range `0..TAU`.
+    pub fn project_point(&self, point: impl Into<Point<2>>) -> Point<1> {
+        let point = point.into();
+
+        match self {
+            Self::Circle(circle) => {
+                let vector = point - circle.center();
+                let angle = Scalar::atan2(
+                    vector.dot(&circle.b().normalize()),
+                    vector.dot(&circle.a().normalize()),
+                );
+
+                let angle = if angle < Scalar::ZERO {
+                    angle + Scalar::TAU
+                } else {
+                    angle
+                };
+
+                Point::from([angle])
+            }
+            Self::Line(line) => line.point_to_line_coords(point),
+            Self::Spline(spline) => {
+                let t = minimize(
+                    |t| {
+                        let distance =
+                            spline.point_from_spline_coords([t]) - point;
+                        distance.dot(&distance)
+                    },
+                    spline.domain(),
+                    Domain::Bounded,
+                );
+
+                Point::from([t])
+            }
         }
     }
 
@@ -75,18 +151,29 @@ impl SurfacePath {
         match self {
             Self::Circle(circle) => Self::Circle(circle.reverse()),
             Self::Line(line) => Self::Line(line.reverse()),
+            Self::Spline(spline) => Self::Spline(spline.reverse()),
         }
     }
 }
 
 /// A path through global (3D) space
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum GlobalPath {
     /// A circle
     Circle(Circle<3>),
 
+    /// An ellipse
+    ///
+    /// Uses the same coordinate system as [`GlobalPath::Circle`]. Circles
+    /// become ellipses, when they are transformed by a transform that doesn't
+    /// preserve angles.
+    Ellipse(Ellipse<3>),
+
     /// A line
     Line(Line<3>),
+
+    /// A spline
+    Spline(Spline<3>),
 }
 
 impl GlobalPath {
@@ -131,11 +218,23 @@ impl GlobalPath {
         (Self::Line(line), coords)
     }
 
+    /// Build a spline from the given control points
+    ///
+    /// See [`Spline::from_control_points`].
+    pub fn spline_from_control_points(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<3>>>,
+    ) -> Self {
+        Self::Spline(Spline::from_control_points(degree, control_points))
+    }
+
     /// Access the origin of the path's coordinate system
     pub fn origin(&self) -> Point<3> {
         match self {
             Self::Circle(circle) => circle.center() + circle.a(),
+            Self::Ellipse(ellipse) => ellipse.center() + ellipse.a(),
             Self::Line(line) => line.origin(),
+            Self::Spline(spline) => spline.point_from_spline_coords([0.]),
         }
     }
 
@@ -146,29 +245,121 @@ impl GlobalPath {
     ) -> Point<3> {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
+            Self::Ellipse(ellipse) => ellipse.point_from_ellipse_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
+            Self::Spline(spline) => spline.point_from_spline_coords(point),
+        }
+    }
+
+    /// Compute the first and second derivative at the provided path coordinate
+    pub fn derivatives_at(&self, point: impl Into<Point<1>>) -> [Vector<3>; 2] {
+        let point = point.into();
+
+        match self {
+            Self::Circle(circle) => {
+                let (sin, cos) = point.t.sin_cos();
+
+                let first = circle.b() * cos - circle.a() * sin;
+                let second = -(circle.a() * cos + circle.b() * sin);
+
+                [first, second]
+            }
+            Self::Ellipse(ellipse) => {
+                let (sin, cos) = point.t.sin_cos();
+
+                let first = ellipse.b() * cos - ellipse.a() * sin;
+                let second = -(ellipse.a() * cos + ellipse.b() * sin);
+
+                [first, second]
+            }
+            Self::Line(line) => [line.direction(), Vector::default()],
+            Self::Spline(spline) => spline.derivatives_at(point),
+        }
+    }
+
+    /// Find the point on the path that is closest to the provided point
+    ///
+    /// Returns the path coordinate of that point. For circles and ellipses,
+    /// that coordinate is in the range `0..TAU`.
+    pub fn project_point(&self, point: impl Into<Point<3>>) -> Point<1> {
+        let point = point.into();
+
+        match self {
+            Self::Circle(circle) => {
+                let vector = point - circle.center();
+                let angle = Scalar::atan2(
+                    vector.dot(&circle.b().normalize()),
+                    vector.dot(&circle.a().normalize()),
+                );
+
+                let angle = if angle < Scalar::ZERO {
+                    angle + Scalar::TAU
+                } else {
+                    angle
+                };
+
+                Point::from([angle])
+            }
+            Self::Ellipse(ellipse) => ellipse.point_to_ellipse_coords(point),
+            Self::Line(line) => line.point_to_line_coords(point),
+            Self::Spline(spline) => {
+                let t = minimize(
+                    |t| {
+                        let distance =
+                            spline.point_from_spline_coords([t]) - point;
+                        distance.dot(&distance)
+                    },
+                    spline.domain(),
+                    Domain::Bounded,
+                );
+
+                Point::from([t])
+            }
         }
     }
 
     /// Convert a vector on the path into global coordinates
+    ///
+    /// ## Implementation Note
+    ///
+    /// Converting vectors on splines is not supported, as the result would
+    /// depend on the location of the vector.
     pub fn vector_from_path_coords(
         &self,
         vector: impl Into<Vector<1>>,
     ) -> Vector<3> {
         match self {
             Self::Circle(circle) => circle.vector_from_circle_coords(vector),
+            Self::Ellipse(ellipse) => {
+                ellipse.vector_from_ellipse_coords(vector)
+            }
             Self::Line(line) => line.vector_from_line_coords(vector),
+            Self::Spline(_) => {
+                todo!("Converting vectors on splines is not supported.")
+            }
         }
     }
 
     /// Transform the path
+    ///
+    /// If the transform doesn't preserve angles, circles become ellipses.
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
         match self {
             Self::Circle(curve) => {
-                Self::Circle(transform.transform_circle(&curve))
+                if transform.preserves_angles() {
+                    Self::Circle(transform.transform_circle(&curve))
+                } else {
+                    Self::Ellipse(transform.transform_ellipse(&curve.into()))
+                }
+            }
+            Self::Ellipse(curve) => {
+                Self::Ellipse(transform.transform_ellipse(&curve))
             }
             Self::Line(curve) => Self::Line(transform.transform_line(&curve)),
+            Self::Spline(curve) => {
+                Self::Spline(transform.transform_spline(&curve))
+            }
         }
     }
 }
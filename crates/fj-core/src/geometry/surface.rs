@@ -1,79 +1,802 @@
 //! The geometry that defines a surface
 
-use fj_math::{Line, Plane, Point, Transform, Vector};
+use fj_math::{Circle, Ellipse, Line, Plane, Point, Scalar, Transform, Vector};
 
-use super::GlobalPath;
+use super::{
+    minimize::{minimize, Domain},
+    GlobalPath, Helix, SurfacePath, SweepPath,
+};
 
 /// The geometry that defines a surface
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
-pub struct SurfaceGeom {
-    /// The u-axis of the surface
-    pub u: GlobalPath,
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SurfaceGeom {
+    /// A surface that is created by sweeping a path along another path
+    ///
+    /// If the sweep path is a straight line, the result is a translational
+    /// surface, like a plane or a cylinder. If it is a helix, the result is a
+    /// helical surface, or a surface of revolution, if the helix doesn't
+    /// advance along its axis.
+    Swept {
+        /// The u-axis of the surface
+        u: GlobalPath,
+
+        /// The v-axis of the surface
+        ///
+        /// This is the path that the u-axis is swept along, to create the
+        /// surface.
+        v: SweepPath,
+    },
 
-    /// The v-axis of the surface
-    pub v: Vector<3>,
+    /// A ruled surface that connects two paths with straight lines
+    ///
+    /// Both paths share the u-coordinate of the surface. Every point of `a` is
+    /// connected to the point of `b` with the same coordinate, by a straight
+    /// line along the v-axis. `a` is located at `v = 0`, `b` at `v = 1`.
+    Ruled {
+        /// The path at the start of the v-axis
+        a: GlobalPath,
+
+        /// The path at the end of the v-axis
+        b: GlobalPath,
+    },
 }
 
 impl SurfaceGeom {
+    /// Construct a sphere
+    ///
+    /// The sphere is a surface of revolution around an axis that goes through
+    /// `center`, parallel to the z-axis. Its u-coordinate is the angle along a
+    /// meridian, starting at the top of the sphere (`0`) and reaching the
+    /// bottom at `PI`. Its v-coordinate goes around the axis, with a full turn
+    /// from `0` to `1`, starting at the meridian in positive x-direction.
+    ///
+    /// The normal of the sphere points outward.
+    pub fn sphere(
+        center: impl Into<Point<3>>,
+        radius: impl Into<Scalar>,
+    ) -> Self {
+        let center = center.into();
+        let radius = radius.into();
+
+        let meridian = Circle::new(
+            center,
+            Vector::unit_z() * radius,
+            Vector::unit_x() * radius,
+        );
+
+        Self::revolution(GlobalPath::Circle(meridian), center, Scalar::TAU)
+    }
+
+    /// Construct a cone
+    ///
+    /// The cone has its apex at `apex` and opens towards the positive
+    /// z-direction. `half_angle` is the angle between the cone's axis and its
+    /// surface. The u-coordinate is the distance from the apex, along the
+    /// surface. The v-coordinate goes around the axis, with a full turn from
+    /// `0` to `1`, starting in positive x-direction. It moves clockwise, as
+    /// viewed from above, so the normal of the cone points outward.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `half_angle` is not between zero and `PI / 2` (exclusive).
+    pub fn cone(
+        apex: impl Into<Point<3>>,
+        half_angle: impl Into<Scalar>,
+    ) -> Self {
+        let apex = apex.into();
+        let half_angle = half_angle.into();
+
+        assert!(
+            half_angle > Scalar::ZERO && half_angle < Scalar::PI / 2.,
+            "Half-angle of cone must be between zero and 90 degrees"
+        );
+
+        let (sin, cos) = half_angle.sin_cos();
+        let generator = Line::from_origin_and_direction(
+            apex,
+            Vector::unit_z() * cos + Vector::unit_x() * sin,
+        );
+
+        Self::revolution(GlobalPath::Line(generator), apex, -Scalar::TAU)
+    }
+
+    /// Construct a torus
+    ///
+    /// The torus is a surface of revolution around an axis that goes through
+    /// `center`, parallel to the z-axis. Its tube is a circle with
+    /// `minor_radius`, whose center is `major_radius` away from the axis.
+    ///
+    /// The u-coordinate is the angle around the tube, starting at the outside
+    /// of the torus and moving downwards from there. The v-coordinate goes
+    /// around the axis, with a full turn from `0` to `1`, starting in positive
+    /// x-direction.
+    ///
+    /// The normal of the torus points outward.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `minor_radius` is not positive, or not smaller than
+    /// `major_radius`.
+    pub fn torus(
+        center: impl Into<Point<3>>,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+    ) -> Self {
+        let center = center.into();
+        let major_radius = major_radius.into();
+        let minor_radius = minor_radius.into();
+
+        assert!(
+            minor_radius > Scalar::ZERO && minor_radius < major_radius,
+            "Minor radius of torus must be positive and smaller than the \
+            major radius"
+        );
+
+        let tube = Circle::new(
+            center + Vector::unit_x() * major_radius,
+            Vector::unit_x() * minor_radius,
+            -Vector::unit_z() * minor_radius,
+        );
+
+        Self::revolution(GlobalPath::Circle(tube), center, Scalar::TAU)
+    }
+
+    /// Construct a surface of revolution around a vertical axis
+    fn revolution(u: GlobalPath, center: Point<3>, angle: Scalar) -> Self {
+        let axis = Line::from_origin_and_direction(center, Vector::unit_z());
+
+        Self::Swept {
+            u,
+            v: SweepPath::Helix(Helix::new(axis, angle, Scalar::ZERO)),
+        }
+    }
+
     /// Convert a point in surface coordinates to model coordinates
     pub fn point_from_surface_coords(
         &self,
         point: impl Into<Point<2>>,
     ) -> Point<3> {
         let point = point.into();
-        self.u.point_from_path_coords([point.u])
-            + self.path_to_line().vector_from_line_coords([point.v])
+
+        match self {
+            Self::Swept { u, v } => {
+                v.point_at(u.point_from_path_coords([point.u]), point.v)
+            }
+            Self::Ruled { a, b } => {
+                let a = a.point_from_path_coords([point.u]);
+                let b = b.point_from_path_coords([point.u]);
+
+                a + (b - a) * point.v
+            }
+        }
     }
 
     /// Convert a vector in surface coordinates to model coordinates
+    ///
+    /// ## Implementation Note
+    ///
+    /// Converting vectors on helical and ruled surfaces is not supported yet,
+    /// as the result would depend on the location of the vector.
     pub fn vector_from_surface_coords(
         &self,
         vector: impl Into<Vector<2>>,
     ) -> Vector<3> {
         let vector = vector.into();
-        self.u.vector_from_path_coords([vector.u])
-            + self.path_to_line().vector_from_line_coords([vector.v])
+
+        let Self::Swept {
+            u,
+            v: SweepPath::Line(v),
+        } = self
+        else {
+            todo!(
+                "Converting vectors from helical or ruled surface coordinates \
+                is not supported yet."
+            )
+        };
+
+        u.vector_from_path_coords([vector.u]) + *v * vector.v
+    }
+
+    /// Compute the first derivatives at the provided surface coordinates
+    ///
+    /// Returns the derivatives along the u-axis and the v-axis, in that order.
+    pub fn derivatives_at(&self, point: impl Into<Point<2>>) -> [Vector<3>; 2] {
+        let (first, _) = self.all_derivatives_at(point.into());
+        first
     }
 
-    fn path_to_line(&self) -> Line<3> {
-        Line::from_origin_and_direction(self.u.origin(), self.v)
+    /// Compute the normal at the provided surface coordinates
+    ///
+    /// The normal is the normalized cross product of the derivatives along the
+    /// u-axis and the v-axis. It points towards the side of the surface, from
+    /// which its coordinate system appears right-handed.
+    ///
+    /// Returns `None`, if the surface is degenerate at the provided point, and
+    /// the normal is not defined there. This is the case for the poles of a
+    /// sphere, or the apex of a cone.
+    pub fn normal_at(&self, point: impl Into<Point<2>>) -> Option<Vector<3>> {
+        let [u, v] = self.derivatives_at(point);
+        normal_from_derivatives(u, v)
+    }
+
+    /// Compute the curvature at the provided surface coordinates
+    ///
+    /// The curvature is positive, where the surface curves away from the
+    /// direction of its normal, like a sphere with normals that point outward.
+    /// See [`SurfaceGeom::normal_at`].
+    ///
+    /// Returns `None`, if the surface is degenerate at the provided point.
+    pub fn curvature_at(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Option<Curvature> {
+        let ([u, v], [uu, uv, vv]) = self.all_derivatives_at(point.into());
+        let normal = normal_from_derivatives(u, v)?;
+
+        // Coefficients of the first fundamental form...
+        let e = u.dot(&u);
+        let f = u.dot(&v);
+        let g = v.dot(&v);
+
+        // ...and the second fundamental form. Those are negated, to get the
+        // sign convention described above.
+        let l = -uu.dot(&normal);
+        let m = -uv.dot(&normal);
+        let n = -vv.dot(&normal);
+
+        // The principal curvatures and their directions are the eigenvalues
+        // and eigenvectors of the shape operator, `I⁻¹ II`.
+        let det = e * g - f * f;
+        let s11 = (g * l - f * m) / det;
+        let s12 = (g * m - f * n) / det;
+        let s21 = (e * m - f * l) / det;
+        let s22 = (e * n - f * m) / det;
+
+        // Computing the discriminant from the differences, rather than from
+        // the mean and Gaussian curvature, keeps it accurate where both
+        // principal curvatures are equal.
+        let mean = (s11 + s22) / 2.;
+        let half_difference = (s11 - s22) / 2.;
+        let discriminant =
+            (half_difference * half_difference + s12 * s21).max(Scalar::ZERO);
+        let offset = Scalar::from(discriminant.into_f64().sqrt());
+
+        let principal = [mean - offset, mean + offset];
+        let directions = principal.map(|k| {
+            // Both of these are eigenvectors, unless they are zero. Pick the
+            // larger one, as that is numerically more robust.
+            let a = [s12, k - s11];
+            let b = [k - s22, s21];
+            let [x, y] = if a[0].abs() + a[1].abs() >= b[0].abs() + b[1].abs() {
+                a
+            } else {
+                b
+            };
+
+            let direction = u * x + v * y;
+            if direction.magnitude() == Scalar::ZERO {
+                // All directions are principal directions at this point.
+                None
+            } else {
+                Some(direction.normalize())
+            }
+        });
+
+        let directions = match directions {
+            [Some(a), Some(b)] if offset > Scalar::ZERO => [a, b],
+            _ => {
+                // The curvature is the same in all directions. Any pair of
+                // orthogonal directions is valid.
+                let a = u.normalize();
+                [a, normal.cross(&a)]
+            }
+        };
+
+        Some(Curvature {
+            principal,
+            directions,
+        })
+    }
+
+    /// Compute the first and second derivatives at the provided point
+    ///
+    /// Returns `[u, v]` and `[uu, uv, vv]`.
+    fn all_derivatives_at(
+        &self,
+        point: Point<2>,
+    ) -> ([Vector<3>; 2], [Vector<3>; 3]) {
+        match self {
+            Self::Swept { u: path, v: sweep } => {
+                let [first, second] = path.derivatives_at([point.u]);
+
+                let transform = sweep.to_transform_at(point.v);
+                let u = transform.transform_vector(&first);
+                let uu = transform.transform_vector(&second);
+
+                match sweep {
+                    SweepPath::Line(v) => {
+                        let zero = Vector::default();
+                        ([u, *v], [uu, zero, zero])
+                    }
+                    SweepPath::Helix(helix) => {
+                        let axis = helix.axis().direction();
+                        let angle = helix.angle();
+
+                        let v = helix
+                            .tangent_at(self.point_from_surface_coords(point));
+                        let uv = axis.cross(&u) * angle;
+                        let vv = axis.cross(&v) * angle;
+
+                        ([u, v], [uu, uv, vv])
+                    }
+                }
+            }
+            Self::Ruled { a, b } => {
+                let [first_a, second_a] = a.derivatives_at([point.u]);
+                let [first_b, second_b] = b.derivatives_at([point.u]);
+
+                let u = first_a + (first_b - first_a) * point.v;
+                let v = b.point_from_path_coords([point.u])
+                    - a.point_from_path_coords([point.u]);
+                let uu = second_a + (second_b - second_a) * point.v;
+                let uv = first_b - first_a;
+
+                ([u, v], [uu, uv, Vector::default()])
+            }
+        }
     }
 
     /// Project the global point into the surface
+    ///
+    /// Returns the surface coordinates of the point on the surface, that is
+    /// closest to the provided point.
+    ///
+    /// Planes and cylinders are handled analytically, as are surfaces of
+    /// revolution with a straight or circular profile. All other surfaces are
+    /// handled numerically, which is reliable, as long as the surface doesn't
+    /// curve so sharply, that it has multiple local minima close to each other.
+    /// The numerical search locates the closest point to about the square root
+    /// of floating point precision, relative to the size of the surface.
     pub fn project_global_point(&self, point: impl Into<Point<3>>) -> Point<2> {
-        let GlobalPath::Line(line) = self.u else {
-            todo!("Projecting point into non-plane surface is not supported")
+        let point = point.into();
+
+        match self {
+            Self::Swept {
+                u: GlobalPath::Line(line),
+                v: SweepPath::Line(v),
+            } => {
+                let plane =
+                    Plane::from_parametric(line.origin(), line.direction(), *v);
+                plane.project_point(point)
+            }
+            Self::Swept {
+                u,
+                v: SweepPath::Line(v),
+            } => {
+                if let Some(section) = perpendicular_section(u, v) {
+                    // The closest point on a cylinder is located on the line
+                    // along `v`, through the closest point on its section.
+                    let t = section.point_to_ellipse_coords(point).t;
+
+                    let to_point = point - u.point_from_path_coords([t]);
+                    return Point::from([t, to_point.dot(v) / v.dot(v)]);
+                }
+
+                // For every point on `u`, the closest point on the surface is
+                // on the line through that point, along `v`. We need to find
+                // the one of those lines that is closest to the point.
+                let distance_from_line = |t: Scalar| {
+                    let to_point = point - u.point_from_path_coords([t]);
+                    let along_line = *v * (to_point.dot(v) / v.dot(v));
+                    let distance = to_point - along_line;
+
+                    distance.dot(&distance)
+                };
+
+                let (range, domain) = search_range(u, point);
+                let t = minimize(distance_from_line, range, domain);
+
+                let to_point = point - u.point_from_path_coords([t]);
+                Point::from([t, to_point.dot(v) / v.dot(v)])
+            }
+            Self::Swept {
+                u,
+                v: SweepPath::Helix(helix),
+            } => {
+                if helix.pitch() == Scalar::ZERO {
+                    if let Some(point) =
+                        project_into_surface_of_revolution(point, u, helix)
+                    {
+                        return point;
+                    }
+                }
+
+                // Moving backwards along the helix, the point traces a helix
+                // itself. We need to find the location on that helix, that is
+                // closest to `u`.
+                let project_into_u = |t: Scalar| {
+                    let point = helix.point_at(point, -t);
+                    let u = u.project_point(point);
+
+                    (u, point)
+                };
+                let distance_from_u = |t: Scalar| {
+                    let (u_coord, point) = project_into_u(t);
+                    let distance = point - u.point_from_path_coords(u_coord);
+
+                    distance.dot(&distance)
+                };
+
+                let full_turn = Scalar::TAU / helix.angle().abs();
+
+                let v = if helix.pitch() == Scalar::ZERO {
+                    minimize(
+                        distance_from_u,
+                        [Scalar::ZERO, full_turn],
+                        Domain::Periodic,
+                    )
+                } else {
+                    // Start searching where the point's height along the axis
+                    // is the same as the height of the origin of `u`.
+                    let start = (point - u.origin())
+                        .dot(&helix.axis().direction())
+                        / helix.advance();
+
+                    minimize(
+                        distance_from_u,
+                        [start - full_turn, start + full_turn],
+                        Domain::Unbounded,
+                    )
+                };
+
+                let (u, _) = project_into_u(v);
+                Point::from([u.t, v])
+            }
+            Self::Ruled { a, b } => {
+                // For every value of `u`, the closest point on the surface is
+                // on the straight line between `a` and `b`.
+                let project_into_line = |t: Scalar| {
+                    let a = a.point_from_path_coords([t]);
+                    let b = b.point_from_path_coords([t]);
+
+                    let a_to_b = b - a;
+                    let v = if a_to_b.magnitude() == Scalar::ZERO {
+                        Scalar::ZERO
+                    } else {
+                        (point - a).dot(&a_to_b) / a_to_b.dot(&a_to_b)
+                    };
+
+                    (v, a + a_to_b * v)
+                };
+                let distance_from_line = |t: Scalar| {
+                    let (_, closest) = project_into_line(t);
+                    let distance = point - closest;
+
+                    distance.dot(&distance)
+                };
+
+                let (range, domain) = match (a, b) {
+                    (GlobalPath::Spline(spline), _)
+                    | (_, GlobalPath::Spline(spline)) => {
+                        (spline.domain(), Domain::Bounded)
+                    }
+                    (
+                        GlobalPath::Circle(_) | GlobalPath::Ellipse(_),
+                        GlobalPath::Circle(_) | GlobalPath::Ellipse(_),
+                    ) => ([Scalar::ZERO, Scalar::TAU], Domain::Periodic),
+                    (GlobalPath::Line(line), _)
+                    | (_, GlobalPath::Line(line)) => {
+                        search_range(&GlobalPath::Line(*line), point)
+                    }
+                };
+
+                let u = minimize(distance_from_line, range, domain);
+                let (v, _) = project_into_line(u);
+
+                Point::from([u, v])
+            }
+        }
+    }
+
+    /// Convert a path in surface coordinates to model coordinates
+    ///
+    /// The resulting path uses the same path coordinates as the original one.
+    ///
+    /// ## Implementation Note
+    ///
+    /// Only paths on planes are supported right now. Circles become ellipses,
+    /// unless the coordinate system of the plane is uniformly scaled and
+    /// orthogonal. Splines are converted exactly, as the conversion between
+    /// plane and global coordinates is affine.
+    pub fn path_from_surface_coords(&self, path: SurfacePath) -> GlobalPath {
+        match path {
+            SurfacePath::Circle(circle) => {
+                let center = self.point_from_surface_coords(circle.center());
+                let a = self.vector_from_surface_coords(circle.a());
+                let b = self.vector_from_surface_coords(circle.b());
+
+                match Circle::try_new(center, a, b) {
+                    Some(circle) => GlobalPath::Circle(circle),
+                    None => GlobalPath::Ellipse(Ellipse::new(center, a, b)),
+                }
+            }
+            SurfacePath::Line(line) => {
+                GlobalPath::Line(Line::from_origin_and_direction(
+                    self.point_from_surface_coords(line.origin()),
+                    self.vector_from_surface_coords(line.direction()),
+                ))
+            }
+            SurfacePath::Spline(spline) => {
+                GlobalPath::Spline(spline.map_control_points(|point| {
+                    self.point_from_surface_coords(point)
+                }))
+            }
+        }
+    }
+
+    /// Project the global path into the surface
+    ///
+    /// The path must be located within the surface. The resulting path uses
+    /// the same path coordinates as the original one.
+    ///
+    /// ## Implementation Note
+    ///
+    /// This has the same limitations as [`SurfaceGeom::path_from_surface_coords`].
+    pub fn project_global_path(&self, path: GlobalPath) -> SurfacePath {
+        let project_vector = |origin: Point<3>, vector: Vector<3>| {
+            self.project_global_point(origin + vector)
+                - self.project_global_point(origin)
         };
 
-        let plane =
-            Plane::from_parametric(line.origin(), line.direction(), self.v);
-        plane.project_point(point)
+        match path {
+            GlobalPath::Circle(circle) => SurfacePath::Circle(Circle::new(
+                self.project_global_point(circle.center()),
+                project_vector(circle.center(), circle.a()),
+                project_vector(circle.center(), circle.b()),
+            )),
+            GlobalPath::Ellipse(ellipse) => {
+                let center = self.project_global_point(ellipse.center());
+                let a = project_vector(ellipse.center(), ellipse.a());
+                let b = project_vector(ellipse.center(), ellipse.b());
+
+                let Some(circle) = Circle::try_new(center, a, b) else {
+                    todo!(
+                        "Projecting an ellipse into a surface, where it \
+                        doesn't become a circle, is not supported."
+                    )
+                };
+
+                SurfacePath::Circle(circle)
+            }
+            GlobalPath::Line(line) => {
+                SurfacePath::Line(Line::from_origin_and_direction(
+                    self.project_global_point(line.origin()),
+                    project_vector(line.origin(), line.direction()),
+                ))
+            }
+            GlobalPath::Spline(spline) => {
+                SurfacePath::Spline(spline.map_control_points(|point| {
+                    self.project_global_point(point)
+                }))
+            }
+        }
     }
 
     /// Transform the surface geometry
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
-        let u = self.u.transform(transform);
-        let v = transform.transform_vector(&self.v);
-        Self { u, v }
+        match self {
+            Self::Swept { u, v } => Self::Swept {
+                u: u.transform(transform),
+                v: v.transform(transform),
+            },
+            Self::Ruled { a, b } => Self::Ruled {
+                a: a.transform(transform),
+                b: b.transform(transform),
+            },
+        }
+    }
+}
+
+/// The curvature of a surface at a point
+///
+/// See [`SurfaceGeom::curvature_at`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Curvature {
+    /// The principal curvatures, the smaller one first
+    pub principal: [Scalar; 2],
+
+    /// The directions of principal curvature, in the same order
+    pub directions: [Vector<3>; 2],
+}
+
+impl Curvature {
+    /// Compute the Gaussian curvature
+    pub fn gaussian(&self) -> Scalar {
+        let [a, b] = self.principal;
+        a * b
+    }
+
+    /// Compute the mean curvature
+    pub fn mean(&self) -> Scalar {
+        let [a, b] = self.principal;
+        (a + b) / 2.
+    }
+
+    /// Return the curvature of the other side of the surface
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        let [a, b] = self.principal;
+        let [dir_a, dir_b] = self.directions;
+
+        Self {
+            principal: [-b, -a],
+            directions: [dir_b, dir_a],
+        }
+    }
+}
+
+fn normal_from_derivatives(u: Vector<3>, v: Vector<3>) -> Option<Vector<3>> {
+    let normal = u.cross(&v);
+
+    // If either derivative is zero, or they are parallel, there is no normal.
+    // Compare to the size of the derivatives, to not be fooled by floating
+    // point noise.
+    let scale = u.dot(&u) + v.dot(&v);
+    if normal.magnitude() <= scale * Scalar::from(1e-12) {
+        return None;
+    }
+
+    Some(normal.normalize())
+}
+
+/// Project a point into a surface of revolution
+///
+/// The surface is defined by the path that is revolved around the axis of the
+/// helix. This only supports lines and circles that are located within a plane
+/// that contains the axis. Returns `None`, if that is not the case.
+///
+/// The surface covers every point twice, if the path crosses the axis. This
+/// function always chooses the coordinates on the half-plane, which the path
+/// is located in, which keeps the results predictable.
+fn project_into_surface_of_revolution(
+    point: Point<3>,
+    path: &GlobalPath,
+    helix: &Helix,
+) -> Option<Point<2>> {
+    let origin = helix.axis().origin();
+    let axis = helix.axis().direction();
+
+    let perpendicular_to_axis =
+        |vector: Vector<3>| vector - axis * axis.dot(&vector);
+    let epsilon = Scalar::from(1e-9);
+
+    // The direction from the axis, towards the half-plane that the path is
+    // located in.
+    let (meridian, normal) = match path {
+        GlobalPath::Circle(circle) => {
+            let normal = circle.a().cross(&circle.b());
+            let from_axis = perpendicular_to_axis(circle.center() - origin);
+
+            let meridian = if from_axis.magnitude() > epsilon {
+                from_axis
+            } else {
+                normal.cross(&axis)
+            };
+
+            (meridian, normal)
+        }
+        GlobalPath::Line(line) => {
+            let from_axis = {
+                let direction = perpendicular_to_axis(line.direction());
+                if direction.magnitude() > epsilon {
+                    direction
+                } else {
+                    perpendicular_to_axis(line.origin() - origin)
+                }
+            };
+
+            (from_axis, from_axis.cross(&axis))
+        }
+        GlobalPath::Ellipse(_) | GlobalPath::Spline(_) => {
+            return None;
+        }
+    };
+    let path_origin = path.point_from_path_coords([0.]);
+    let is_in_plane_through_axis = meridian.magnitude() > epsilon
+        && normal.magnitude() > epsilon
+        && axis.dot(&normal.normalize()).abs() < epsilon
+        && (path_origin - origin).dot(&normal.normalize()).abs() < epsilon;
+    if !is_in_plane_through_axis {
+        return None;
+    }
+    let meridian = meridian.normalize();
+
+    // The angle between the meridian of the path and the meridian of the
+    // point.
+    let angle = {
+        let from_axis = perpendicular_to_axis(point - origin);
+        let angle = Scalar::atan2(
+            axis.dot(&meridian.cross(&from_axis)),
+            meridian.dot(&from_axis),
+        );
+
+        if angle < Scalar::ZERO {
+            angle + Scalar::TAU
+        } else {
+            angle
+        }
+    };
+
+    let full_turn = Scalar::TAU / helix.angle().abs();
+    let v = {
+        let v = angle / helix.angle();
+        if v < Scalar::ZERO {
+            v + full_turn
+        } else {
+            v
+        }
+    };
+
+    // Rotate the point back into the meridian of the path.
+    let point = helix.point_at(point, -v);
+    let u = path.project_point(point);
+
+    Some(Point::from([u.t, v]))
+}
+
+/// Compute the section of a cylinder, within a plane perpendicular to its axis
+///
+/// The section has the same coordinates as `u`. Returns `None`, if `u` is not
+/// a circle or ellipse, or if `v` is parallel to its plane, which makes the
+/// surface degenerate.
+fn perpendicular_section(u: &GlobalPath, v: &Vector<3>) -> Option<Ellipse<3>> {
+    let section: Ellipse<3> = match u {
+        GlobalPath::Circle(circle) => (*circle).into(),
+        GlobalPath::Ellipse(ellipse) => *ellipse,
+        GlobalPath::Line(_) | GlobalPath::Spline(_) => return None,
+    };
+
+    let [a, b] = [section.a(), section.b()]
+        .map(|vector| vector - *v * (vector.dot(v) / v.dot(v)));
+    if a.cross(&b).magnitude()
+        <= a.magnitude() * b.magnitude() * Scalar::from(1e-12)
+    {
+        return None;
+    }
+
+    Some(Ellipse::new(section.center(), a, b))
+}
+
+/// Determine the range in which to search for the closest point on a path
+fn search_range(path: &GlobalPath, point: Point<3>) -> ([Scalar; 2], Domain) {
+    match path {
+        GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
+            ([Scalar::ZERO, Scalar::TAU], Domain::Periodic)
+        }
+        GlobalPath::Line(line) => {
+            let t = line.point_to_line_coords(point).t;
+            ([t - Scalar::ONE, t + Scalar::ONE], Domain::Unbounded)
+        }
+        GlobalPath::Spline(spline) => (spline.domain(), Domain::Bounded),
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use fj_math::{Circle, Ellipse, Line, Point, Scalar, Vector};
     use pretty_assertions::assert_eq;
 
-    use crate::geometry::{GlobalPath, SurfaceGeom};
+    use crate::geometry::{GlobalPath, Helix, SurfaceGeom, SweepPath};
 
     #[test]
     fn point_from_surface_coords() {
-        let surface = SurfaceGeom {
+        let surface = SurfaceGeom::Swept {
             u: GlobalPath::Line(Line::from_origin_and_direction(
                 Point::from([1., 1., 1.]),
                 Vector::from([0., 2., 0.]),
             )),
-            v: Vector::from([0., 0., 2.]),
+            v: SweepPath::Line(Vector::from([0., 0., 2.])),
         };
 
         assert_eq!(
@@ -84,12 +807,12 @@ mod tests {
 
     #[test]
     fn vector_from_surface_coords() {
-        let surface = SurfaceGeom {
+        let surface = SurfaceGeom::Swept {
             u: GlobalPath::Line(Line::from_origin_and_direction(
                 Point::from([1., 0., 0.]),
                 Vector::from([0., 2., 0.]),
             )),
-            v: Vector::from([0., 0., 2.]),
+            v: SweepPath::Line(Vector::from([0., 0., 2.])),
         };
 
         assert_eq!(
@@ -97,4 +820,235 @@ mod tests {
             Vector::from([0., 4., 8.]),
         );
     }
+
+    #[test]
+    fn sphere() {
+        let surface = SurfaceGeom::sphere([1., 2., 3.], 2.);
+
+        let top = surface.point_from_surface_coords([0., 0.]);
+        let equator = surface
+            .point_from_surface_coords([Scalar::PI / 2., Scalar::from(0.25)]);
+        assert_close(top, [1., 2., 5.]);
+        assert_close(equator, [1., 4., 3.]);
+
+        check_projection(&surface, [[0.5, 0.1], [1., 0.6], [2.5, 0.9]]);
+    }
+
+    #[test]
+    fn cone() {
+        let surface = SurfaceGeom::cone([0., 0., 1.], Scalar::PI / 4.);
+
+        let point = surface.point_from_surface_coords([2., 0.5]);
+        let expected = 2_f64.sqrt();
+        assert_close(point, [-expected, 0., 1. + expected]);
+
+        check_projection(&surface, [[0.5, 0.1], [1., 0.6], [2.5, 0.9]]);
+    }
+
+    #[test]
+    fn torus() {
+        let surface = SurfaceGeom::torus([0., 0., 0.], 3., 1.);
+
+        let outside = surface.point_from_surface_coords([0., 0.]);
+        let top =
+            surface.point_from_surface_coords([Scalar::PI * 1.5, Scalar::ZERO]);
+        assert_close(outside, [4., 0., 0.]);
+        assert_close(top, [3., 0., 1.]);
+
+        check_projection(
+            &surface,
+            [[0.5, 0.1], [2., 0.6], [4., 0.3], [6., 0.9]],
+        );
+    }
+
+    #[test]
+    fn project_into_cylinder() {
+        let surface = SurfaceGeom::Swept {
+            u: GlobalPath::circle_from_radius(1.),
+            v: SweepPath::Line(Vector::from([0., 0., 2.])),
+        };
+
+        check_projection(&surface, [[0.5, 0.1], [2., -0.6], [4., 1.5]]);
+
+        // Points that are not on the surface are projected to the closest
+        // point on it.
+        let projected = surface.project_global_point([0., 3., 1.]);
+        let expected = Point::from([Scalar::PI / 2., Scalar::from(0.5)]);
+        assert!((projected - expected).magnitude() < Scalar::from(1e-12));
+    }
+
+    #[test]
+    fn project_into_oblique_elliptic_cylinder() {
+        let surface = SurfaceGeom::Swept {
+            u: GlobalPath::Ellipse(Ellipse::new(
+                [1., 0., 0.],
+                [2., 0., 0.],
+                [0., 1., 0.],
+            )),
+            v: SweepPath::Line(Vector::from([1., 0., 2.])),
+        };
+
+        check_projection(&surface, [[0.5, 0.1], [2., -0.6], [4., 1.5]]);
+
+        // The closest point is where the direction towards the point is
+        // perpendicular to the surface.
+        let point = Point::from([4., 3., 1.]);
+        let projected = surface.project_global_point(point);
+        let to_point = point - surface.point_from_surface_coords(projected);
+        let [u, v] = surface.derivatives_at(projected);
+        assert!(to_point.dot(&u).abs() < Scalar::from(1e-12));
+        assert!(to_point.dot(&v).abs() < Scalar::from(1e-12));
+    }
+
+    #[test]
+    fn project_into_extruded_spline() {
+        let surface = SurfaceGeom::Swept {
+            u: GlobalPath::spline_from_control_points(
+                3,
+                [[0., 0., 0.], [1., 2., 0.], [2., -1., 0.], [3., 0., 1.]],
+            ),
+            v: SweepPath::Line(Vector::from([0., 1., 3.])),
+        };
+
+        check_projection(&surface, [[0., 0.], [0.3, 0.5], [0.8, -1.]]);
+    }
+
+    #[test]
+    fn project_into_helical_surface() {
+        let helix = Helix::new(
+            Line::from_origin_and_direction(Point::origin(), Vector::unit_z()),
+            Scalar::TAU * 3.,
+            1.,
+        );
+        let surface = SurfaceGeom::Swept {
+            u: GlobalPath::line_from_points([[1., 0., 0.], [2., 0., 0.]]).0,
+            v: SweepPath::Helix(helix),
+        };
+
+        check_projection(&surface, [[0.5, 0.1], [0.2, 0.6], [0.9, 0.95]]);
+    }
+
+    #[test]
+    fn project_into_ruled_surface() {
+        let surface = SurfaceGeom::Ruled {
+            a: GlobalPath::circle_from_radius(1.),
+            b: GlobalPath::Circle(Circle::new(
+                [0., 0., 1.],
+                [0., 2., 0.],
+                [-2., 0., 0.],
+            )),
+        };
+
+        check_projection(&surface, [[0.5, 0.1], [2., 0.6], [4., 0.9]]);
+    }
+
+    #[test]
+    fn normal_and_curvature_of_sphere() {
+        let center = Point::from([1., 2., 3.]);
+        let surface = SurfaceGeom::sphere(center, 2.);
+
+        for point in [[0.5, 0.1], [1., 0.6], [2.5, 0.9]] {
+            let position = surface.point_from_surface_coords(point);
+            let normal = surface.normal_at(point).unwrap();
+            let curvature = surface.curvature_at(point).unwrap();
+
+            assert_close_vector(normal, (position - center) / 2.);
+            assert_close_scalars(curvature.principal, [0.5, 0.5]);
+        }
+
+        // The normal is not defined at the poles.
+        assert!(surface.normal_at([0., 0.3]).is_none());
+    }
+
+    #[test]
+    fn normal_and_curvature_of_cylinder() {
+        let surface = SurfaceGeom::Swept {
+            u: GlobalPath::circle_from_radius(2.),
+            v: SweepPath::Line(Vector::from([0., 0., 3.])),
+        };
+
+        let normal = surface.normal_at([Scalar::PI / 2., Scalar::ONE]).unwrap();
+        let curvature = surface
+            .curvature_at([Scalar::PI / 2., Scalar::ONE])
+            .unwrap();
+
+        assert_close_vector(normal, [0., 1., 0.]);
+        assert_close_scalars(curvature.principal, [0., 0.5]);
+        for (direction, expected) in curvature
+            .directions
+            .into_iter()
+            .zip([Vector::unit_z(), Vector::unit_x()])
+        {
+            // The sign of the directions is not defined.
+            assert!(
+                (direction.dot(&expected).abs() - 1.).abs()
+                    < Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn normal_and_curvature_of_cone() {
+        let surface = SurfaceGeom::cone([0., 0., 0.], Scalar::PI / 4.);
+
+        let normal = surface.normal_at([2., 0.]).unwrap();
+        let curvature = surface.curvature_at([2., 0.]).unwrap();
+
+        // The point is at a distance of `sqrt(2)` from the axis, and the
+        // surface is inclined by 45 degrees.
+        let component = 0.5_f64.sqrt();
+        assert_close_vector(normal, [component, 0., -component]);
+        assert_close_scalars(curvature.principal, [0., 0.5]);
+    }
+
+    #[test]
+    fn normal_and_curvature_of_torus() {
+        let surface = SurfaceGeom::torus([0., 0., 0.], 3., 1.);
+
+        // On the outside, the torus curves away from the normal in both
+        // directions.
+        let normal = surface.normal_at([0., 0.]).unwrap();
+        let curvature = surface.curvature_at([0., 0.]).unwrap();
+        assert_close_vector(normal, [1., 0., 0.]);
+        assert_close_scalars(curvature.principal, [0.25, 1.]);
+
+        // On the inside, it is saddle-shaped.
+        let normal = surface.normal_at([Scalar::PI, Scalar::ZERO]).unwrap();
+        let curvature =
+            surface.curvature_at([Scalar::PI, Scalar::ZERO]).unwrap();
+        assert_close_vector(normal, [-1., 0., 0.]);
+        assert_close_scalars(curvature.principal, [-0.5, 1.]);
+        assert!(curvature.gaussian() < Scalar::ZERO);
+    }
+
+    fn assert_close_vector(a: Vector<3>, b: impl Into<Vector<3>>) {
+        let b = b.into();
+        assert!((a - b).magnitude() < Scalar::from(1e-12), "{a:?} != {b:?}");
+    }
+
+    fn assert_close_scalars(a: [Scalar; 2], b: [f64; 2]) {
+        for (a, b) in a.into_iter().zip(b) {
+            assert!((a - b).abs() < Scalar::from(1e-12), "{a:?} != {b:?}");
+        }
+    }
+
+    fn assert_close(a: Point<3>, b: impl Into<Point<3>>) {
+        let b = b.into();
+        assert!((a - b).magnitude() < Scalar::from(1e-12), "{a:?} != {b:?}");
+    }
+
+    fn check_projection<const N: usize>(
+        surface: &SurfaceGeom,
+        points: [[f64; 2]; N],
+    ) {
+        for point in points {
+            let point = Point::from(point);
+            let projected = surface
+                .project_global_point(surface.point_from_surface_coords(point));
+            assert!(
+                (projected - point).magnitude() < Scalar::from(1e-9),
+                "{projected:?} != {point:?}"
+            );
+        }
+    }
 }
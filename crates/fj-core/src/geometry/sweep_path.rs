@@ -0,0 +1,404 @@
+//! The path that something is swept along
+
+use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+
+/// A path that something is swept along
+///
+/// Sweep operations use this to define how the object they sweep moves through
+/// space. It is also used by [`SurfaceGeom`], to define how the u-axis of a
+/// surface is swept to create that surface.
+///
+/// Sweep paths are parameterized, such that `0` is the start of the path, while
+/// `1` is its end.
+///
+/// [`SurfaceGeom`]: super::SurfaceGeom
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum SweepPath {
+    /// A straight path, defined by a vector
+    Line(Vector<3>),
+
+    /// A helical path, which rotates around an axis
+    Helix(Helix),
+}
+
+impl SweepPath {
+    /// Construct a sweep path along an arc of the provided circle
+    ///
+    /// See [`Helix::from_circle`].
+    pub fn arc(circle: &Circle<3>, angle: impl Into<Scalar>) -> Self {
+        Self::Helix(Helix::from_circle(circle, angle, Scalar::ZERO))
+    }
+
+    /// Construct a sweep path along a helix around the provided circle
+    ///
+    /// See [`Helix::from_circle`].
+    pub fn helix(
+        circle: &Circle<3>,
+        angle: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+    ) -> Self {
+        Self::Helix(Helix::from_circle(circle, angle, pitch))
+    }
+
+    /// Move the provided point along the path, to the provided path coordinate
+    pub fn point_at(&self, point: Point<3>, t: impl Into<Scalar>) -> Point<3> {
+        let t = t.into();
+
+        match self {
+            Self::Line(vector) => point + *vector * t,
+            Self::Helix(helix) => helix.point_at(point, t),
+        }
+    }
+
+    /// Compute the direction that the provided point moves in, at the start
+    ///
+    /// The magnitude of the returned vector is the speed that the point moves
+    /// at, in relation to the path coordinate.
+    pub fn tangent_at(&self, point: Point<3>) -> Vector<3> {
+        match self {
+            Self::Line(vector) => *vector,
+            Self::Helix(helix) => helix.tangent_at(point),
+        }
+    }
+
+    /// Compute the transform that moves an object from the start to the end
+    pub fn to_transform(&self) -> Transform {
+        match self {
+            Self::Line(vector) => Transform::translation(*vector),
+            Self::Helix(helix) => helix.to_transform(),
+        }
+    }
+
+    /// Compute the transform that moves an object from the start to the
+    /// provided path coordinate
+    pub fn to_transform_at(&self, t: impl Into<Scalar>) -> Transform {
+        let t = t.into();
+
+        match self {
+            Self::Line(vector) => Transform::translation(*vector * t),
+            Self::Helix(helix) => {
+                Helix::new(helix.axis, helix.angle * t, helix.pitch)
+                    .to_transform()
+            }
+        }
+    }
+
+    /// Transform the path
+    #[must_use]
+    pub fn transform(self, transform: &Transform) -> Self {
+        match self {
+            Self::Line(vector) => {
+                Self::Line(transform.transform_vector(&vector))
+            }
+            Self::Helix(helix) => Self::Helix(helix.transform(transform)),
+        }
+    }
+}
+
+impl From<Vector<3>> for SweepPath {
+    fn from(vector: Vector<3>) -> Self {
+        Self::Line(vector)
+    }
+}
+
+impl<T: Into<Scalar>> From<[T; 3]> for SweepPath {
+    fn from(vector: [T; 3]) -> Self {
+        Self::Line(vector.into())
+    }
+}
+
+impl From<Helix> for SweepPath {
+    fn from(helix: Helix) -> Self {
+        Self::Helix(helix)
+    }
+}
+
+/// A helical motion around an axis
+///
+/// Moves points around the axis by `angle`, while advancing them along the
+/// axis by `pitch` per full turn. If `pitch` is zero, the points move along
+/// circle arcs, and surfaces swept along the helix are surfaces of revolution.
+///
+/// The rotation follows the right-hand rule, in relation to the direction of
+/// the axis.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Helix {
+    axis: Line<3>,
+    angle: Scalar,
+    pitch: Scalar,
+}
+
+impl Helix {
+    /// Construct a helix from an axis, an angle, and a pitch
+    ///
+    /// The direction of the axis is normalized.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the direction of the axis is zero.
+    pub fn new(
+        axis: Line<3>,
+        angle: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+    ) -> Self {
+        assert!(
+            axis.direction() != Vector::from([0., 0., 0.]),
+            "Axis of helix must have a direction"
+        );
+
+        let axis = Line::from_origin_and_direction(
+            axis.origin(),
+            axis.direction().normalize(),
+        );
+
+        Self {
+            axis,
+            angle: angle.into(),
+            pitch: pitch.into(),
+        }
+    }
+
+    /// Construct a helix that rotates around the axis of a circle
+    ///
+    /// The axis goes through the center of the circle. Its direction is chosen,
+    /// such that a positive angle moves points in the same direction that the
+    /// circle's coordinates increase in.
+    pub fn from_circle(
+        circle: &Circle<3>,
+        angle: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+    ) -> Self {
+        let axis = Line::from_origin_and_direction(
+            circle.center(),
+            circle.a().cross(&circle.b()),
+        );
+        Self::new(axis, angle, pitch)
+    }
+
+    /// Access the axis of the helix
+    ///
+    /// The direction of the axis is normalized.
+    pub fn axis(&self) -> Line<3> {
+        self.axis
+    }
+
+    /// Access the angle that the helix rotates by
+    pub fn angle(&self) -> Scalar {
+        self.angle
+    }
+
+    /// Access the distance that the helix advances along its axis per turn
+    pub fn pitch(&self) -> Scalar {
+        self.pitch
+    }
+
+    /// Compute the distance that the helix advances along its axis in total
+    pub fn advance(&self) -> Scalar {
+        self.pitch * self.angle / Scalar::TAU
+    }
+
+    /// Move the provided point along the helix, to the provided coordinate
+    pub fn point_at(&self, point: Point<3>, t: impl Into<Scalar>) -> Point<3> {
+        let t = t.into();
+
+        let origin = self.axis.origin();
+        let direction = self.axis.direction();
+
+        origin
+            + self.rotate_vector(point - origin, self.angle * t)
+            + direction * self.advance() * t
+    }
+
+    /// Compute the direction that the provided point moves in, at the start
+    pub fn tangent_at(&self, point: Point<3>) -> Vector<3> {
+        let direction = self.axis.direction();
+        let radius = point - self.axis.origin();
+
+        direction.cross(&radius) * self.angle + direction * self.advance()
+    }
+
+    /// Compute the distance of the provided point from the axis
+    pub fn distance_from_axis(&self, point: Point<3>) -> Scalar {
+        let direction = self.axis.direction();
+        let radius = point - self.axis.origin();
+
+        (radius - direction * direction.dot(&radius)).magnitude()
+    }
+
+    /// Compute the circle around the axis, that the provided point is on
+    ///
+    /// If the helix doesn't advance along its axis, this is the circle that the
+    /// point moves along. Moving the point to the coordinate `t` on the helix,
+    /// moves it to the coordinate `angle * t` on the circle.
+    ///
+    /// Returns `None`, if the point is located on the axis.
+    pub fn circle_around_axis(&self, point: Point<3>) -> Option<Circle<3>> {
+        let direction = self.axis.direction();
+        let from_origin = point - self.axis.origin();
+
+        let a = from_origin - direction * direction.dot(&from_origin);
+        let b = direction.cross(&a);
+
+        Circle::try_new(point - a, a, b)
+    }
+
+    /// Compute the transform that moves an object from the start to the end
+    pub fn to_transform(&self) -> Transform {
+        let origin = self.axis.origin().coords;
+        let direction = self.axis.direction();
+
+        Transform::translation(origin + direction * self.advance())
+            * Transform::rotation(direction * self.angle)
+            * Transform::translation(-origin)
+    }
+
+    /// Transform the helix
+    ///
+    /// If the transform mirrors the helix, the direction of rotation is
+    /// reversed, as is the sign of the pitch.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the transform doesn't preserve angles. The result would no
+    /// longer be a helix.
+    #[must_use]
+    pub fn transform(self, transform: &Transform) -> Self {
+        assert!(
+            transform.preserves_angles(),
+            "Transforming a helix with a transform that doesn't preserve \
+            angles (like a non-uniform scaling) is not supported, as the \
+            result would no longer be a helix."
+        );
+
+        let axis = transform.transform_line(&self.axis);
+
+        let mut angle = self.angle;
+        let mut pitch = self.pitch * axis.direction().magnitude();
+
+        // A mirrored helix rotates the other way around its axis. The pitch
+        // must change its sign too, or the helix would advance into the wrong
+        // direction along the axis.
+        if transform.reverses_orientation() {
+            angle = -angle;
+            pitch = -pitch;
+        }
+
+        Self::new(axis, angle, pitch)
+    }
+
+    fn rotate_vector(&self, vector: Vector<3>, angle: Scalar) -> Vector<3> {
+        let direction = self.axis.direction();
+        let (sin, cos) = angle.sin_cos();
+
+        vector * cos
+            + direction.cross(&vector) * sin
+            + direction * direction.dot(&vector) * (Scalar::ONE - cos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_math::{Circle, Line, Plane, Point, Transform, Vector};
+
+    use super::{Helix, SweepPath};
+
+    #[test]
+    fn point_at() {
+        let helix = Helix::new(
+            Line::from_origin_and_direction(
+                Point::from([1., 0., 0.]),
+                Vector::from([0., 0., 2.]),
+            ),
+            FRAC_PI_2,
+            4.,
+        );
+
+        let point = Point::from([2., 0., 0.]);
+        assert_eq!(helix.point_at(point, 0.), point);
+        assert!(
+            (helix.point_at(point, 1.) - Point::from([1., 1., 1.])).magnitude()
+                < 1e-12.into()
+        );
+    }
+
+    #[test]
+    fn point_at_matches_transform() {
+        let circle = Circle::from_center_and_radius([1., 2., 3.], 1.);
+        let path = SweepPath::helix(&circle, 2., 3.);
+
+        let point = Point::from([3., 1., 2.]);
+        let transformed = path.to_transform().transform_point(&point);
+        assert!(
+            (path.point_at(point, 1.) - transformed).magnitude() < 1e-12.into()
+        );
+    }
+
+    #[test]
+    fn circle_around_axis() {
+        let helix = Helix::new(
+            Line::from_origin_and_direction(
+                Point::from([1., 2., 3.]),
+                Vector::from([1., 1., 1.]),
+            ),
+            2.,
+            0.,
+        );
+
+        let point = Point::from([3., 1., 2.]);
+        let circle = helix
+            .circle_around_axis(point)
+            .expect("Point is not on the axis");
+        for t in [0., 0.25, 0.5, 1.] {
+            let on_circle =
+                circle.point_from_circle_coords([helix.angle() * t]);
+            assert!(
+                (helix.point_at(point, t) - on_circle).magnitude()
+                    < 1e-12.into()
+            );
+        }
+
+        assert!(helix.circle_around_axis(helix.axis().origin()).is_none());
+    }
+
+    #[test]
+    fn transform_mirrored() {
+        let helix = Helix::new(
+            Line::from_origin_and_direction(
+                Point::from([1., 0., 0.]),
+                Vector::from([0., 0., 1.]),
+            ),
+            1.,
+            3.,
+        );
+
+        let planes = [
+            Plane::from_parametric(
+                Point::origin(),
+                Vector::unit_x(),
+                Vector::unit_y(),
+            ),
+            Plane::from_parametric(
+                Point::origin(),
+                Vector::unit_y(),
+                Vector::unit_z(),
+            ),
+        ];
+
+        for plane in planes {
+            let transform = Transform::reflection(&plane);
+            let mirrored = helix.transform(&transform);
+
+            // Moving a point along the mirrored helix must have the same
+            // result as mirroring the point after moving it along the original
+            // one.
+            let point = Point::from([2., 0., 0.]);
+            let expected =
+                transform.transform_point(&helix.point_at(point, 1.));
+            let actual =
+                mirrored.point_at(transform.transform_point(&point), 1.);
+            assert!((actual - expected).magnitude() < 1e-12.into());
+        }
+    }
+}
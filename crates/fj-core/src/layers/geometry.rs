@@ -110,7 +110,10 @@ impl Command<Geometry> for DefineHalfEdge {
 
 impl Event<Geometry> for DefineHalfEdge {
     fn evolve(&self, state: &mut Geometry) {
-        state.define_half_edge_inner(self.half_edge.clone(), self.geometry);
+        state.define_half_edge_inner(
+            self.half_edge.clone(),
+            self.geometry.clone(),
+        );
     }
 }
 
@@ -135,6 +138,6 @@ impl Command<Geometry> for DefineSurface {
 
 impl Event<Geometry> for DefineSurface {
     fn evolve(&self, state: &mut Geometry) {
-        state.define_surface_inner(self.surface.clone(), self.geometry);
+        state.define_surface_inner(self.surface.clone(), self.geometry.clone());
     }
 }
@@ -0,0 +1,916 @@
+//! Infrastructure for replacing edges of a [`Shell`] with blend faces
+//!
+//! This is shared by the operations that treat edges, like [fillets] and
+//! [chamfers]. Those replace an edge with a face that blends the two faces that
+//! the edge connects, trimming those faces, as well as the faces at the ends of
+//! the edge.
+//!
+//! [fillets]: super::fillet
+//! [chamfers]: super::chamfer
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_math::{Circle, Ellipse, Line, Point, Scalar, Transform, Vector};
+
+use crate::{
+    geometry::{
+        CurveBoundary, GlobalPath, HalfEdgeGeom, SurfaceGeom, SurfacePath,
+        SweepPath,
+    },
+    operations::{
+        build::BuildSurface,
+        derive::DeriveFrom,
+        geometry::{UpdateCurveGeometry, UpdateHalfEdgeGeometry},
+        insert::Insert,
+        presentation::{GetColor, SetColor},
+        update::{UpdateCycle, UpdateFace, UpdateRegion, UpdateShell},
+    },
+    queries::SiblingOfHalfEdge,
+    storage::Handle,
+    topology::{Curve, Cycle, Face, HalfEdge, Region, Shell, Surface, Vertex},
+    Core,
+};
+
+/// The faces and half-edges around an edge that is being blended
+///
+/// The half-edge that defines the edge goes from `A` to `B`, within the first
+/// face. Its sibling goes from `B` to `A` within the second face.
+pub(super) struct EdgeNeighborhood {
+    /// The half-edge, and its sibling
+    half_edges: [Handle<HalfEdge>; 2],
+
+    /// The faces that contain the half-edge and its sibling
+    faces: [Handle<Face>; 2],
+
+    /// The positions of `A` and `B`
+    points: [Point<3>; 2],
+
+    /// The vertices at `A` and `B`
+    vertices: [Handle<Vertex>; 2],
+
+    /// The half-edges next to the edge, at `A` and `B`
+    ///
+    /// For each end of the edge, this contains the half-edge in the first face,
+    /// then the half-edge in the second face.
+    neighbors: [[Neighbor; 2]; 2],
+}
+
+impl EdgeNeighborhood {
+    pub(super) fn new(
+        shell: &Shell,
+        half_edge: &Handle<HalfEdge>,
+        core: &Core,
+    ) -> Self {
+        let geometry = &core.layers.geometry;
+
+        let sibling = shell
+            .get_sibling_of(half_edge, geometry)
+            .expect("Expected half-edge and its sibling to be part of shell");
+
+        let [(face_1, cycle_1), (face_2, cycle_2)] =
+            [half_edge, &sibling].map(|half_edge| {
+                find_half_edge(shell, half_edge)
+                    .expect("Expected half-edge to be part of shell")
+            });
+
+        for face in [&face_1, &face_2] {
+            let SurfaceGeom::Swept {
+                u: GlobalPath::Line(_),
+                v: SweepPath::Line(_),
+            } = geometry.of_surface(face.surface())
+            else {
+                todo!("Blending edges of curved faces is not supported yet")
+            };
+        }
+
+        let [a, b] = line_segment(half_edge, face_1.surface(), core);
+
+        let [prev_1, next_1] = neighbors_in_cycle(&cycle_1, half_edge);
+        let [prev_2, next_2] = neighbors_in_cycle(&cycle_2, &sibling);
+
+        let at_a = [prev_1, next_2]
+            .map(|half_edge| Neighbor::new(shell, half_edge, a, core));
+        let at_b = [next_1, prev_2]
+            .map(|half_edge| Neighbor::new(shell, half_edge, b, core));
+
+        // Exactly one other face must meet the edge at each end. This means
+        // that, within that face, the sibling of the neighbor that arrives at
+        // the end of the edge is followed by the sibling of the neighbor that
+        // leaves it.
+        let [[prev_1, next_2], [next_1, prev_2]] = [&at_a, &at_b];
+        for [arriving, leaving] in [
+            [&next_2.sibling, &prev_1.sibling],
+            [&next_1.sibling, &prev_2.sibling],
+        ] {
+            let (face, cycle) = find_half_edge(shell, arriving)
+                .expect("Expected sibling to be part of shell");
+
+            if cycle.half_edges().after(arriving) != Some(leaving) {
+                todo!(
+                    "Blending an edge is only supported, if exactly three \
+                    faces meet at each of its vertices"
+                )
+            }
+
+            let SurfaceGeom::Swept {
+                u: GlobalPath::Line(_),
+                v: SweepPath::Line(_),
+            } = geometry.of_surface(face.surface())
+            else {
+                todo!(
+                    "Blending edges that end at curved faces is not supported \
+                    yet"
+                )
+            };
+        }
+
+        let vertices = [
+            half_edge.start_vertex().clone(),
+            sibling.start_vertex().clone(),
+        ];
+
+        Self {
+            half_edges: [half_edge.clone(), sibling],
+            faces: [face_1, face_2],
+            points: [a, b],
+            vertices,
+            neighbors: [at_a, at_b],
+        }
+    }
+
+    /// The positions of `A` and `B`
+    pub(super) fn points(&self) -> [Point<3>; 2] {
+        self.points
+    }
+
+    /// The directions in which the first and second face extend from the edge
+    ///
+    /// Both directions are perpendicular to the edge. Also checks that the edge
+    /// can be blended, and marks its vertices as used.
+    pub(super) fn directions(
+        &self,
+        updates: &mut ShellUpdates,
+        core: &Core,
+    ) -> [Vector<3>; 2] {
+        let epsilon = core.layers.validation.config.distinct_min_distance;
+
+        for vertex in &self.vertices {
+            assert!(
+                updates.vertices.insert(vertex.clone()),
+                "Edges that share a vertex can't be blended together"
+            );
+        }
+
+        let [a, b] = self.points;
+        let [[prev_1, next_2], _] = &self.neighbors;
+
+        let direction = (b - a).normalize();
+        let [e1, e2] = [prev_1, next_2].map(|neighbor| {
+            let d = neighbor.direction;
+            (d - direction * d.dot(&direction)).normalize()
+        });
+
+        assert!(
+            e1.cross(&e2).magnitude() > epsilon,
+            "Can't blend edge between parallel faces"
+        );
+
+        [e1, e2]
+    }
+
+    /// Replace the edge with a blend face of the provided profile
+    pub(super) fn blend(
+        &self,
+        profile: BlendProfile,
+        updates: &mut ShellUpdates,
+        core: &mut Core,
+    ) {
+        let epsilon = core.layers.validation.config.distinct_min_distance;
+
+        let [a, b] = self.points;
+        let [[prev_1, next_2], [next_1, prev_2]] = &self.neighbors;
+        let path = b - a;
+
+        // The profile is defined in the plane that is perpendicular to the
+        // edge. At each end of the edge, the blend ends in the plane of the
+        // face there, which might be oblique.
+        let end_a =
+            project_profile(&profile.path, a, [prev_1, next_2], path, epsilon);
+        let end_b = project_profile(
+            &profile
+                .path
+                .clone()
+                .transform(&Transform::translation(path)),
+            b,
+            [next_1, prev_2],
+            path,
+            epsilon,
+        );
+        let [path_a, path_b] = [&end_a, &end_b].map(|end| end.path.clone());
+
+        let zero = Point::from([Scalar::ZERO]);
+        let one = Point::from([Scalar::ONE]);
+        let end = Point::from([profile.end]);
+        let profile_boundary = CurveBoundary::from([zero, end]);
+
+        let [a1, a2, b1, b2] = [
+            (&path_a, zero),
+            (&path_a, end),
+            (&path_b, zero),
+            (&path_b, end),
+        ]
+        .map(|(path, t)| path.point_from_path_coords(t));
+
+        for (neighbor, point, vertex) in [
+            (prev_1, a1, a),
+            (next_1, b1, b),
+            (next_2, a2, a),
+            (prev_2, b2, b),
+        ] {
+            assert!(
+                neighbor.length > (point - vertex).magnitude() + epsilon,
+                "Blend is too large for the edges next to the blended edge"
+            );
+        }
+
+        let [vertex_a1, vertex_a2, vertex_b1, vertex_b2] =
+            [(); 4].map(|_| Vertex::new().insert(core));
+        let [curve_1, curve_2, curve_a, curve_b] =
+            [(); 4].map(|_| Curve::new().insert(core));
+
+        let line = |[a, b]: [Point<3>; 2]| {
+            GlobalPath::Line(Line::from_points_with_line_coords([
+                ([0.], a),
+                ([1.], b),
+            ]))
+        };
+
+        // The edge itself is replaced by the lines where the blend touches the
+        // faces.
+        let [half_edge_1, half_edge_2] = &self.half_edges;
+        updates.replace(
+            half_edge_1,
+            NewHalfEdge {
+                curve: curve_1.clone(),
+                start_vertex: vertex_a1.clone(),
+                path: line([a1, b1]),
+                boundary: CurveBoundary::from([zero, one]),
+            },
+        );
+        updates.replace(
+            half_edge_2,
+            NewHalfEdge {
+                curve: curve_2.clone(),
+                start_vertex: vertex_b2.clone(),
+                path: line([a2, b2]),
+                boundary: CurveBoundary::from([one, zero]),
+            },
+        );
+
+        // The neighbors of the edge are trimmed, and the faces at the ends of
+        // the edge get a corner that follows the profile of the blend.
+        prev_1.trim_end((a1 - a).magnitude(), vertex_a1.clone(), updates);
+        next_1.trim_start((b1 - b).magnitude(), vertex_b1.clone(), updates);
+        next_2.trim_start((a2 - a).magnitude(), vertex_a2.clone(), updates);
+        prev_2.trim_end((b2 - b).magnitude(), vertex_b2.clone(), updates);
+
+        updates.insert_after(
+            &next_2.sibling,
+            NewHalfEdge {
+                curve: curve_a.clone(),
+                start_vertex: vertex_a2.clone(),
+                path: path_a.clone(),
+                boundary: profile_boundary.reverse(),
+            },
+        );
+        updates.insert_after(
+            &next_1.sibling,
+            NewHalfEdge {
+                curve: curve_b.clone(),
+                start_vertex: vertex_b1.clone(),
+                path: path_b.clone(),
+                boundary: profile_boundary,
+            },
+        );
+
+        // And finally, the blend face itself. Its surface coordinates are the
+        // position along the profile (u), and the position along the edge (v).
+        // If the faces at both ends are perpendicular to the edge, the blend
+        // is a sweep of the profile. Otherwise, the profile changes along the
+        // edge, and the blend is a ruled surface between its ends.
+        let surface = if end_a.is_perpendicular && end_b.is_perpendicular {
+            Surface::from_uv(profile.path, path, core)
+        } else {
+            Surface::ruled(path_a, path_b, core)
+        };
+        let [u_a, u_b] = [Scalar::ZERO, profile.end];
+        let [v_a, v_b] = [Scalar::ZERO, Scalar::ONE];
+        let half_edges = [
+            (curve_a, vertex_a1, [[u_a, v_a], [u_b, v_a]], [zero, end]),
+            (curve_2, vertex_a2, [[u_b, v_a], [u_b, v_b]], [zero, one]),
+            (curve_b, vertex_b2, [[u_b, v_b], [u_a, v_b]], [end, zero]),
+            (curve_1, vertex_b1, [[u_a, v_b], [u_a, v_a]], [one, zero]),
+        ]
+        .map(|(curve, start_vertex, [start, end], boundary)| {
+            let [t0, t1] = boundary.map(|point| point.t);
+            let path = SurfacePath::line_from_points_with_coords([
+                ([t0], start),
+                ([t1], end),
+            ]);
+            let boundary = CurveBoundary::from(boundary);
+
+            let curve = curve.make_path_on_surface(
+                path.clone(),
+                surface.clone(),
+                &mut core.layers.geometry,
+            );
+            HalfEdge::new(curve, start_vertex)
+                .insert(core)
+                .set_geometry(
+                    HalfEdgeGeom { path, boundary },
+                    &mut core.layers.geometry,
+                )
+        });
+
+        let region =
+            Region::new(Cycle::new(half_edges).insert(core), []).insert(core);
+        if let Some(color) = self.faces[0].region().get_color(core) {
+            region.set_color(color, core);
+        }
+
+        updates.faces.push(Face::new(surface, region));
+    }
+}
+
+/// The cross-section of a blend face
+pub(super) struct BlendProfile {
+    /// The path of the cross-section at `A`
+    ///
+    /// The cross-section is located in the plane through `A`, that is
+    /// perpendicular to the edge. It goes from the point where the blend meets the first face, at path
+    /// coordinate zero, to the point where it meets the second face.
+    pub path: GlobalPath,
+
+    /// The path coordinate at which the blend meets the second face
+    pub end: Scalar,
+}
+
+/// The profile of a blend, at one end of the blended edge
+struct ProfileEnd {
+    /// The path of the profile, within the plane of the face at that end
+    path: GlobalPath,
+
+    /// Indicate whether the face at that end is perpendicular to the edge
+    is_perpendicular: bool,
+}
+
+/// Project the profile of a blend along the edge, into the face at one end
+///
+/// `origin` is the end of the edge, and `neighbors` are the half-edges of the
+/// first and second face, that meet the edge there. They span the plane of the
+/// face at that end. `direction` is the direction of the edge.
+fn project_profile(
+    path: &GlobalPath,
+    origin: Point<3>,
+    [neighbor_1, neighbor_2]: [&Neighbor; 2],
+    direction: Vector<3>,
+    epsilon: Scalar,
+) -> ProfileEnd {
+    let normal = neighbor_1.direction.cross(&neighbor_2.direction);
+
+    if normal.normalize().cross(&direction.normalize()).magnitude() < epsilon {
+        return ProfileEnd {
+            path: path.clone(),
+            is_perpendicular: true,
+        };
+    }
+
+    let k = direction.dot(&normal);
+    let point = |point: Point<3>| {
+        point - direction * ((point - origin).dot(&normal) / k)
+    };
+    let vector =
+        |vector: Vector<3>| vector - direction * (vector.dot(&normal) / k);
+
+    let ellipse = |center: Point<3>, a: Vector<3>, b: Vector<3>| {
+        let (center, a, b) = (point(center), vector(a), vector(b));
+        match Circle::try_new(center, a, b) {
+            Some(circle) => GlobalPath::Circle(circle),
+            None => GlobalPath::Ellipse(Ellipse::new(center, a, b)),
+        }
+    };
+
+    let path = match path {
+        GlobalPath::Circle(circle) => {
+            ellipse(circle.center(), circle.a(), circle.b())
+        }
+        GlobalPath::Ellipse(e) => ellipse(e.center(), e.a(), e.b()),
+        GlobalPath::Line(line) => {
+            GlobalPath::Line(Line::from_origin_and_direction(
+                point(line.origin()),
+                vector(line.direction()),
+            ))
+        }
+        GlobalPath::Spline(spline) => {
+            GlobalPath::Spline(spline.map_control_points(point))
+        }
+    };
+
+    ProfileEnd {
+        path,
+        is_perpendicular: false,
+    }
+}
+
+/// A half-edge that is next to an edge that is being blended
+struct Neighbor {
+    /// The half-edge
+    half_edge: Handle<HalfEdge>,
+
+    /// The sibling of the half-edge
+    sibling: Handle<HalfEdge>,
+
+    /// The direction from the blended edge along the half-edge
+    direction: Vector<3>,
+
+    /// The length of the half-edge
+    length: Scalar,
+
+    /// The boundary of the half-edge
+    boundary: CurveBoundary<Point<1>>,
+}
+
+impl Neighbor {
+    fn new(
+        shell: &Shell,
+        half_edge: Handle<HalfEdge>,
+        vertex: Point<3>,
+        core: &Core,
+    ) -> Self {
+        let (face, _) = find_half_edge(shell, &half_edge)
+            .expect("Expected neighbor to be part of shell");
+        let sibling = shell
+            .get_sibling_of(&half_edge, &core.layers.geometry)
+            .expect("Expected neighbor to have a sibling");
+
+        let [start, end] = line_segment(&half_edge, face.surface(), core);
+        let boundary = core.layers.geometry.of_half_edge(&half_edge).boundary;
+
+        // The half-edge either starts or ends at the vertex of the blended
+        // edge.
+        let other = if (start - vertex).magnitude() < (end - vertex).magnitude()
+        {
+            end
+        } else {
+            start
+        };
+
+        Self {
+            half_edge,
+            sibling,
+            direction: (other - vertex).normalize(),
+            length: (other - vertex).magnitude(),
+            boundary,
+        }
+    }
+
+    /// Move the start of the half-edge by the provided distance
+    fn trim_start(
+        &self,
+        distance: Scalar,
+        vertex: Handle<Vertex>,
+        updates: &mut ShellUpdates,
+    ) {
+        let point = self.point_at(distance, 0);
+
+        updates.entry(&self.half_edge).start = Some((vertex, point));
+        updates.entry(&self.sibling).end = Some(point);
+    }
+
+    /// Move the end of the half-edge by the provided distance
+    fn trim_end(
+        &self,
+        distance: Scalar,
+        vertex: Handle<Vertex>,
+        updates: &mut ShellUpdates,
+    ) {
+        let point = self.point_at(distance, 1);
+
+        updates.entry(&self.half_edge).end = Some(point);
+        updates.entry(&self.sibling).start = Some((vertex, point));
+    }
+
+    /// The curve coordinate at the provided distance from one of the ends
+    fn point_at(&self, distance: Scalar, end: usize) -> Point<1> {
+        let [from, to] =
+            [self.boundary.inner[end], self.boundary.inner[1 - end]];
+
+        from + (to - from) * (distance / self.length)
+    }
+}
+
+/// The changes to the half-edges of a shell
+#[derive(Default)]
+pub(super) struct ShellUpdates {
+    edges: BTreeMap<Handle<HalfEdge>, HalfEdgeUpdate>,
+    vertices: BTreeSet<Handle<Vertex>>,
+    faces: Vec<Face>,
+}
+
+impl ShellUpdates {
+    /// Indicate whether the edge has already been blended
+    pub(super) fn contains_edge(&self, edge: &EdgeNeighborhood) -> bool {
+        edge.half_edges.iter().any(|half_edge| {
+            self.edges
+                .get(half_edge)
+                .is_some_and(|update| update.replacement.is_some())
+        })
+    }
+
+    fn entry(&mut self, half_edge: &Handle<HalfEdge>) -> &mut HalfEdgeUpdate {
+        self.edges.entry(half_edge.clone()).or_default()
+    }
+
+    fn replace(&mut self, half_edge: &Handle<HalfEdge>, new: NewHalfEdge) {
+        self.entry(half_edge).replacement = Some(new);
+    }
+
+    fn insert_after(&mut self, half_edge: &Handle<HalfEdge>, new: NewHalfEdge) {
+        self.entry(half_edge).followed_by = Some(new);
+    }
+
+    /// Apply the updates to the provided shell
+    pub(super) fn apply(self, shell: &Shell, core: &mut Core) -> Shell {
+        let mut updated = shell.clone();
+
+        for face in shell.faces() {
+            let is_affected = face
+                .region()
+                .all_cycles()
+                .flat_map(|cycle| cycle.half_edges())
+                .any(|half_edge| self.edges.contains_key(half_edge));
+
+            if is_affected {
+                updated = updated.update_face(
+                    face,
+                    |face, core| [self.update_face(face, core)],
+                    core,
+                );
+            }
+        }
+
+        updated.add_faces(self.faces, core)
+    }
+
+    fn update_face(&self, face: &Handle<Face>, core: &mut Core) -> Face {
+        // Faces that get new curved corners need a surface, in whose
+        // coordinate system the corners can be represented as circles. For
+        // circular corners, that's a surface with a uniform and orthogonal
+        // coordinate system. Elliptical corners need a coordinate system that
+        // is fitted to the ellipse.
+        let surface_geom =
+            core.layers.geometry.of_surface(face.surface()).clone();
+        let corner = face
+            .region()
+            .all_cycles()
+            .flat_map(|cycle| cycle.half_edges())
+            .filter_map(|half_edge| self.edges.get(half_edge))
+            .filter_map(|update| update.followed_by.as_ref())
+            .map(|new| &new.path)
+            .find(|path| {
+                matches!(path, GlobalPath::Circle(_) | GlobalPath::Ellipse(_))
+            });
+
+        let surface = match corner {
+            Some(GlobalPath::Circle(_))
+                if !is_orthonormal_plane(&surface_geom) =>
+            {
+                orthonormal_plane(&surface_geom, core)
+            }
+            Some(GlobalPath::Ellipse(ellipse)) => {
+                circular_plane(&surface_geom, ellipse, core)
+            }
+            _ => {
+                let surface = face.surface().clone();
+                return face.update_region(
+                    |region, core| {
+                        self.update_region(region, &surface, None, core)
+                    },
+                    core,
+                );
+            }
+        };
+        let region = self
+            .update_region(face.region(), &surface, Some(&surface_geom), core)
+            .insert(core)
+            .derive_from(face.region(), core);
+
+        Face::new(surface, region)
+    }
+
+    /// Update the cycles of a region
+    ///
+    /// If the region is moved to a new surface, `previous_surface` must be the
+    /// geometry of the surface it was on before.
+    fn update_region(
+        &self,
+        region: &Handle<Region>,
+        surface: &Handle<Surface>,
+        previous_surface: Option<&SurfaceGeom>,
+        core: &mut Core,
+    ) -> Region {
+        let mut updated = region.update_exterior(
+            |cycle, core| {
+                self.update_cycle(cycle, surface, previous_surface, core)
+            },
+            core,
+        );
+
+        for interior in region.interiors() {
+            updated = updated.update_interior(
+                interior,
+                |cycle, core| {
+                    [self.update_cycle(cycle, surface, previous_surface, core)]
+                },
+                core,
+            );
+        }
+
+        updated
+    }
+
+    fn update_cycle(
+        &self,
+        cycle: &Handle<Cycle>,
+        surface: &Handle<Surface>,
+        previous_surface: Option<&SurfaceGeom>,
+        core: &mut Core,
+    ) -> Cycle {
+        let mut updated = cycle.clone_object();
+
+        for half_edge in cycle.half_edges() {
+            let update = self.edges.get(half_edge);
+
+            if update.is_none() && previous_surface.is_none() {
+                continue;
+            }
+
+            updated = updated.update_half_edge(
+                half_edge,
+                |half_edge, core| {
+                    let mut half_edges = Vec::new();
+
+                    if let Some(new) =
+                        update.and_then(|update| update.replacement.as_ref())
+                    {
+                        half_edges.push(new.build(surface, core));
+                    } else {
+                        half_edges.push(trim_half_edge(
+                            half_edge,
+                            update,
+                            surface,
+                            previous_surface,
+                            core,
+                        ));
+                    }
+
+                    if let Some(new) =
+                        update.and_then(|update| update.followed_by.as_ref())
+                    {
+                        half_edges.push(new.build(surface, core));
+                    }
+
+                    half_edges
+                },
+                core,
+            );
+        }
+
+        updated
+    }
+}
+
+/// The changes to a single half-edge
+#[derive(Default)]
+struct HalfEdgeUpdate {
+    /// A new start vertex and start of the boundary
+    start: Option<(Handle<Vertex>, Point<1>)>,
+
+    /// A new end of the boundary
+    end: Option<Point<1>>,
+
+    /// A half-edge that replaces this one completely
+    replacement: Option<NewHalfEdge>,
+
+    /// A half-edge that is inserted after this one
+    followed_by: Option<NewHalfEdge>,
+}
+
+/// A half-edge that is yet to be built, defined in global coordinates
+struct NewHalfEdge {
+    curve: Handle<Curve>,
+    start_vertex: Handle<Vertex>,
+    path: GlobalPath,
+    boundary: CurveBoundary<Point<1>>,
+}
+
+impl NewHalfEdge {
+    fn build(
+        &self,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> Handle<HalfEdge> {
+        let path = core
+            .layers
+            .geometry
+            .of_surface(surface)
+            .project_global_path(self.path.clone());
+
+        let curve = self.curve.clone().make_path_on_surface(
+            path.clone(),
+            surface.clone(),
+            &mut core.layers.geometry,
+        );
+        HalfEdge::new(curve, self.start_vertex.clone())
+            .insert(core)
+            .set_geometry(
+                HalfEdgeGeom {
+                    path,
+                    boundary: self.boundary,
+                },
+                &mut core.layers.geometry,
+            )
+    }
+}
+
+/// Build a copy of the half-edge, applying the provided update
+///
+/// If the half-edge is moved to a new surface, `previous_surface` must be the
+/// geometry of the surface it was on before.
+fn trim_half_edge(
+    half_edge: &Handle<HalfEdge>,
+    update: Option<&HalfEdgeUpdate>,
+    surface: &Handle<Surface>,
+    previous_surface: Option<&SurfaceGeom>,
+    core: &mut Core,
+) -> Handle<HalfEdge> {
+    let HalfEdgeGeom { path, boundary } =
+        core.layers.geometry.of_half_edge(half_edge).clone();
+
+    let mut start_vertex = half_edge.start_vertex().clone();
+    let mut boundary = boundary;
+    if let Some(update) = update {
+        if let Some((vertex, point)) = &update.start {
+            start_vertex = vertex.clone();
+            boundary.inner[0] = *point;
+        }
+        if let Some(point) = update.end {
+            boundary.inner[1] = point;
+        }
+    }
+
+    let path = if let Some(previous_surface) = previous_surface {
+        let global = previous_surface.path_from_surface_coords(path);
+        core.layers
+            .geometry
+            .of_surface(surface)
+            .project_global_path(global)
+    } else {
+        path
+    };
+
+    HalfEdge::new(half_edge.curve().clone(), start_vertex)
+        .insert(core)
+        .set_geometry(
+            HalfEdgeGeom { path, boundary },
+            &mut core.layers.geometry,
+        )
+}
+
+/// Find the face and cycle that contain the provided half-edge
+fn find_half_edge(
+    shell: &Shell,
+    half_edge: &Handle<HalfEdge>,
+) -> Option<(Handle<Face>, Handle<Cycle>)> {
+    for face in shell.faces() {
+        for cycle in face.region().all_cycles() {
+            if cycle.half_edges().contains(half_edge) {
+                return Some((face.clone(), cycle.clone()));
+            }
+        }
+    }
+
+    None
+}
+
+/// The half-edges before and after the provided one, within its cycle
+fn neighbors_in_cycle(
+    cycle: &Cycle,
+    half_edge: &Handle<HalfEdge>,
+) -> [Handle<HalfEdge>; 2] {
+    let half_edges = cycle.half_edges();
+    let index = half_edges
+        .index_of(half_edge)
+        .expect("Expected half-edge to be part of cycle");
+
+    [
+        half_edges
+            .nth_circular(index + half_edges.len() - 1)
+            .clone(),
+        half_edges.nth_circular(index + 1).clone(),
+    ]
+}
+
+/// The start and end point of a half-edge, which must be a line segment
+pub(super) fn line_segment(
+    half_edge: &Handle<HalfEdge>,
+    surface: &Handle<Surface>,
+    core: &Core,
+) -> [Point<3>; 2] {
+    let geometry = &core.layers.geometry;
+    let half_edge_geom = geometry.of_half_edge(half_edge);
+
+    let SurfacePath::Line(_) = half_edge_geom.path else {
+        todo!("Blending edges next to curved edges is not supported yet")
+    };
+
+    half_edge_geom.boundary.inner.map(|point| {
+        geometry.of_surface(surface).point_from_surface_coords(
+            half_edge_geom.path.point_from_path_coords(point),
+        )
+    })
+}
+
+fn is_orthonormal_plane(surface: &SurfaceGeom) -> bool {
+    let SurfaceGeom::Swept {
+        u: GlobalPath::Line(u),
+        v: SweepPath::Line(v),
+    } = surface
+    else {
+        return false;
+    };
+
+    u.direction().magnitude() == Scalar::ONE
+        && v.magnitude() == Scalar::ONE
+        && u.direction().dot(v) == Scalar::ZERO
+}
+
+/// Create a plane that is identical to the provided one, except that its
+/// coordinate system is uniform and orthogonal
+fn orthonormal_plane(
+    surface: &SurfaceGeom,
+    core: &mut Core,
+) -> Handle<Surface> {
+    let SurfaceGeom::Swept {
+        u: GlobalPath::Line(u),
+        v: SweepPath::Line(v),
+    } = surface
+    else {
+        unreachable!("Checked that surfaces of trimmed faces are planes")
+    };
+
+    let u_direction = u.direction().normalize();
+    let v_direction = (*v - u_direction * u_direction.dot(v)).normalize();
+
+    Surface::from_uv(
+        GlobalPath::Line(Line::from_origin_and_direction(
+            u.origin(),
+            u_direction,
+        )),
+        v_direction,
+        core,
+    )
+}
+
+/// Create a plane that is identical to the provided one, except that the
+/// provided ellipse is a circle in its coordinate system
+fn circular_plane(
+    surface: &SurfaceGeom,
+    ellipse: &Ellipse<3>,
+    core: &mut Core,
+) -> Handle<Surface> {
+    let SurfaceGeom::Swept {
+        u: GlobalPath::Line(u),
+        v: SweepPath::Line(v),
+    } = surface
+    else {
+        unreachable!("Checked that surfaces of trimmed faces are planes")
+    };
+
+    // The new plane must face the same way as the original one.
+    let normal = u.direction().cross(v);
+    let b = if ellipse.a().cross(&ellipse.b()).dot(&normal) < Scalar::ZERO {
+        -ellipse.b()
+    } else {
+        ellipse.b()
+    };
+
+    Surface::from_uv(
+        GlobalPath::Line(Line::from_origin_and_direction(
+            u.origin(),
+            ellipse.a(),
+        )),
+        b,
+        core,
+    )
+}
@@ -0,0 +1,416 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        classify::{classify_point_by_triangles, PointClassification},
+    },
+    geometry::Geometry,
+    operations::{
+        derive::DeriveFrom,
+        imprint::{
+            assemble, imprint, sample_point, Cut, FaceBoundary, Location,
+        },
+        insert::Insert,
+        reverse::Reverse,
+    },
+    storage::Handle,
+    topology::{Face, Shell, Solid},
+    Core,
+};
+
+/// The boolean operations, as far as [`combine`] is concerned
+#[derive(Clone, Copy)]
+pub(super) enum Operation {
+    Union,
+    Difference,
+    Intersection,
+}
+
+impl Operation {
+    /// Decide whether to keep a part of the first solid's boundary
+    fn keep_a(&self, location: PieceLocation) -> bool {
+        match self {
+            Self::Union => matches!(
+                location,
+                PieceLocation::Outside | PieceLocation::OnSame
+            ),
+            Self::Difference => matches!(
+                location,
+                PieceLocation::Outside | PieceLocation::OnOpposite
+            ),
+            Self::Intersection => matches!(
+                location,
+                PieceLocation::Inside | PieceLocation::OnSame
+            ),
+        }
+    }
+
+    /// Decide whether to keep a part of the second solid's boundary
+    ///
+    /// Where the boundaries of both solids overlap, the first solid's faces
+    /// are kept, if anything.
+    fn keep_b(&self, location: PieceLocation) -> bool {
+        match self {
+            Self::Union => matches!(location, PieceLocation::Outside),
+            Self::Difference | Self::Intersection => {
+                matches!(location, PieceLocation::Inside)
+            }
+        }
+    }
+}
+
+/// The location of a part of one solid's boundary, relative to the other solid
+#[derive(Clone, Copy)]
+enum PieceLocation {
+    Inside,
+    Outside,
+
+    /// On the other solid's boundary, facing the same direction
+    OnSame,
+
+    /// On the other solid's boundary, facing the opposite direction
+    OnOpposite,
+}
+
+/// Combine two solids, according to a boolean operation
+///
+/// The faces of both solids are intersected with each other, and the
+/// intersections are imprinted onto the shells of both. Each face of the
+/// resulting shells is then located fully inside or outside of the other
+/// solid, or on its boundary, and is kept or discarded as a whole.
+///
+/// Shells that don't touch the other solid at all are kept or discarded as a
+/// whole, without being rebuilt.
+pub(super) fn combine(
+    a: &Solid,
+    b: &Solid,
+    operation: Operation,
+    core: &mut Core,
+) -> Solid {
+    let tolerance = core.layers.validation.config.distinct_min_distance;
+
+    let solids = [a, b].map(|solid| SolidFaces::new(solid, core));
+    let [faces_a, faces_b] = &solids;
+
+    let mut cuts = [BTreeMap::new(), BTreeMap::new()];
+    let mut coincident = [BTreeMap::new(), BTreeMap::new()];
+    let mut touched = BTreeSet::new();
+
+    for face_a in &faces_a.faces {
+        for face_b in &faces_b.faces {
+            if !overlap(&face_a.aabb, &face_b.aabb, tolerance) {
+                continue;
+            }
+
+            let intersection =
+                face_a.boundary.intersect(&face_b.boundary, tolerance);
+            let [cuts_a, cuts_b] = intersection.cuts;
+
+            if intersection.coincident {
+                coincident[0]
+                    .entry(face_a.face.clone())
+                    .or_insert_with(Vec::new)
+                    .push(face_b.face.clone());
+                coincident[1]
+                    .entry(face_b.face.clone())
+                    .or_insert_with(Vec::new)
+                    .push(face_a.face.clone());
+            } else if cuts_a.is_empty() && cuts_b.is_empty() {
+                continue;
+            }
+
+            touched.insert(face_a.face.clone());
+            touched.insert(face_b.face.clone());
+
+            let [all_cuts_a, all_cuts_b] = &mut cuts;
+            for (cuts, face, new_cuts) in [
+                (all_cuts_a, &face_a.face, cuts_a),
+                (all_cuts_b, &face_b.face, cuts_b),
+            ] {
+                cuts.entry(face.clone())
+                    .or_insert_with(Vec::new)
+                    .extend(new_cuts);
+            }
+        }
+    }
+
+    let mut shells = Vec::new();
+    let mut kept = Vec::new();
+
+    for (i, (solid, cuts)) in [a, b].into_iter().zip(cuts).enumerate() {
+        let mut cuts: BTreeMap<Handle<Face>, Vec<Cut>> = cuts;
+        let other = &solids[1 - i];
+        let mut triangles = None;
+
+        let keep = |location| {
+            if i == 0 {
+                operation.keep_a(location)
+            } else {
+                operation.keep_b(location)
+            }
+        };
+        let reverse = i == 1 && matches!(operation, Operation::Difference);
+
+        for shell in solid.shells() {
+            let is_touched =
+                shell.faces().iter().any(|face| touched.contains(face));
+
+            if !is_touched {
+                // The shell is located fully inside or outside of the other
+                // solid. A single point is enough to tell which.
+                let face = shell.faces().first();
+                let location = locate_face(
+                    face,
+                    &[],
+                    other,
+                    &mut triangles,
+                    tolerance,
+                    core,
+                );
+
+                if keep(location) {
+                    if reverse {
+                        let faces = shell
+                            .faces()
+                            .iter()
+                            .map(|face| reverse_face(face, core))
+                            .collect::<Vec<_>>();
+                        shells.push(Shell::new(faces).insert(core));
+                    } else {
+                        shells.push(shell.clone());
+                    }
+                }
+
+                continue;
+            }
+
+            let cuts_of_shell = shell
+                .faces()
+                .iter()
+                .filter_map(|face| {
+                    cuts.remove(face).map(|cuts| (face.clone(), cuts))
+                })
+                .collect();
+            let pieces = imprint(shell, cuts_of_shell, tolerance, core);
+
+            for (piece, original) in pieces {
+                let coincident =
+                    coincident[i].get(&original).cloned().unwrap_or_default();
+                let location = locate_face(
+                    &piece,
+                    &coincident,
+                    other,
+                    &mut triangles,
+                    tolerance,
+                    core,
+                );
+
+                if keep(location) {
+                    let piece = if reverse {
+                        reverse_face(&piece, core)
+                    } else {
+                        piece
+                    };
+                    kept.push(piece);
+                }
+            }
+        }
+    }
+
+    shells.extend(assemble(kept, tolerance, core));
+
+    Solid::new(shells)
+}
+
+/// The faces of a solid, prepared for intersecting them
+struct SolidFaces {
+    faces: Vec<PreparedFace>,
+    aabb: Option<Aabb<3>>,
+    solid: Solid,
+}
+
+impl SolidFaces {
+    fn new(solid: &Solid, core: &Core) -> Self {
+        let geometry = &core.layers.geometry;
+
+        let faces = solid
+            .shells()
+            .iter()
+            .flat_map(|shell| shell.faces())
+            .filter_map(|face| {
+                let aabb = aabb_of_face(face, geometry)?;
+                Some(PreparedFace {
+                    face: face.clone(),
+                    boundary: FaceBoundary::new(face, core),
+                    aabb,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let aabb = faces
+            .iter()
+            .map(|face| face.aabb)
+            .reduce(|a, b| a.merged(&b));
+
+        Self {
+            faces,
+            aabb,
+            solid: solid.clone(),
+        }
+    }
+
+    /// Approximate the solid as triangles, for classifying points against it
+    fn triangles(&self, geometry: &Geometry) -> Vec<[Point<3>; 3]> {
+        let size = self
+            .aabb
+            .map(|aabb| aabb.size().magnitude())
+            .unwrap_or(Scalar::ONE);
+        let tolerance = Tolerance::from_scalar(size * 1e-4)
+            .expect("Size of solid must be positive");
+
+        self.solid
+            .approx(tolerance, geometry)
+            .into_iter()
+            .flat_map(|face| face.triangles())
+            .collect()
+    }
+
+    fn face(&self, face: &Handle<Face>) -> Option<&PreparedFace> {
+        self.faces.iter().find(|prepared| &prepared.face == face)
+    }
+}
+
+struct PreparedFace {
+    face: Handle<Face>,
+    boundary: FaceBoundary,
+    aabb: Aabb<3>,
+}
+
+/// Locate a face, that is not intersected by the other solid's boundary
+///
+/// Since the face can't be partially inside of the other solid, any point of
+/// the face can be used to locate it. `coincident` are the faces of the other
+/// solid, that are located on the same surface.
+fn locate_face(
+    face: &Handle<Face>,
+    coincident: &[Handle<Face>],
+    other: &SolidFaces,
+    triangles: &mut Option<Vec<[Point<3>; 3]>>,
+    tolerance: Scalar,
+    core: &Core,
+) -> PieceLocation {
+    let geometry = &core.layers.geometry;
+
+    let point_surface = sample_point(face, geometry);
+    let point = geometry
+        .of_surface(face.surface())
+        .point_from_surface_coords(point_surface);
+
+    for other_face in coincident {
+        let Some(prepared) = other.face(other_face) else {
+            continue;
+        };
+
+        let point_other =
+            prepared.boundary.surface().project_global_point(point);
+        if let Location::Outside =
+            prepared.boundary.locate(point_other, tolerance)
+        {
+            continue;
+        }
+
+        let normal = face.normal_at(point_surface, geometry);
+        let normal_other = other_face.normal_at(point_other, geometry);
+
+        if let (Some(normal), Some(normal_other)) = (normal, normal_other) {
+            return if normal.dot(&normal_other) > Scalar::ZERO {
+                PieceLocation::OnSame
+            } else {
+                PieceLocation::OnOpposite
+            };
+        }
+    }
+
+    let is_disjoint = match other.aabb {
+        Some(aabb) => {
+            let point_aabb = Aabb {
+                min: point,
+                max: point,
+            };
+            !overlap(&aabb, &point_aabb, tolerance)
+        }
+        None => true,
+    };
+    if is_disjoint {
+        return PieceLocation::Outside;
+    }
+
+    let triangles = triangles.get_or_insert_with(|| other.triangles(geometry));
+    match classify_point_by_triangles(
+        point,
+        triangles.iter().copied(),
+        tolerance,
+    ) {
+        PointClassification::Inside => PieceLocation::Inside,
+        PointClassification::Outside | PointClassification::OnBoundary => {
+            PieceLocation::Outside
+        }
+    }
+}
+
+/// Compute a conservative AABB of a face
+///
+/// The AABB is computed from points along the edges of the face, then
+/// enlarged, to account for the edges curving between those points. This works
+/// for planes and cylinders, on which any point of a face is located on a
+/// straight line between two points of its boundary.
+fn aabb_of_face(face: &Face, geometry: &Geometry) -> Option<Aabb<3>> {
+    const POINTS_PER_EDGE: u32 = 32;
+
+    let surface = geometry.of_surface(face.surface());
+
+    let mut points = Vec::new();
+    for cycle in face.region().all_cycles() {
+        for half_edge in cycle.half_edges() {
+            let geometry = geometry.of_half_edge(half_edge);
+            let [a, b] = geometry.boundary.inner;
+
+            for i in 0..=POINTS_PER_EDGE {
+                let t = a.t
+                    + (b.t - a.t) * (f64::from(i) / f64::from(POINTS_PER_EDGE));
+                points.push(surface.point_from_surface_coords(
+                    geometry.path.point_from_path_coords([t]),
+                ));
+            }
+        }
+    }
+    if points.is_empty() {
+        return None;
+    }
+
+    let aabb = Aabb::<3>::from_points(points);
+    let margin = aabb.size().magnitude() * 0.05;
+    let margin = Vector::from([margin, margin, margin]);
+
+    Some(Aabb {
+        min: aabb.min - margin,
+        max: aabb.max + margin,
+    })
+}
+
+fn reverse_face(face: &Handle<Face>, core: &mut Core) -> Handle<Face> {
+    face.reverse(core).insert(core).derive_from(face, core)
+}
+
+/// Determine whether two AABBs overlap, or are within `margin` of each other
+fn overlap(a: &Aabb<3>, b: &Aabb<3>, margin: Scalar) -> bool {
+    (0..3).all(|i| {
+        let [a_min, a_max, b_min, b_max] = [a.min, a.max, b.min, b.max]
+            .map(|point| point.coords.components[i]);
+
+        a_min <= b_max + margin && b_min <= a_max + margin
+    })
+}
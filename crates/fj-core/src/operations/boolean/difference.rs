@@ -0,0 +1,29 @@
+use crate::{topology::Solid, Core};
+
+use super::combine::{combine, Operation};
+
+/// Compute the difference of two [`Solid`]s
+///
+/// See [module-level documentation] for context.
+///
+/// [module-level documentation]: super
+pub trait Difference {
+    /// Subtract another solid from this one
+    ///
+    /// The resulting solid covers all space that is covered by this solid, but
+    /// not by the other one. Faces of the result keep the surface and color of
+    /// the face they originate from.
+    ///
+    /// This can be used to cut holes and pockets into a solid, for example by
+    /// subtracting a swept circle from it. If the other solid is located fully
+    /// within this one, the result has an additional shell that bounds the
+    /// resulting cavity.
+    #[must_use]
+    fn difference(&self, other: &Self, core: &mut Core) -> Self;
+}
+
+impl Difference for Solid {
+    fn difference(&self, other: &Self, core: &mut Core) -> Self {
+        combine(self, other, Operation::Difference, core)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::{topology::Solid, Core};
+
+use super::combine::{combine, Operation};
+
+/// Compute the intersection of two [`Solid`]s
+///
+/// See [module-level documentation] for context.
+///
+/// [module-level documentation]: super
+pub trait Intersection {
+    /// Compute the intersection of this solid and another
+    ///
+    /// The resulting solid covers all space that is covered by both of the
+    /// solids. Faces of the result keep the surface and color of the face they
+    /// originate from.
+    ///
+    /// If the solids don't overlap, the resulting solid has no shells.
+    #[must_use]
+    fn intersection(&self, other: &Self, core: &mut Core) -> Self;
+}
+
+impl Intersection for Solid {
+    fn intersection(&self, other: &Self, core: &mut Core) -> Self {
+        combine(self, other, Operation::Intersection, core)
+    }
+}
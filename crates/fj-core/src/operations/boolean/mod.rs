@@ -0,0 +1,414 @@
+//! # Boolean operations on solids
+//!
+//! See [`Union`], [`Difference`], and [`Intersection`].
+//!
+//! The faces of both solids are intersected with each other, and the shells of
+//! both solids are split along those intersections, using [`SplitEdge`] and
+//! [`SplitFace`]. The resulting faces are kept or discarded, depending on
+//! whether they are located inside or outside of the other solid, and then
+//! assembled into the shells of the result. Adjacent faces on the same plane,
+//! that have the same orientation and color, are merged.
+//!
+//! ## Implementation Note
+//!
+//! Boolean operations are currently limited to solids that are bounded by
+//! planar and cylindrical faces, with the axes of all cylinders being
+//! parallel. Operations on solids with other faces will panic.
+//!
+//! Results that are not manifold (for example two solids that touch in a
+//! single edge) are not supported either.
+//!
+//! [`SplitEdge`]: super::split::SplitEdge
+//! [`SplitFace`]: super::split::SplitFace
+
+mod combine;
+mod difference;
+mod intersection;
+mod union;
+
+pub use self::{
+    difference::Difference, intersection::Intersection, union::Union,
+};
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        operations::{
+            build::BuildSolid,
+            fixtures::{assert_close, cuboid, cylinder, volume},
+            insert::Insert,
+            presentation::{GetColor, SetColor},
+        },
+        topology::Solid,
+        Core,
+    };
+
+    use super::{Difference, Intersection, Union};
+
+    #[test]
+    fn union_of_overlapping_cuboids() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([1., 1., 1.], [2.; 3], &mut core);
+
+        let union = a.union(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = union.shells().only();
+        assert_eq!(shell.faces().len(), 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_disjoint_cuboids() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([3., 0., 0.], [2.; 3], &mut core);
+
+        let union = a.union(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(union.shells().len(), 2);
+        for shell in union.shells() {
+            assert_eq!(shell.faces().len(), 6);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_cuboids_with_shared_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([2., 0., 0.], [2.; 3], &mut core);
+
+        let union = a.union(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = union.shells().only();
+        assert_eq!(shell.faces().len(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_cuboid_and_cylinder() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cylinder([2., 1., 0.5], 0.5, 1., &mut core);
+
+        let union = a.union(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        // The part of the cylinder's side that is kept, is divided by the seam
+        // of the cylinder into two faces.
+        let shell = union.shells().only();
+        assert_eq!(shell.faces().len(), 10);
+
+        let faces_with_holes = shell
+            .faces()
+            .iter()
+            .filter(|face| face.region().interiors().len() == 1)
+            .count();
+        assert_eq!(faces_with_holes, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_cuboid_on_top() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The bottom of the smaller cuboid lies within the top face of the
+        // larger one.
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([0.5, 0.5, 2.], [1.; 3], &mut core);
+
+        let union = a.union(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = union.shells().only();
+        assert_eq!(shell.faces().len(), 11);
+
+        assert_close(volume(&union, &core), 9.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_tangent_cylinder() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The side of the cylinder touches a side of the cuboid along a line.
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cylinder([2.5, 1., 0.5], 0.5, 1., &mut core);
+
+        let union = a.union(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(union.shells().len(), 2);
+
+        let expected = Scalar::from(8.) + Scalar::PI * 0.5 * 0.5;
+        assert_close(volume(&union, &core), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_of_overlapping_cuboids() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([1., 1., 1.], [2.; 3], &mut core);
+
+        let difference = a.difference(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = difference.shells().only();
+        assert_eq!(shell.faces().len(), 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_through_hole() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = Solid::tetrahedron(
+            [
+                [0.5, 0.5, -1.],
+                [1., 1.5, -1.],
+                [1.5, 0.5, -1.],
+                [1., 1., 5.],
+            ],
+            &mut core,
+        )
+        .solid;
+
+        let difference = a.difference(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = difference.shells().only();
+        assert_eq!(shell.faces().len(), 9);
+
+        let faces_with_holes = shell
+            .faces()
+            .iter()
+            .filter(|face| face.region().interiors().len() == 1)
+            .count();
+        assert_eq!(faces_with_holes, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_round_through_hole() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cylinder([1., 1., -1.], 0.5, 4., &mut core);
+
+        let difference = a.difference(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = difference.shells().only();
+        assert_eq!(shell.faces().len(), 7);
+
+        let faces_with_holes = shell
+            .faces()
+            .iter()
+            .filter(|face| face.region().interiors().len() == 1)
+            .count();
+        assert_eq!(faces_with_holes, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_round_pocket() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cylinder([1., 1., 1.], 0.5, 2., &mut core);
+
+        let difference = a.difference(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        // The pocket adds its wall and its floor to the faces of the cuboid.
+        let shell = difference.shells().only();
+        assert_eq!(shell.faces().len(), 8);
+
+        let faces_with_holes = shell
+            .faces()
+            .iter()
+            .filter(|face| face.region().interiors().len() == 1)
+            .count();
+        assert_eq!(faces_with_holes, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_flush_pocket() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The top of the pocket is located within the top face of the cuboid.
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([0.5, 0.5, 1.], [1.; 3], &mut core);
+
+        let difference = a.difference(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = difference.shells().only();
+        assert_eq!(shell.faces().len(), 11);
+
+        assert_close(volume(&difference, &core), 7.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_flush_pocket_at_bottom() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([0.5, 0.5, 0.], [1.; 3], &mut core);
+
+        let difference = a.difference(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = difference.shells().only();
+        assert_eq!(shell.faces().len(), 11);
+
+        assert_close(volume(&difference, &core), 7.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_flush_round_pocket() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cylinder([1., 1., 1.], 0.5, 1., &mut core);
+
+        let difference = a.difference(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = difference.shells().only();
+        assert_eq!(shell.faces().len(), 8);
+
+        let expected = Scalar::from(8.) - Scalar::PI * 0.5 * 0.5;
+        assert_close(volume(&difference, &core), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_cavity() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [4.; 3], &mut core);
+        let b = cuboid([1., 1., 1.], [2.; 3], &mut core);
+
+        let difference = a.difference(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(difference.shells().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_of_overlapping_cuboids() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let red = [255, 0, 0, 255];
+        let blue = [0, 0, 255, 255];
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([1., 1., 1.], [2.; 3], &mut core);
+        for (solid, color) in [(&a, red), (&b, blue)] {
+            for face in solid.shells().iter().flat_map(|shell| shell.faces()) {
+                face.region().set_color(color, &mut core);
+            }
+        }
+
+        let intersection = a.intersection(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = intersection.shells().only();
+        assert_eq!(shell.faces().len(), 6);
+
+        let colors = shell
+            .faces()
+            .iter()
+            .map(|face| face.region().get_color(&mut core))
+            .collect::<Vec<_>>();
+        for color in [red, blue] {
+            let num_faces =
+                colors.iter().filter(|&&c| c == Some(color.into())).count();
+            assert_eq!(num_faces, 3);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_with_flush_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The top of the smaller cuboid lies within the top face of the larger
+        // one.
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([0.5, 0.5, 1.], [1.; 3], &mut core);
+
+        let intersection = a.intersection(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = intersection.shells().only();
+        assert_eq!(shell.faces().len(), 6);
+
+        assert_close(volume(&intersection, &core), 1.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_of_touching_cuboids() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([0.5, 0.5, 2.], [1.; 3], &mut core);
+
+        let intersection = a.intersection(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert!(intersection.shells().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_of_disjoint_cuboids() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let b = cuboid([3., 0., 0.], [2.; 3], &mut core);
+
+        let intersection = a.intersection(&b, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert!(intersection.shells().is_empty());
+
+        Ok(())
+    }
+}
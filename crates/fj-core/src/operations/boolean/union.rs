@@ -0,0 +1,24 @@
+use crate::{topology::Solid, Core};
+
+use super::combine::{combine, Operation};
+
+/// Compute the union of two [`Solid`]s
+///
+/// See [module-level documentation] for context.
+///
+/// [module-level documentation]: super
+pub trait Union {
+    /// Compute the union of this solid and another
+    ///
+    /// The resulting solid covers all space that is covered by either of the
+    /// two solids. Faces of the result keep the surface and color of the face
+    /// they originate from.
+    #[must_use]
+    fn union(&self, other: &Self, core: &mut Core) -> Self;
+}
+
+impl Union for Solid {
+    fn union(&self, other: &Self, core: &mut Core) -> Self {
+        combine(self, other, Operation::Union, core)
+    }
+}
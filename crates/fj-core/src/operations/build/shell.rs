@@ -112,7 +112,8 @@ pub trait BuildShell {
                                                 defined in same function",
                                             )
                                             .local_on(&surface)
-                                            .path,
+                                            .path
+                                            .clone(),
                                         boundary,
                                     },
                                     &mut core.layers.geometry,
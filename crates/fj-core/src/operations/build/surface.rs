@@ -1,7 +1,7 @@
-use fj_math::{Point, Scalar, Vector};
+use fj_math::{Point, Scalar};
 
 use crate::{
-    geometry::{GlobalPath, SurfaceGeom},
+    geometry::{GlobalPath, SurfaceGeom, SweepPath},
     operations::insert::Insert,
     storage::Handle,
     topology::Surface,
@@ -37,17 +37,17 @@ pub trait BuildSurface {
         (surface, points_surface)
     }
 
-    /// Build a plane from the provided `u` and `v`
+    /// Build a surface from the provided `u` and `v`
     fn from_uv(
         u: impl Into<GlobalPath>,
-        v: impl Into<Vector<3>>,
+        v: impl Into<SweepPath>,
         core: &mut Core,
     ) -> Handle<Surface> {
         let surface = Surface::new().insert(core);
 
         core.layers.geometry.define_surface(
             surface.clone(),
-            SurfaceGeom {
+            SurfaceGeom::Swept {
                 u: u.into(),
                 v: v.into(),
             },
@@ -55,6 +55,82 @@ pub trait BuildSurface {
 
         surface
     }
+
+    /// Build a sphere
+    ///
+    /// See [`SurfaceGeom::sphere`].
+    fn sphere(
+        center: impl Into<Point<3>>,
+        radius: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Handle<Surface> {
+        let surface = Surface::new().insert(core);
+
+        core.layers.geometry.define_surface(
+            surface.clone(),
+            SurfaceGeom::sphere(center, radius),
+        );
+
+        surface
+    }
+
+    /// Build a cone
+    ///
+    /// See [`SurfaceGeom::cone`].
+    fn cone(
+        apex: impl Into<Point<3>>,
+        half_angle: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Handle<Surface> {
+        let surface = Surface::new().insert(core);
+
+        core.layers.geometry.define_surface(
+            surface.clone(),
+            SurfaceGeom::cone(apex, half_angle),
+        );
+
+        surface
+    }
+
+    /// Build a torus
+    ///
+    /// See [`SurfaceGeom::torus`].
+    fn torus(
+        center: impl Into<Point<3>>,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Handle<Surface> {
+        let surface = Surface::new().insert(core);
+
+        core.layers.geometry.define_surface(
+            surface.clone(),
+            SurfaceGeom::torus(center, major_radius, minor_radius),
+        );
+
+        surface
+    }
+
+    /// Build a ruled surface between the provided paths
+    ///
+    /// See [`SurfaceGeom::Ruled`].
+    fn ruled(
+        a: impl Into<GlobalPath>,
+        b: impl Into<GlobalPath>,
+        core: &mut Core,
+    ) -> Handle<Surface> {
+        let surface = Surface::new().insert(core);
+
+        core.layers.geometry.define_surface(
+            surface.clone(),
+            SurfaceGeom::Ruled {
+                a: a.into(),
+                b: b.into(),
+            },
+        );
+
+        surface
+    }
 }
 
 impl BuildSurface for Surface {}
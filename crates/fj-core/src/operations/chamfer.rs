@@ -0,0 +1,379 @@
+//! Bevel the edges of a [`Shell`]
+//!
+//! See [`ChamferEdges`].
+
+use fj_math::{Line, Scalar};
+
+use crate::{
+    geometry::GlobalPath,
+    operations::blend::{BlendProfile, EdgeNeighborhood, ShellUpdates},
+    storage::Handle,
+    topology::{HalfEdge, Shell},
+    Core,
+};
+
+/// # Bevel the edges of a [`Shell`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: self
+pub trait ChamferEdges {
+    /// # Chamfer the provided edges
+    ///
+    /// Replaces each edge with a planar face, which bevels the two faces that
+    /// the edge connects. Those faces, as well as the faces at the ends of the
+    /// edge, are trimmed accordingly.
+    ///
+    /// Each edge can be specified using either of its half-edges. The face
+    /// that contains this half-edge is the first face, as far as
+    /// [`ChamferSize`] is concerned.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the size of the chamfer is not positive, or if it is too
+    /// large for the edges next to a chamfered edge. Also panics, if any of the
+    /// provided edges share a vertex, as the chamfers would need to be mitered
+    /// at that vertex, which is not supported.
+    ///
+    /// ## Implementation Note
+    ///
+    /// Only straight edges between planar faces are supported right now. At
+    /// each end of the edge, exactly one other face must meet it, and that face
+    /// must be a plane. It doesn't need to be perpendicular to the edge.
+    #[must_use]
+    fn chamfer_edges(
+        &self,
+        half_edges: impl IntoIterator<Item = Handle<HalfEdge>>,
+        size: impl Into<ChamferSize>,
+        core: &mut Core,
+    ) -> Self;
+}
+
+impl ChamferEdges for Shell {
+    fn chamfer_edges(
+        &self,
+        half_edges: impl IntoIterator<Item = Handle<HalfEdge>>,
+        size: impl Into<ChamferSize>,
+        core: &mut Core,
+    ) -> Self {
+        let size = size.into();
+
+        let mut updates = ShellUpdates::default();
+        for half_edge in half_edges {
+            let edge = EdgeNeighborhood::new(self, &half_edge, core);
+
+            if updates.contains_edge(&edge) {
+                // The sibling of this half-edge has already been chamfered.
+                continue;
+            }
+
+            let profile = chamfer_profile(&edge, size, &mut updates, core);
+            edge.blend(profile, &mut updates, core);
+        }
+
+        updates.apply(self, core)
+    }
+}
+
+/// # The size of a chamfer
+///
+/// Defines where the chamfer meets the two faces that are connected by the
+/// chamfered edge. See [`ChamferEdges`] for which face is the first one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum ChamferSize {
+    /// The chamfer meets both faces at the same distance from the edge
+    Distance(Scalar),
+
+    /// The chamfer meets the first and second face at the respective distance
+    /// from the edge
+    Distances([Scalar; 2]),
+
+    /// The chamfer meets the first face at the provided distance from the edge,
+    /// enclosing the provided angle (in radians) with it
+    DistanceAngle {
+        /// The distance from the edge, within the first face
+        distance: Scalar,
+
+        /// The angle between the first face and the chamfer
+        angle: Scalar,
+    },
+}
+
+impl From<Scalar> for ChamferSize {
+    fn from(distance: Scalar) -> Self {
+        Self::Distance(distance)
+    }
+}
+
+impl From<f64> for ChamferSize {
+    fn from(distance: f64) -> Self {
+        Self::Distance(distance.into())
+    }
+}
+
+/// The profile of a chamfer of the provided size
+fn chamfer_profile(
+    edge: &EdgeNeighborhood,
+    size: ChamferSize,
+    updates: &mut ShellUpdates,
+    core: &Core,
+) -> BlendProfile {
+    let [e1, e2] = edge.directions(updates, core);
+    let [a, _] = edge.points();
+
+    let distances = match size {
+        ChamferSize::Distance(distance) => [distance, distance],
+        ChamferSize::Distances(distances) => distances,
+        ChamferSize::DistanceAngle { distance, angle } => {
+            // The edge and the points where the chamfer meets the faces form
+            // a triangle. Its angle at the edge is the angle between the
+            // faces, and its angle at the first face is the provided one.
+            let cos = e1.dot(&e2);
+            let sin = e1.cross(&e2).magnitude();
+
+            let (angle_sin, angle_cos) = angle.sin_cos();
+            let sin_of_third_angle = sin * angle_cos + cos * angle_sin;
+            assert!(
+                angle > Scalar::ZERO && sin_of_third_angle > Scalar::ZERO,
+                "Chamfer angle must be positive and leave room for the \
+                chamfer to meet the second face"
+            );
+
+            [distance, distance * angle_sin / sin_of_third_angle]
+        }
+    };
+
+    for distance in distances {
+        assert!(distance > Scalar::ZERO, "Chamfer distance must be positive");
+    }
+
+    let [d1, d2] = distances;
+    let path = GlobalPath::Line(Line::from_points_with_line_coords([
+        ([0.], a + e1 * d1),
+        ([1.], a + e2 * d2),
+    ]));
+
+    BlendProfile {
+        path,
+        end: Scalar::ONE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        operations::{
+            fixtures::{assert_close, cuboid, find_half_edge, prism, volume},
+            insert::Insert,
+        },
+        topology::{Shell, Solid},
+        Core,
+    };
+
+    use super::{ChamferEdges, ChamferSize};
+
+    #[test]
+    fn chamfer_edge_between_side_faces() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let shell = cuboid([0., 0., 0.], [2., 3., 1.], &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edge = find_half_edge(&shell, [[0., 0., 0.], [0., 0., 1.]], &core)
+            .expect("Edge not found");
+
+        let shell = shell
+            .chamfer_edges([edge], 0.5, &mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 7);
+        for [x, y] in [[0.5, 0.], [0., 0.5]] {
+            assert!(has_edge(&shell, [[x, y, 0.], [x, y, 1.]], &core));
+        }
+        assert_close(
+            volume(&Solid::new([shell.clone()]), &core),
+            6. - removed_area([0.5, 0.5]),
+        );
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn chamfer_edge_with_two_distances() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The faces at the ends of this edge have coordinate systems that are
+        // not uniformly scaled. Unlike with fillets, they can keep them.
+        let shell = cuboid([0., 0., 0.], [2., 3., 1.], &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edge = find_half_edge(&shell, [[0., 0., 1.], [2., 0., 1.]], &core)
+            .expect("Edge not found");
+
+        let shell = shell
+            .chamfer_edges(
+                [edge],
+                ChamferSize::Distances([0.25.into(), 0.5.into()]),
+                &mut core,
+            )
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 7);
+        assert!(has_edge(&shell, [[0., 0.25, 1.], [2., 0.25, 1.]], &core));
+        assert!(has_edge(&shell, [[0., 0., 0.5], [2., 0., 0.5]], &core));
+        assert_close(
+            volume(&Solid::new([shell.clone()]), &core),
+            6. - 2. * removed_area([0.25, 0.5]),
+        );
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn chamfer_edge_with_distance_and_angle() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let shell = cuboid([0., 0., 0.], [2., 3., 1.], &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edge = find_half_edge(&shell, [[0., 0., 1.], [2., 0., 1.]], &core)
+            .expect("Edge not found");
+
+        // With an angle of 60°, the chamfer meets the second face at a
+        // distance of `tan(60°) * 0.25`.
+        let shell = shell
+            .chamfer_edges(
+                [edge],
+                ChamferSize::DistanceAngle {
+                    distance: 0.25.into(),
+                    angle: Scalar::PI / 3.,
+                },
+                &mut core,
+            )
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let distance = 0.25 * 3f64.sqrt();
+        assert_eq!(shell.faces().len(), 7);
+        assert!(has_edge(
+            &shell,
+            [[0., 0., 1. - distance], [2., 0., 1. - distance]],
+            &core
+        ));
+        assert!(has_edge(&shell, [[0., 0.25, 1.], [2., 0.25, 1.]], &core));
+        assert_close(
+            volume(&Solid::new([shell]), &core),
+            6. - 2. * removed_area([0.25, distance]),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn chamfer_multiple_edges() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let shell = cuboid([0., 0., 0.], [2., 3., 1.], &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edges = [[0., 0.], [2., 0.], [2., 3.], [0., 3.]].map(|[x, y]| {
+            find_half_edge(&shell, [[x, y, 0.], [x, y, 1.]], &core)
+                .expect("Edge not found")
+        });
+
+        let shell =
+            shell.chamfer_edges(edges, 0.5, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 10);
+        assert_close(
+            volume(&Solid::new([shell.clone()]), &core),
+            6. - 4. * removed_area([0.5, 0.5]),
+        );
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn chamfer_edge_with_oblique_ends() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The faces at the ends of the edge meet it at 45°.
+        let shell = prism(&[[0., 0.], [2., 0.], [0., 2.]], 1., &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edge = find_half_edge(&shell, [[2., 0., 1.], [0., 2., 1.]], &core)
+            .expect("Edge not found");
+
+        let distance = 0.25;
+        let shell = shell
+            .chamfer_edges([edge], distance, &mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 6);
+
+        // The chamfer removes a prism that is cut off at 45° on both ends. Its
+        // volume is the area of its cross-section, times its length through
+        // the centroid of that cross-section.
+        let length = 2. * 2f64.sqrt() - 2. * distance / 3.;
+        assert_close(
+            volume(&Solid::new([shell]), &core),
+            2. - removed_area([distance, distance]) * length,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Edges that share a vertex")]
+    fn chamfer_edges_that_share_a_vertex() {
+        let mut core = Core::new();
+
+        let shell = cuboid([0., 0., 0.], [2., 3., 1.], &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edges =
+            [[[0., 0., 1.], [2., 0., 1.]], [[2., 0., 1.], [2., 3., 1.]]].map(
+                |points| {
+                    find_half_edge(&shell, points, &core)
+                        .expect("Edge not found")
+                },
+            );
+
+        let _ = shell.chamfer_edges(edges, 0.25, &mut core);
+    }
+
+    /// The area that a chamfer between perpendicular faces removes
+    fn removed_area([a, b]: [f64; 2]) -> f64 {
+        a * b / 2.
+    }
+
+    fn has_edge(shell: &Shell, [a, b]: [[f64; 3]; 2], core: &Core) -> bool {
+        find_half_edge(shell, [a, b], core).is_some()
+            && find_half_edge(shell, [b, a], core).is_some()
+    }
+}
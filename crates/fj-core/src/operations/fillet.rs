@@ -0,0 +1,260 @@
+//! Round the edges of a [`Shell`]
+//!
+//! See [`FilletEdges`].
+
+use fj_math::{Circle, Scalar};
+
+use crate::{
+    geometry::GlobalPath,
+    operations::blend::{BlendProfile, EdgeNeighborhood, ShellUpdates},
+    storage::Handle,
+    topology::{HalfEdge, Shell},
+    Core,
+};
+
+/// # Round the edges of a [`Shell`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: self
+pub trait FilletEdges {
+    /// # Fillet the provided edges
+    ///
+    /// Replaces each edge with a cylindrical face of the provided radius, which
+    /// blends the two faces that the edge connects. Those faces, as well as the
+    /// faces at the ends of the edge, are trimmed accordingly.
+    ///
+    /// Each edge can be specified using either of its half-edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the radius is not positive, or if it is too large for the
+    /// edges next to a filleted edge. Also panics, if any of the provided edges
+    /// share a vertex, as the fillets would need to be blended into each other
+    /// at that vertex, which is not supported.
+    ///
+    /// ## Implementation Note
+    ///
+    /// Only straight edges between planar faces are supported right now. At
+    /// each end of the edge, exactly one other face must meet it, and that face
+    /// must be a plane. It doesn't need to be perpendicular to the edge.
+    #[must_use]
+    fn fillet_edges(
+        &self,
+        half_edges: impl IntoIterator<Item = Handle<HalfEdge>>,
+        radius: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Self;
+}
+
+impl FilletEdges for Shell {
+    fn fillet_edges(
+        &self,
+        half_edges: impl IntoIterator<Item = Handle<HalfEdge>>,
+        radius: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Self {
+        let radius = radius.into();
+        assert!(radius > Scalar::ZERO, "Fillet radius must be positive");
+
+        let mut updates = ShellUpdates::default();
+        for half_edge in half_edges {
+            let edge = EdgeNeighborhood::new(self, &half_edge, core);
+
+            if updates.contains_edge(&edge) {
+                // The sibling of this half-edge has already been filleted.
+                continue;
+            }
+
+            let profile = fillet_profile(&edge, radius, &mut updates, core);
+            edge.blend(profile, &mut updates, core);
+        }
+
+        updates.apply(self, core)
+    }
+}
+
+/// The profile of a fillet with the provided radius
+fn fillet_profile(
+    edge: &EdgeNeighborhood,
+    radius: Scalar,
+    updates: &mut ShellUpdates,
+    core: &Core,
+) -> BlendProfile {
+    let [e1, e2] = edge.directions(updates, core);
+    let [a, _] = edge.points();
+
+    let cos = e1.dot(&e2);
+    let sin = e1.cross(&e2).magnitude();
+
+    // The distance between the edge and the lines where the fillet touches
+    // the faces.
+    let distance = radius * (Scalar::ONE + cos) / sin;
+
+    let normal = (e2 - e1 * cos).normalize();
+    let angle = Scalar::PI - sin.atan2(cos);
+
+    // The circle that the fillet follows at `A`. Its coordinates are the angle
+    // from the first face towards the second.
+    let circle = Circle::new(
+        a + e1 * distance + normal * radius,
+        -normal * radius,
+        -e1 * radius,
+    );
+
+    BlendProfile {
+        path: GlobalPath::Circle(circle),
+        end: angle,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        operations::{
+            fixtures::{assert_close, cuboid, find_half_edge, prism, volume},
+            insert::Insert,
+        },
+        topology::Solid,
+        Core,
+    };
+
+    use super::FilletEdges;
+
+    #[test]
+    fn fillet_edge_between_side_faces() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let shell = cuboid([0., 0., 0.], [2., 3., 1.], &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edge = find_half_edge(&shell, [[0., 0., 0.], [0., 0., 1.]], &core)
+            .expect("Edge not found");
+
+        let shell =
+            shell.fillet_edges([edge], 0.5, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 7);
+        assert_close(
+            volume(&Solid::new([shell]), &core),
+            6. - removed_area(0.5),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fillet_edge_between_side_and_top_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The faces at the ends of this edge have coordinate systems that are
+        // not uniformly scaled. They need to be replaced, to accommodate the
+        // fillet.
+        let shell = cuboid([0., 0., 0.], [2., 3., 1.], &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edge = find_half_edge(&shell, [[0., 0., 1.], [2., 0., 1.]], &core)
+            .expect("Edge not found");
+
+        let shell = shell
+            .fillet_edges([edge], 0.25, &mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 7);
+        assert_close(
+            volume(&Solid::new([shell]), &core),
+            6. - 2. * removed_area(0.25),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fillet_multiple_edges() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let shell = cuboid([0., 0., 0.], [2., 3., 1.], &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edges = [[0., 0.], [2., 0.], [2., 3.], [0., 3.]].map(|[x, y]| {
+            find_half_edge(&shell, [[x, y, 0.], [x, y, 1.]], &core)
+                .expect("Edge not found")
+        });
+
+        let shell = shell.fillet_edges(edges, 0.5, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 10);
+        assert_close(
+            volume(&Solid::new([shell]), &core),
+            6. - 4. * removed_area(0.5),
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fillet_edge_with_oblique_ends() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The faces at the ends of the edge meet it at 45°. The fillet ends in
+        // an elliptical corner within each of them.
+        let shell = prism(&[[0., 0.], [2., 0.], [0., 2.]], 1., &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edge = find_half_edge(&shell, [[2., 0., 1.], [0., 2., 1.]], &core)
+            .expect("Edge not found");
+
+        let radius = 0.25;
+        let shell = shell
+            .fillet_edges([edge], radius, &mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 6);
+
+        // The fillet removes a prism that is cut off at 45° on both ends. Its
+        // volume is the area of its cross-section, times its length through
+        // the centroid of that cross-section.
+        let pi = std::f64::consts::PI;
+        let centroid = radius * (10. - 3. * pi) / (12. - 3. * pi);
+        let length = 2. * 2f64.sqrt() - 2. * centroid;
+        assert_close(
+            volume(&Solid::new([shell]), &core),
+            2. - removed_area(radius) * length,
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Edges that share a vertex")]
+    fn fillet_edges_that_share_a_vertex() {
+        let mut core = Core::new();
+
+        let shell = cuboid([0., 0., 0.], [2., 3., 1.], &mut core)
+            .shells()
+            .only()
+            .clone_object();
+        let edges =
+            [[[0., 0., 1.], [2., 0., 1.]], [[2., 0., 1.], [2., 3., 1.]]].map(
+                |points| {
+                    find_half_edge(&shell, points, &core)
+                        .expect("Edge not found")
+                },
+            );
+
+        let _ = shell.fillet_edges(edges, 0.25, &mut core);
+    }
+
+    /// The area that a fillet between perpendicular faces removes
+    fn removed_area(radius: f64) -> f64 {
+        radius * radius * (1. - std::f64::consts::PI / 4.)
+    }
+}
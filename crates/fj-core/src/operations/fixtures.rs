@@ -0,0 +1,139 @@
+//! Shapes and assertions that are shared between tests
+//!
+//! All solids are built by sweeping a sketch on the xy-plane along the z-axis,
+//! which is how most solids are built in practice.
+
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::mass_properties::ComputeMassProperties,
+    geometry::SurfacePath,
+    operations::{
+        build::{BuildCycle, BuildRegion, BuildSketch},
+        reverse::Reverse,
+        sweep::SweepSketch,
+        transform::TransformObject,
+        update::{UpdateRegion, UpdateSketch},
+    },
+    storage::Handle,
+    topology::{Cycle, HalfEdge, Region, Shell, Sketch, Solid},
+    Core,
+};
+
+/// Build a prism from a polygon on the xy-plane, with the provided height
+pub fn prism(points: &[[f64; 2]], height: f64, core: &mut Core) -> Solid {
+    let region = Region::polygon(
+        points.iter().copied(),
+        core.layers.topology.surfaces.space_2d(),
+        core,
+    );
+
+    sweep(region, height, core)
+}
+
+/// Build a cuboid of the provided size, positioned at `min`
+pub fn cuboid(
+    min: impl Into<Point<3>>,
+    [x, y, z]: [f64; 3],
+    core: &mut Core,
+) -> Solid {
+    prism(&[[0., 0.], [x, 0.], [x, y], [0., y]], z, core)
+        .translate(min.into().coords, core)
+}
+
+/// Build a cylinder along the z-axis, with its bottom centered on `center`
+pub fn cylinder(
+    center: impl Into<Point<3>>,
+    radius: f64,
+    height: f64,
+    core: &mut Core,
+) -> Solid {
+    let region = Region::circle(
+        [0., 0.],
+        radius,
+        core.layers.topology.surfaces.space_2d(),
+        core,
+    );
+
+    sweep(region, height, core).translate(center.into().coords, core)
+}
+
+/// A 2x2 square with a 1x1 square hole, swept into a solid with a volume of `3`
+pub fn solid_with_hole(core: &mut Core) -> Solid {
+    let surface = core.layers.topology.surfaces.space_2d();
+
+    let region = Region::polygon(
+        [[0., 0.], [2., 0.], [2., 2.], [0., 2.]],
+        surface.clone(),
+        core,
+    )
+    .add_interiors(
+        [Cycle::polygon(
+            [[0.5, 0.5], [1.5, 0.5], [1.5, 1.5], [0.5, 1.5]],
+            surface,
+            core,
+        )
+        .reverse(core)],
+        core,
+    );
+
+    sweep(region, 1., core)
+}
+
+/// Find the half-edge that goes from `a` to `b` in a straight line
+pub fn find_half_edge(
+    shell: &Shell,
+    [a, b]: [[f64; 3]; 2],
+    core: &Core,
+) -> Option<Handle<HalfEdge>> {
+    let geometry = &core.layers.geometry;
+    let [a, b] = [a, b].map(Point::from);
+
+    for face in shell.faces() {
+        let surface = geometry.of_surface(face.surface());
+
+        for cycle in face.region().all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let half_edge_geom = geometry.of_half_edge(half_edge);
+                let SurfacePath::Line(_) = half_edge_geom.path else {
+                    continue;
+                };
+
+                let [start, end] = half_edge_geom.boundary.inner.map(|point| {
+                    surface.point_from_surface_coords(
+                        half_edge_geom.path.point_from_path_coords(point),
+                    )
+                });
+
+                if (start - a).magnitude() < Scalar::from(1e-12)
+                    && (end - b).magnitude() < Scalar::from(1e-12)
+                {
+                    return Some(half_edge.clone());
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Compute the volume of a solid
+pub fn volume(solid: &Solid, core: &Core) -> Scalar {
+    solid.mass_properties(1., &core.layers.geometry).volume
+}
+
+/// Assert that two scalars are equal, within numerical precision
+pub fn assert_close(a: Scalar, b: impl Into<Scalar>) {
+    let b = b.into();
+    assert!((a - b).abs() < Scalar::from(1e-9), "{a:?} != {b:?}");
+}
+
+fn sweep(region: Region, height: f64, core: &mut Core) -> Solid {
+    Sketch::empty(&core.layers.topology)
+        .add_regions([region], core)
+        .sweep_sketch(
+            core.layers.topology.surfaces.xy_plane(),
+            [0., 0., height],
+            core,
+        )
+}
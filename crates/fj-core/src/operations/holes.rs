@@ -1,8 +1,9 @@
 //! Add holes to shapes
 
-use fj_math::{Point, Scalar, Vector};
+use fj_math::{Point, Scalar, Transform, Vector};
 
 use crate::{
+    geometry::SurfaceGeom,
     storage::Handle,
     topology::{Cycle, Face, HalfEdge, Region, Shell},
     Core,
@@ -11,6 +12,7 @@ use crate::{
 use super::{
     build::{BuildCycle, BuildHalfEdge, BuildRegion},
     join::JoinCycle,
+    pattern::Pattern,
     sweep::{SweepCache, SweepRegion},
     update::{UpdateCycle, UpdateFace, UpdateRegion, UpdateShell},
 };
@@ -33,6 +35,37 @@ pub trait AddHole {
         radius: impl Into<Scalar>,
         core: &mut Core,
     ) -> Self;
+
+    /// Add blind holes, repeated in the provided pattern
+    ///
+    /// The first hole is added at the provided location. The location of each
+    /// other hole is derived by transforming the first one, then projecting
+    /// the result back onto the face's surface. The path of each hole is
+    /// transformed accordingly.
+    ///
+    /// See [`AddHole::add_blind_hole`] and [`Pattern`].
+    fn add_blind_hole_pattern(
+        &self,
+        location: HoleLocation,
+        radius: impl Into<Scalar>,
+        path: impl Into<Vector<3>>,
+        pattern: &Pattern,
+        core: &mut Core,
+    ) -> Self;
+
+    /// Add through holes, repeated in the provided pattern
+    ///
+    /// The locations of the holes are derived in the same way as for
+    /// [`AddHole::add_blind_hole_pattern`].
+    ///
+    /// See [`AddHole::add_through_hole`] and [`Pattern`].
+    fn add_through_hole_pattern(
+        &self,
+        locations: [HoleLocation; 2],
+        radius: impl Into<Scalar>,
+        pattern: &Pattern,
+        core: &mut Core,
+    ) -> Self;
 }
 
 impl AddHole for Shell {
@@ -43,6 +76,8 @@ impl AddHole for Shell {
         path: impl Into<Vector<3>>,
         core: &mut Core,
     ) -> Self {
+        let path: Vector<3> = path.into();
+
         let entry = HalfEdge::circle(
             location.position,
             radius,
@@ -73,7 +108,10 @@ impl AddHole for Shell {
                             [Cycle::empty().add_joined_edges(
                                 [(
                                     entry.clone(),
-                                    *core.layers.geometry.of_half_edge(&entry),
+                                    core.layers
+                                        .geometry
+                                        .of_half_edge(&entry)
+                                        .clone(),
                                 )],
                                 core,
                             )],
@@ -148,7 +186,10 @@ impl AddHole for Shell {
                             [Cycle::empty().add_joined_edges(
                                 [(
                                     entry.clone(),
-                                    *core.layers.geometry.of_half_edge(&entry),
+                                    core.layers
+                                        .geometry
+                                        .of_half_edge(&entry)
+                                        .clone(),
                                 )],
                                 core,
                             )],
@@ -169,7 +210,10 @@ impl AddHole for Shell {
                             [Cycle::empty().add_joined_edges(
                                 [(
                                     exit.clone(),
-                                    *core.layers.geometry.of_half_edge(exit),
+                                    core.layers
+                                        .geometry
+                                        .of_half_edge(exit)
+                                        .clone(),
                                 )],
                                 core,
                             )],
@@ -183,6 +227,77 @@ impl AddHole for Shell {
         )
         .add_faces(hole, core)
     }
+
+    fn add_blind_hole_pattern(
+        &self,
+        location: HoleLocation,
+        radius: impl Into<Scalar>,
+        path: impl Into<Vector<3>>,
+        pattern: &Pattern,
+        core: &mut Core,
+    ) -> Self {
+        let radius = radius.into();
+        let path = path.into();
+
+        let origin = PatternedLocation::new(&location, self, core);
+
+        let mut shell = self.clone();
+        for transform in pattern.transforms() {
+            let face = origin.face(&shell).clone();
+            let position = origin.position(transform);
+
+            shell = shell.add_blind_hole(
+                HoleLocation {
+                    face: &face,
+                    position,
+                },
+                radius,
+                transform.transform_vector(&path),
+                core,
+            );
+        }
+
+        shell
+    }
+
+    fn add_through_hole_pattern(
+        &self,
+        [entry_location, exit_location]: [HoleLocation; 2],
+        radius: impl Into<Scalar>,
+        pattern: &Pattern,
+        core: &mut Core,
+    ) -> Self {
+        let radius = radius.into();
+
+        let entry = PatternedLocation::new(&entry_location, self, core);
+        let exit = PatternedLocation::new(&exit_location, self, core);
+
+        let mut shell = self.clone();
+        for transform in pattern.transforms() {
+            let entry_face = entry.face(&shell).clone();
+            let exit_face = exit.face(&shell).clone();
+
+            let entry_position = entry.position(transform);
+            let exit_position = exit.position(transform);
+
+            shell = shell.add_through_hole(
+                [
+                    HoleLocation {
+                        face: &entry_face,
+                        position: entry_position,
+                    },
+                    HoleLocation {
+                        face: &exit_face,
+                        position: exit_position,
+                    },
+                ],
+                radius,
+                core,
+            );
+        }
+
+        shell
+    }
 }
 
 /// Defines the location of a hole
@@ -193,3 +308,146 @@ pub struct HoleLocation<'r> {
     /// The position of the hole within the face, in surface coordinates
     pub position: Point<2>,
 }
+
+/// A hole location, as the starting point of a pattern
+///
+/// Adding a hole replaces the face it's in, so the face is tracked by its
+/// position within the shell, which doesn't change.
+struct PatternedLocation {
+    face_index: usize,
+    surface: SurfaceGeom,
+    point: Point<3>,
+}
+
+impl PatternedLocation {
+    fn new(location: &HoleLocation, shell: &Shell, core: &Core) -> Self {
+        let face_index = shell
+            .faces()
+            .index_of(location.face)
+            .expect("Face of hole location not found in shell");
+        let surface = core
+            .layers
+            .geometry
+            .of_surface(location.face.surface())
+            .clone();
+        let point = surface.point_from_surface_coords(location.position);
+
+        Self {
+            face_index,
+            surface,
+            point,
+        }
+    }
+
+    fn face<'r>(&self, shell: &'r Shell) -> &'r Handle<Face> {
+        shell
+            .faces()
+            .nth(self.face_index)
+            .expect("Adding holes doesn't remove faces")
+    }
+
+    fn position(&self, transform: &Transform) -> Point<2> {
+        self.surface
+            .project_global_point(transform.transform_point(&self.point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            insert::Insert,
+            pattern::Pattern,
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        topology::{Region, Shell, Sketch},
+        Core,
+    };
+
+    use super::{AddHole, HoleLocation};
+
+    #[test]
+    fn blind_hole_pattern() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let plate = plate(&mut core);
+        let bottom = plate.faces().first().clone();
+
+        let shell = plate.add_blind_hole_pattern(
+            HoleLocation {
+                face: &bottom,
+                position: [1., 0.].into(),
+            },
+            0.25,
+            [0., 0., 0.5],
+            &Pattern::circular([0., 0., 0.], [0., 0., 1.], 4),
+            &mut core,
+        );
+        let shell = shell.insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let bottom = shell.faces().first();
+        assert_eq!(bottom.region().interiors().len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn through_hole_pattern() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let plate = plate(&mut core);
+        let bottom = plate.faces().first().clone();
+        let top = plate.faces().nth(5).unwrap().clone();
+
+        let position = [Scalar::from(-1.), Scalar::from(-1.)];
+        let shell = plate.add_through_hole_pattern(
+            [
+                HoleLocation {
+                    face: &bottom,
+                    position: position.into(),
+                },
+                HoleLocation {
+                    face: &top,
+                    position: position.into(),
+                },
+            ],
+            0.25,
+            &Pattern::grid([[1., 0., 0.], [0., 1., 0.]], [3, 3]),
+            &mut core,
+        );
+        let shell = shell.insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let bottom = shell.faces().first();
+        let top = shell.faces().nth(5).unwrap();
+        assert_eq!(bottom.region().interiors().len(), 9);
+        assert_eq!(top.region().interiors().len(), 9);
+
+        Ok(())
+    }
+
+    /// A 4x4 plate, centered on the z-axis, with a thickness of 1
+    fn plate(core: &mut Core) -> Shell {
+        let surface = core.layers.topology.surfaces.space_2d();
+        let region = Region::polygon(
+            [[-2., -2.], [2., -2.], [2., 2.], [-2., 2.]],
+            surface,
+            core,
+        );
+
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions([region], core)
+            .sweep_sketch(
+                core.layers.topology.surfaces.xy_plane(),
+                [0., 0., 1.],
+                core,
+            );
+
+        solid.shells().only().clone_object()
+    }
+}
@@ -0,0 +1,788 @@
+//! # Hollow out shapes
+//!
+//! See [`Hollow`].
+
+use std::collections::BTreeMap;
+
+use fj_math::{Circle, Line, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::intersect::{
+        surface_surface::SurfaceSurfaceIntersection, Intersect,
+    },
+    geometry::{
+        Geometry, GlobalPath, HalfEdgeGeom, LocalCurveGeom, SurfaceGeom,
+        SurfacePath, SweepPath,
+    },
+    queries::SiblingOfHalfEdge,
+    storage::Handle,
+    topology::{
+        Curve, Cycle, Face, HalfEdge, Region, Shell, Solid, Surface, Vertex,
+    },
+    Core,
+};
+
+use super::{build::BuildSurface, insert::Insert, reverse::Reverse};
+
+/// Hollow out a shape, leaving walls of a given thickness
+pub trait Hollow {
+    /// Hollow out the shape
+    ///
+    /// Every face is offset inward by `thickness`, and the offset faces make up
+    /// the boundary of the cavity. Faces in `open_faces` are not offset.
+    /// Instead, they are removed, together with their offset counterpart,
+    /// leaving an opening into the cavity. Where an open face was, a rim that
+    /// connects the outer and inner walls is left in its place.
+    ///
+    /// If there are no open faces, the cavity is closed, and becomes an
+    /// additional shell of the solid.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `thickness` is not positive, or if the shape is not closed.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only shapes made from planar faces and faces on right cylinders, like
+    /// the ones created by sweeping a sketch, are supported so far. Hollowing
+    /// out other shapes results in a panic.
+    ///
+    /// The topology of the cavity matches that of the original shape. Faces or
+    /// edges that would vanish, because the thickness is too large compared to
+    /// the shape's features, result in an invalid shape.
+    #[must_use]
+    fn hollow(
+        &self,
+        thickness: impl Into<Scalar>,
+        open_faces: &[Handle<Face>],
+        core: &mut Core,
+    ) -> Self;
+}
+
+impl Hollow for Solid {
+    fn hollow(
+        &self,
+        thickness: impl Into<Scalar>,
+        open_faces: &[Handle<Face>],
+        core: &mut Core,
+    ) -> Self {
+        let thickness = thickness.into();
+        assert!(thickness > Scalar::ZERO, "Wall thickness must be positive");
+
+        let mut shells = Vec::new();
+
+        for shell in self.shells() {
+            let inner = InnerShell::new(
+                shell,
+                thickness,
+                open_faces,
+                &core.layers.geometry,
+            )
+            .build(shell, open_faces, core);
+
+            // The inner shell has the same orientation as the outer one, but
+            // needs to face into the cavity.
+            let inner = inner
+                .into_iter()
+                .map(|face| face.reverse(core).insert(core))
+                .collect::<Vec<_>>();
+
+            let is_open =
+                shell.faces().iter().any(|face| open_faces.contains(face));
+            if !is_open {
+                shells.push(shell.clone());
+                shells.push(Shell::new(inner).insert(core));
+                continue;
+            }
+
+            let mut faces = Vec::new();
+
+            for (outer, inner) in shell.faces().iter().zip(inner) {
+                if !open_faces.contains(outer) {
+                    faces.push(outer.clone());
+                    faces.push(inner);
+                    continue;
+                }
+
+                // The open face and its offset counterpart lie within the same
+                // surface. Their cycles bound the rim that connects the outer
+                // and inner walls.
+                let rim = Region::new(
+                    outer.region().exterior().clone(),
+                    [inner.region().exterior().clone()],
+                )
+                .insert(core);
+                faces
+                    .push(Face::new(outer.surface().clone(), rim).insert(core));
+
+                for (outer_interior, inner_interior) in outer
+                    .region()
+                    .interiors()
+                    .iter()
+                    .zip(inner.region().interiors())
+                {
+                    let rim = Region::new(
+                        inner_interior.clone(),
+                        [outer_interior.clone()],
+                    )
+                    .insert(core);
+                    faces.push(
+                        Face::new(outer.surface().clone(), rim).insert(core),
+                    );
+                }
+            }
+
+            shells.push(Shell::new(faces).insert(core));
+        }
+
+        Solid::new(shells)
+    }
+}
+
+/// The geometry of the inner shell, that bounds the cavity
+struct InnerShell {
+    surfaces: Vec<SurfaceGeom>,
+    paths: BTreeMap<Handle<HalfEdge>, SurfacePath>,
+    boundaries: BTreeMap<Handle<HalfEdge>, [Point<1>; 2]>,
+}
+
+impl InnerShell {
+    fn new(
+        shell: &Shell,
+        thickness: Scalar,
+        open_faces: &[Handle<Face>],
+        geometry: &Geometry,
+    ) -> Self {
+        let surfaces = shell
+            .faces()
+            .iter()
+            .map(|face| {
+                if open_faces.contains(face) {
+                    geometry.of_surface(face.surface()).clone()
+                } else {
+                    offset_surface(face, thickness, geometry)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut faces_of_half_edges = BTreeMap::new();
+        for (i, face) in shell.faces().iter().enumerate() {
+            for cycle in face.region().all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    faces_of_half_edges.insert(half_edge.clone(), i);
+                }
+            }
+        }
+
+        // Each edge of the inner shell lies where the offset surfaces of the
+        // faces it borders intersect. Siblings must agree on the parameters of
+        // their curve, so each edge is only computed once, for both of them.
+        let mut paths = BTreeMap::new();
+        for (half_edge, &i) in &faces_of_half_edges {
+            if paths.contains_key(half_edge) {
+                continue;
+            }
+
+            let sibling = shell
+                .get_sibling_of(half_edge, geometry)
+                .expect("Can only hollow out closed shapes");
+            let j = faces_of_half_edges[&sibling];
+
+            // Faces on the same surface, like adjacent arcs of a cylinder, have
+            // coincident offset surfaces. Those don't intersect in a curve.
+            let intersection = if i == j || surfaces[i] == surfaces[j] {
+                None
+            } else {
+                (&surfaces[i], &surfaces[j]).intersect()
+            };
+
+            match intersection {
+                Some(SurfaceSurfaceIntersection::Curves(curves)) => {
+                    let original = geometry.of_half_edge(half_edge);
+                    let [a, b] = original.boundary.inner;
+                    let middle =
+                        original.path.point_from_path_coords(a + (b - a) * 0.5);
+
+                    let [Some(path), Some(sibling_path)] = curves
+                        .into_iter()
+                        .map(|curve| curve.local)
+                        .min_by_key(|[path, _]| {
+                            path.as_ref()
+                                .map(|path| distance_to_path(path, middle))
+                        })
+                        .expect("Surfaces of an edge must intersect")
+                    else {
+                        todo!(
+                            "Hollowing out shapes is only supported, if the \
+                            edges can be represented on both of their faces"
+                        );
+                    };
+
+                    paths.insert(half_edge.clone(), path);
+                    paths.insert(sibling, sibling_path);
+                }
+                _ => {
+                    // Either the edge borders a single face (or faces on the
+                    // same surface), like the seam of a cylinder, or the faces
+                    // are tangent. Offsetting preserves surface coordinates,
+                    // so the paths still apply.
+                    for half_edge in [half_edge, &sibling] {
+                        paths.insert(
+                            half_edge.clone(),
+                            geometry.of_half_edge(half_edge).path.clone(),
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut boundaries = BTreeMap::new();
+        for face in shell.faces() {
+            for cycle in face.region().all_cycles() {
+                let half_edges = cycle.half_edges();
+
+                for (i, half_edge) in half_edges.iter().enumerate() {
+                    if boundaries.contains_key(half_edge) {
+                        continue;
+                    }
+
+                    let original = geometry.of_half_edge(half_edge);
+                    let path = &paths[half_edge];
+
+                    let boundary = if half_edges.len() == 1 {
+                        original.boundary.inner
+                    } else {
+                        let previous =
+                            half_edges.nth_circular(i + half_edges.len() - 1);
+                        let next = half_edges.nth_circular(i + 1);
+
+                        let start = intersect_paths(
+                            &paths[previous],
+                            path,
+                            original.start_position(),
+                        );
+                        let end = intersect_paths(
+                            path,
+                            &paths[next],
+                            geometry.of_half_edge(next).start_position(),
+                        );
+
+                        path_coords_of_points(
+                            path,
+                            [start, end],
+                            original.boundary.inner,
+                        )
+                    };
+
+                    let sibling = shell
+                        .get_sibling_of(half_edge, geometry)
+                        .expect("Can only hollow out closed shapes");
+                    let [a, b] = boundary;
+
+                    boundaries.insert(half_edge.clone(), boundary);
+                    boundaries.insert(sibling, [b, a]);
+                }
+            }
+        }
+
+        Self {
+            surfaces,
+            paths,
+            boundaries,
+        }
+    }
+
+    /// Build the faces of the inner shell
+    ///
+    /// Returns one face for each face of the outer shell, in the same order.
+    fn build(
+        self,
+        shell: &Shell,
+        open_faces: &[Handle<Face>],
+        core: &mut Core,
+    ) -> Vec<Handle<Face>> {
+        let mut curves: BTreeMap<Handle<Curve>, Handle<Curve>> =
+            BTreeMap::new();
+        let mut vertices: BTreeMap<Handle<Vertex>, Handle<Vertex>> =
+            BTreeMap::new();
+
+        let mut faces = Vec::new();
+
+        for (face, surface_geom) in shell.faces().iter().zip(self.surfaces) {
+            let surface = if open_faces.contains(face) {
+                face.surface().clone()
+            } else {
+                let SurfaceGeom::Swept { u, v } = surface_geom else {
+                    unreachable!("Offset surfaces are always swept surfaces");
+                };
+                Surface::from_uv(u, v, core)
+            };
+
+            let mut build_cycle = |cycle: &Cycle, core: &mut Core| {
+                let half_edges = cycle
+                    .half_edges()
+                    .iter()
+                    .map(|half_edge| {
+                        let curve = curves
+                            .entry(half_edge.curve().clone())
+                            .or_insert_with(|| Curve::new().insert(core))
+                            .clone();
+                        let start_vertex = vertices
+                            .entry(half_edge.start_vertex().clone())
+                            .or_insert_with(|| Vertex::new().insert(core))
+                            .clone();
+
+                        let path = self.paths[half_edge].clone();
+                        let boundary = self.boundaries[half_edge];
+
+                        core.layers.geometry.define_curve(
+                            curve.clone(),
+                            surface.clone(),
+                            LocalCurveGeom { path: path.clone() },
+                        );
+
+                        let half_edge =
+                            HalfEdge::new(curve, start_vertex).insert(core);
+                        core.layers.geometry.define_half_edge(
+                            half_edge.clone(),
+                            HalfEdgeGeom {
+                                path,
+                                boundary: boundary.into(),
+                            },
+                        );
+
+                        half_edge
+                    })
+                    .collect::<Vec<_>>();
+
+                Cycle::new(half_edges).insert(core)
+            };
+
+            let exterior = build_cycle(face.region().exterior(), core);
+            let interiors = face
+                .region()
+                .interiors()
+                .iter()
+                .map(|cycle| build_cycle(cycle, core))
+                .collect::<Vec<_>>();
+
+            let region = Region::new(exterior, interiors).insert(core);
+            faces.push(Face::new(surface, region).insert(core));
+        }
+
+        faces
+    }
+}
+
+/// Offset the surface of a face, against the direction of its normal
+fn offset_surface(
+    face: &Face,
+    thickness: Scalar,
+    geometry: &Geometry,
+) -> SurfaceGeom {
+    let normal = face
+        .normal_at([0., 0.], geometry)
+        .expect("Planes and cylinders are not degenerate")
+        .normalize();
+
+    match geometry.of_surface(face.surface()) {
+        SurfaceGeom::Swept {
+            u: GlobalPath::Line(line),
+            v: SweepPath::Line(v),
+        } => SurfaceGeom::Swept {
+            u: GlobalPath::Line(Line::from_origin_and_direction(
+                line.origin() - normal * thickness,
+                line.direction(),
+            )),
+            v: SweepPath::Line(*v),
+        },
+        SurfaceGeom::Swept {
+            u: GlobalPath::Circle(circle),
+            v: SweepPath::Line(v),
+        } => {
+            // At the origin of the surface coordinates, the normal points
+            // along `a`, either away from the axis or towards it.
+            let radius = circle.radius();
+            let offset_radius = if normal.dot(&circle.a()) > Scalar::ZERO {
+                radius - thickness
+            } else {
+                radius + thickness
+            };
+            assert!(
+                offset_radius > Scalar::ZERO,
+                "Wall thickness is too large for cylindrical face"
+            );
+
+            let scale = offset_radius / radius;
+
+            SurfaceGeom::Swept {
+                u: GlobalPath::Circle(Circle::new(
+                    circle.center(),
+                    circle.a() * scale,
+                    circle.b() * scale,
+                )),
+                v: SweepPath::Line(*v),
+            }
+        }
+        _ => {
+            todo!(
+                "Hollowing out shapes is only supported for planar and \
+                cylindrical faces"
+            )
+        }
+    }
+}
+
+/// Intersect two paths, returning the intersection nearest to `near`
+fn intersect_paths(
+    a: &SurfacePath,
+    b: &SurfacePath,
+    near: Point<2>,
+) -> Point<2> {
+    let candidates = match (a, b) {
+        (SurfacePath::Line(a), SurfacePath::Line(b)) => {
+            let denominator = a.direction().cross2d(&b.direction());
+
+            if denominator == Scalar::ZERO {
+                // The lines are parallel, which means they must be coincident,
+                // and any point on them will do.
+                vec![b.point_from_line_coords(b.point_to_line_coords(near))]
+            } else {
+                let t = (b.origin() - a.origin()).cross2d(&b.direction())
+                    / denominator;
+                vec![a.point_from_line_coords([t])]
+            }
+        }
+        (SurfacePath::Line(line), SurfacePath::Circle(circle))
+        | (SurfacePath::Circle(circle), SurfacePath::Line(line)) => {
+            intersect_line_and_circle(line, circle)
+        }
+        (SurfacePath::Circle(a), SurfacePath::Circle(b)) => {
+            if a.center() == b.center() {
+                // The circles are concentric, which means they must be
+                // coincident, like adjacent arcs of the same circle are. Any
+                // point on them will do.
+                let direction = near - a.center();
+                vec![
+                    a.center()
+                        + direction * (a.radius() / direction.magnitude()),
+                ]
+            } else {
+                intersect_circles(a, b)
+            }
+        }
+        _ => {
+            todo!("Hollowing out shapes with splines is not supported")
+        }
+    };
+
+    candidates
+        .into_iter()
+        .min_by_key(|point| (*point - near).magnitude())
+        .expect("Adjacent edges of a face must intersect")
+}
+
+fn intersect_line_and_circle(
+    line: &Line<2>,
+    circle: &Circle<2>,
+) -> Vec<Point<2>> {
+    // Points on the line are `origin + direction * t`. Those on the circle
+    // have a distance of `radius` from its center, which results in a
+    // quadratic equation for `t`.
+    let d = line.direction();
+    let f = line.origin() - circle.center();
+
+    let a = d.dot(&d);
+    let b = f.dot(&d) * 2.;
+    let c = f.dot(&f) - circle.radius() * circle.radius();
+
+    // The line might only touch the circle, in which case the discriminant
+    // could end up slightly negative, due to floating point inaccuracies.
+    let discriminant = (b * b - a * c * 4.).max(Scalar::ZERO);
+    let root = Scalar::from(discriminant.into_f64().sqrt());
+
+    [-b - root, -b + root]
+        .map(|t| line.point_from_line_coords([t / (a * 2.)]))
+        .to_vec()
+}
+
+fn intersect_circles(a: &Circle<2>, b: &Circle<2>) -> Vec<Point<2>> {
+    let center_to_center = b.center() - a.center();
+    let distance = center_to_center.magnitude();
+
+    let [r_a, r_b] = [a.radius(), b.radius()];
+
+    // Distance from the center of `a` to the line through both intersections,
+    // and half the distance between those intersections.
+    let along = (distance * distance + r_a * r_a - r_b * r_b) / (distance * 2.);
+    let across = Scalar::from(
+        (r_a * r_a - along * along)
+            .max(Scalar::ZERO)
+            .into_f64()
+            .sqrt(),
+    );
+
+    let direction = center_to_center / distance;
+    let normal = Vector::from([-direction.v, direction.u]);
+    let base = a.center() + direction * along;
+
+    vec![base + normal * across, base - normal * across]
+}
+
+/// Compute the path coordinates of the start and end point of an edge
+///
+/// The original boundary of the edge is used to resolve ambiguities, where
+/// multiple path coordinates refer to the same point.
+fn path_coords_of_points(
+    path: &SurfacePath,
+    points: [Point<2>; 2],
+    original: [Point<1>; 2],
+) -> [Point<1>; 2] {
+    match path {
+        SurfacePath::Line(line) => {
+            points.map(|point| line.point_to_line_coords(point))
+        }
+        SurfacePath::Circle(circle) => {
+            let [mut start, mut end] = points.map(|point| {
+                let v = point - circle.center();
+                Scalar::atan2(v.dot(&circle.b()), v.dot(&circle.a()))
+            });
+
+            // Angles wrap around. Offsetting preserves the parameterization of
+            // the edge's surfaces, so the start must stay as close as possible
+            // to the original one. Otherwise, the edge would end up in a
+            // different place on a neighboring cylinder.
+            let [original_start, original_end] = original.map(|point| point.t);
+            start +=
+                Scalar::TAU * ((original_start - start) / Scalar::TAU).round();
+
+            // And the end must be on the correct side of the start, relative
+            // to the direction of the edge.
+            if original_end > original_start {
+                while end <= start {
+                    end += Scalar::TAU;
+                }
+            } else {
+                while end >= start {
+                    end -= Scalar::TAU;
+                }
+            }
+
+            [start, end].map(|t| Point::from([t]))
+        }
+        SurfacePath::Spline(_) => {
+            todo!("Hollowing out shapes with splines is not supported")
+        }
+    }
+}
+
+fn distance_to_path(path: &SurfacePath, point: Point<2>) -> Scalar {
+    match path {
+        SurfacePath::Line(line) => {
+            let closest =
+                line.point_from_line_coords(line.point_to_line_coords(point));
+            (point - closest).magnitude()
+        }
+        SurfacePath::Circle(circle) => {
+            ((point - circle.center()).magnitude() - circle.radius()).abs()
+        }
+        SurfacePath::Spline(_) => {
+            todo!("Hollowing out shapes with splines is not supported")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{PI, TAU};
+
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        geometry::{HalfEdgeGeom, LocalCurveGeom, SurfacePath},
+        operations::{
+            build::{BuildHalfEdge, BuildSketch},
+            fixtures::{assert_close, cuboid, cylinder, volume},
+            insert::Insert,
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        storage::Handle,
+        topology::{Cycle, HalfEdge, Region, Sketch, Surface},
+        Core,
+    };
+
+    use super::Hollow;
+
+    #[test]
+    fn hollow_closed() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let hollow = cube.hollow(0.25, &[], &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(hollow.shells().len(), 2);
+
+        assert_close(volume(&hollow, &core), 8. - 1.5 * 1.5 * 1.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hollow_with_open_face() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cube = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let top = cube.shells().only().faces().nth(5).unwrap().clone();
+
+        let hollow = cube.hollow(0.25, &[top], &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = hollow.shells().only();
+        assert_eq!(shell.faces().len(), 11);
+
+        assert_close(volume(&hollow, &core), 8. - 1.5 * 1.5 * 1.75);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hollow_cylinder() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cylinder = cylinder([0., 0., 0.], 1., 2., &mut core);
+        let top = cylinder.shells().only().faces().nth(2).unwrap().clone();
+
+        let hollow = cylinder.hollow(0.25, &[top], &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let shell = hollow.shells().only();
+        assert_eq!(shell.faces().len(), 5);
+
+        let expected = Scalar::PI * 2. - Scalar::PI * 0.75 * 0.75 * 1.75;
+        assert_close(volume(&hollow, &core), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hollow_annular_sector() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // A quarter of an annulus, with an outer radius of `2` and an inner
+        // radius of `1`.
+        let surface = core.layers.topology.surfaces.space_2d();
+        let half_edges = [
+            HalfEdge::arc(
+                [2., 0.],
+                [0., 2.],
+                Scalar::PI / 2.,
+                surface.clone(),
+                &mut core,
+            ),
+            HalfEdge::line_segment(
+                [[0., 2.], [0., 1.]],
+                None,
+                surface.clone(),
+                &mut core,
+            )
+            .insert(&mut core),
+            HalfEdge::arc(
+                [0., 1.],
+                [1., 0.],
+                -Scalar::PI / 2.,
+                surface.clone(),
+                &mut core,
+            ),
+            HalfEdge::line_segment(
+                [[1., 0.], [2., 0.]],
+                None,
+                surface,
+                &mut core,
+            )
+            .insert(&mut core),
+        ];
+        let region = Region::new(Cycle::new(half_edges).insert(&mut core), []);
+        let sector = Sketch::empty(&core.layers.topology)
+            .add_regions([region], &mut core)
+            .sweep_sketch(
+                core.layers.topology.surfaces.xy_plane(),
+                [0., 0., 1.],
+                &mut core,
+            );
+
+        let hollow = sector.hollow(0.1, &[], &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(hollow.shells().len(), 2);
+
+        // The cavity is bounded by arcs with radii of `1.1` and `1.9`, and by
+        // lines that are offset from the axes by `0.1`.
+        let quadrant = |r: f64| {
+            let a = 0.1;
+            let s = (r * r - a * a).sqrt();
+            let f = |x: f64| {
+                (x * (r * r - x * x).sqrt() + r * r * (x / r).asin()) / 2.
+            };
+            f(s) - f(a) - a * (s - a)
+        };
+        let cavity = (quadrant(1.9) - quadrant(1.1)) * 0.8;
+        let expected = Scalar::PI * 3. / 4. - cavity;
+
+        assert_close(volume(&hollow, &core), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn hollow_with_adjacent_arcs() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // A circle, made from two half-circle arcs.
+        let surface = core.layers.topology.surfaces.space_2d();
+        let half_edges = [[0., PI], [PI, TAU]]
+            .map(|boundary| arc(boundary, surface.clone(), &mut core));
+        let region = Region::new(Cycle::new(half_edges).insert(&mut core), []);
+        let cylinder = Sketch::empty(&core.layers.topology)
+            .add_regions([region], &mut core)
+            .sweep_sketch(
+                core.layers.topology.surfaces.xy_plane(),
+                [0., 0., 2.],
+                &mut core,
+            );
+
+        let hollow = cylinder.hollow(0.25, &[], &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let expected = Scalar::PI * 2. - Scalar::PI * 0.75 * 0.75 * 1.5;
+        assert_close(volume(&hollow, &core), expected);
+
+        Ok(())
+    }
+
+    /// An arc of the unit circle around the origin
+    fn arc(
+        boundary: [f64; 2],
+        surface: Handle<Surface>,
+        core: &mut Core,
+    ) -> Handle<HalfEdge> {
+        let path = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+        let half_edge = HalfEdge::unjoined(core).insert(core);
+
+        core.layers.geometry.define_curve(
+            half_edge.curve().clone(),
+            surface,
+            LocalCurveGeom { path: path.clone() },
+        );
+        core.layers.geometry.define_half_edge(
+            half_edge.clone(),
+            HalfEdgeGeom {
+                path,
+                boundary: boundary.map(|t| Point::from([t])).into(),
+            },
+        );
+
+        half_edge
+    }
+}
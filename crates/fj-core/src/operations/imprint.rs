@@ -0,0 +1,1582 @@
+//! Infrastructure for imprinting intersections onto shells
+//!
+//! This is shared by the [boolean operations], and by [`SplitSolid`]. Both
+//! intersect the faces of a shell with other faces, then split the edges and
+//! faces of the shell along those intersections, using [`SplitEdge`] and
+//! [`SplitFace`]. Afterwards, each face of the shell is located fully on one
+//! side of the other faces, and can be kept or discarded as a whole. The faces
+//! that are kept are then assembled into new shells.
+//!
+//! Only planar and cylindrical faces are supported, as those are the surfaces
+//! that can be intersected with each other.
+//!
+//! [boolean operations]: super::boolean
+//! [`SplitSolid`]: super::split::SplitSolid
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_math::{Circle, Line, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{
+        intersect::{
+            path_path::PathPathIntersection,
+            surface_surface::SurfaceSurfaceIntersection, Intersect,
+        },
+        triangulate::polygon::Polygon,
+    },
+    geometry::{
+        CurveBoundary, Geometry, GlobalPath, HalfEdgeGeom, LocalCurveGeom,
+        SurfaceGeom, SurfacePath, SweepPath,
+    },
+    operations::{
+        derive::DeriveFrom,
+        geometry::UpdateHalfEdgeGeometry,
+        insert::Insert,
+        presentation::GetColor,
+        split::{
+            faces_from_half_edges, sample_half_edge, SplitEdge, SplitFace,
+        },
+    },
+    queries::SiblingOfHalfEdge,
+    storage::{Handle, ObjectId},
+    topology::{Curve, Cycle, Face, HalfEdge, Region, Shell, Surface, Vertex},
+    Core,
+};
+
+/// A face, prepared for locating points and paths relative to its boundary
+pub(super) struct FaceBoundary {
+    surface: SurfaceGeom,
+    half_edges: Vec<BoundaryHalfEdge>,
+
+    /// The cycles of the face, or `None`, if it covers the whole surface
+    polygon: Option<Polygon>,
+
+    /// The range of u-coordinates that the face covers
+    ///
+    /// This is only available on surfaces that are periodic along their
+    /// u-axis, like cylinders. A point on those surfaces has many possible
+    /// u-coordinates, and only one of them might be within the face.
+    u_range: Option<[Scalar; 2]>,
+}
+
+impl FaceBoundary {
+    /// Prepare a face
+    pub(super) fn new(face: &Handle<Face>, core: &Core) -> Self {
+        let geometry = &core.layers.geometry;
+        let surface = geometry.of_surface(face.surface()).clone();
+
+        let mut half_edges = Vec::new();
+        let mut cycles = Vec::new();
+
+        for cycle in face.region().all_cycles() {
+            let mut points = Vec::new();
+
+            for (half_edge, next) in cycle.half_edges().pairs() {
+                points.extend(sample_half_edge(half_edge, geometry));
+                half_edges.push(BoundaryHalfEdge {
+                    half_edge: half_edge.clone(),
+                    end_vertex: next.start_vertex().clone(),
+                    geometry: geometry.of_half_edge(half_edge).clone(),
+                });
+            }
+
+            points.extend(points.first().copied());
+            cycles.push(points);
+        }
+
+        let u_range = is_periodic(&surface).then(|| {
+            let u = cycles.iter().flatten().map(|point| point.u);
+            [
+                u.clone().min().unwrap_or_default(),
+                u.max().unwrap_or_default(),
+            ]
+        });
+
+        let polygon = match cycles.split_first() {
+            Some((exterior, interiors)) => Polygon::new()
+                .with_exterior(exterior.clone())
+                .with_interiors(interiors.iter().cloned()),
+            None => Polygon::new(),
+        };
+
+        Self {
+            surface,
+            half_edges,
+            polygon: Some(polygon),
+            u_range,
+        }
+    }
+
+    /// Prepare a whole surface, that is not bounded by any edges
+    pub(super) fn unbounded(surface: SurfaceGeom) -> Self {
+        Self {
+            surface,
+            half_edges: Vec::new(),
+            polygon: None,
+            u_range: None,
+        }
+    }
+
+    /// Access the geometry of the face's surface
+    pub(super) fn surface(&self) -> &SurfaceGeom {
+        &self.surface
+    }
+
+    /// Locate a point, given in surface coordinates, relative to the face
+    ///
+    /// Points within `tolerance` of the face's boundary, as measured in model
+    /// coordinates, are considered to be on it.
+    pub(super) fn locate(
+        &self,
+        point: Point<2>,
+        tolerance: Scalar,
+    ) -> Location {
+        let Some(polygon) = &self.polygon else {
+            return Location::Inside;
+        };
+
+        let points = self.representatives(point);
+
+        for &point in &points {
+            for half_edge in &self.half_edges {
+                if let Some(location) =
+                    half_edge.locate(point, &self.surface, tolerance)
+                {
+                    return location;
+                }
+            }
+        }
+
+        if points
+            .into_iter()
+            .any(|point| polygon.contains_point(point))
+        {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    /// Intersect this face with another
+    pub(super) fn intersect(
+        &self,
+        other: &Self,
+        tolerance: Scalar,
+    ) -> FaceIntersection {
+        let curves = match (&self.surface, &other.surface).intersect() {
+            None => return FaceIntersection::default(),
+            Some(SurfaceSurfaceIntersection::Coincident) => {
+                // Where faces lie on the same surface, the boundary of each
+                // face divides the other into parts that overlap, and parts
+                // that don't.
+                return FaceIntersection {
+                    cuts: [
+                        self.cuts_along_boundary_of(other, tolerance),
+                        other.cuts_along_boundary_of(self, tolerance),
+                    ],
+                    coincident: true,
+                };
+            }
+            Some(SurfaceSurfaceIntersection::Curves(curves)) => curves,
+        };
+
+        let mut cuts = [Vec::new(), Vec::new()];
+
+        for curve in curves {
+            let [Some(path_a), Some(path_b)] = curve.local else {
+                todo!(
+                    "Intersecting faces is only supported, if their \
+                    intersection can be represented in the coordinates of \
+                    both surfaces."
+                )
+            };
+
+            // Where the surfaces touch without crossing, like a cylinder
+            // resting on a plane, neither face is divided into parts inside
+            // and outside of the other.
+            if are_tangent(
+                [(&self.surface, &path_a), (&other.surface, &path_b)],
+                tolerance,
+            ) {
+                continue;
+            }
+
+            let is_closed = matches!(
+                curve.global,
+                GlobalPath::Circle(_) | GlobalPath::Ellipse(_)
+            );
+            let coords = self
+                .crossings(&path_a, tolerance)
+                .into_iter()
+                .chain(other.crossings(&path_b, tolerance))
+                .collect();
+
+            for [start, end] in intervals(
+                coords,
+                is_closed,
+                |t| curve.global.point_from_path_coords([t]),
+                tolerance,
+            ) {
+                let middle = Point::from([(start + end) / 2.]);
+                let [location_a, location_b] =
+                    [(self, &path_a), (other, &path_b)].map(|(face, path)| {
+                        face.locate(
+                            path.point_from_path_coords(middle),
+                            tolerance,
+                        )
+                    });
+
+                // Parts of the intersection that are located on the boundary
+                // of a face are already represented by its edges. They only
+                // need to be imprinted onto the other face.
+                if matches!(location_a, Location::Outside)
+                    || matches!(location_b, Location::Outside)
+                {
+                    continue;
+                }
+                let [cuts_a, cuts_b] = &mut cuts;
+                for (cuts, face, path, location) in [
+                    (cuts_a, self, &path_a, location_a),
+                    (cuts_b, other, &path_b, location_b),
+                ] {
+                    // There's no need to cut a whole surface.
+                    if face.polygon.is_some()
+                        && matches!(location, Location::Inside)
+                    {
+                        cuts.push(face.cut(path, [start, end], tolerance));
+                    }
+                }
+            }
+        }
+
+        FaceIntersection {
+            cuts,
+            coincident: false,
+        }
+    }
+
+    /// Compute the parts of the other face's boundary that lie within this one
+    ///
+    /// Both faces must be located on the same plane.
+    fn cuts_along_boundary_of(
+        &self,
+        other: &Self,
+        tolerance: Scalar,
+    ) -> Vec<Cut> {
+        if !is_plane(&self.surface) || !is_plane(&other.surface) {
+            todo!("Intersecting coincident curved faces is not supported.")
+        }
+
+        let mut cuts = Vec::new();
+
+        for half_edge in &other.half_edges {
+            let global = other
+                .surface
+                .path_from_surface_coords(half_edge.geometry.path.clone());
+            let path = project_onto_plane(&global, &self.surface);
+
+            let [min, max] = {
+                let [a, b] = half_edge.geometry.boundary.inner.map(|t| t.t);
+                if a < b {
+                    [a, b]
+                } else {
+                    [b, a]
+                }
+            };
+            let coords = self
+                .crossings(&path, tolerance)
+                .into_iter()
+                .map(|t| match path {
+                    SurfacePath::Circle(_) => {
+                        let middle = (min + max) / 2.;
+                        t + Scalar::TAU * ((middle - t) / Scalar::TAU).round()
+                    }
+                    SurfacePath::Line(_) | SurfacePath::Spline(_) => t,
+                })
+                .filter(|&t| t > min && t < max)
+                .chain([min, max])
+                .collect();
+
+            for [start, end] in intervals(
+                coords,
+                false,
+                |t| global.point_from_path_coords([t]),
+                tolerance,
+            ) {
+                let middle = path.point_from_path_coords([(start + end) / 2.]);
+                if let Location::Inside = self.locate(middle, tolerance) {
+                    cuts.push(self.cut(&path, [start, end], tolerance));
+                }
+            }
+        }
+
+        cuts
+    }
+
+    /// Compute the path coordinates where a path crosses the face's boundary
+    fn crossings(&self, path: &SurfacePath, tolerance: Scalar) -> Vec<Scalar> {
+        let mut crossings = Vec::new();
+
+        for offset in self.offsets() {
+            let path = translate(path, offset);
+
+            for half_edge in &self.half_edges {
+                match (&path, &half_edge.geometry.path).intersect() {
+                    Some(PathPathIntersection::Points(points)) => {
+                        for point in points {
+                            let [t, _] = point.point_on_paths;
+
+                            if half_edge
+                                .locate(point.point, &self.surface, tolerance)
+                                .is_some()
+                            {
+                                crossings.push(t.t);
+                            }
+                        }
+                    }
+                    Some(PathPathIntersection::Coincident) => {
+                        for t in half_edge.geometry.boundary.inner {
+                            let point = half_edge
+                                .geometry
+                                .path
+                                .point_from_path_coords(t);
+                            crossings.push(path.project_point(point).t);
+                        }
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        crossings
+    }
+
+    /// Create a cut along the provided path, within the provided boundary
+    fn cut(
+        &self,
+        path: &SurfacePath,
+        [start, end]: [Scalar; 2],
+        tolerance: Scalar,
+    ) -> Cut {
+        let path = match self.u_range {
+            Some([min, max]) => {
+                let u = path.point_from_path_coords([(start + end) / 2.]).u;
+                let turns = ((min + max) / 2. - u) / Scalar::TAU;
+                translate(
+                    path,
+                    Vector::from([turns.round() * Scalar::TAU, Scalar::ZERO]),
+                )
+            }
+            None => path.clone(),
+        };
+        let boundary = [start, end].map(|t| Point::from([t]));
+
+        let ends = boundary.map(|t| {
+            match self.locate(path.point_from_path_coords(t), tolerance) {
+                Location::Outside => Location::Inside,
+                location => location,
+            }
+        });
+        let points = boundary.map(|t| {
+            self.surface
+                .point_from_surface_coords(path.point_from_path_coords(t))
+        });
+
+        Cut {
+            path,
+            boundary,
+            ends,
+            points,
+        }
+    }
+
+    /// The offsets that a point can be moved by, without leaving the surface
+    fn offsets(&self) -> Vec<Vector<2>> {
+        if self.u_range.is_none() {
+            return vec![Vector::from([0., 0.])];
+        }
+
+        (-2..=2)
+            .map(|turns| {
+                Vector::from([
+                    Scalar::TAU * Scalar::from(f64::from(turns)),
+                    Scalar::ZERO,
+                ])
+            })
+            .collect()
+    }
+
+    /// All surface coordinates of a point, that might be within the face
+    fn representatives(&self, point: Point<2>) -> Vec<Point<2>> {
+        let margin = Scalar::from(1e-9);
+
+        self.offsets()
+            .into_iter()
+            .map(|offset| point + offset)
+            .filter(|point| match self.u_range {
+                Some([min, max]) => {
+                    point.u >= min - margin && point.u <= max + margin
+                }
+                None => true,
+            })
+            .collect()
+    }
+}
+
+/// The location of a point, relative to a face
+#[derive(Clone)]
+pub(super) enum Location {
+    /// The point is within the face
+    Inside,
+
+    /// The point is outside of the face
+    Outside,
+
+    /// The point is located at a vertex of the face's boundary
+    Vertex(Handle<Vertex>),
+
+    /// The point is located on a half-edge of the face's boundary
+    Edge(Handle<HalfEdge>, Point<1>),
+}
+
+/// The intersection of two faces
+///
+/// See [`FaceBoundary::intersect`].
+#[derive(Default)]
+pub(super) struct FaceIntersection {
+    /// The cuts that need to be imprinted onto each face
+    pub(super) cuts: [Vec<Cut>; 2],
+
+    /// Indicates whether the faces are located on the same surface
+    pub(super) coincident: bool,
+}
+
+/// A part of an intersection, that needs to be imprinted onto a face
+pub(super) struct Cut {
+    path: SurfacePath,
+    boundary: [Point<1>; 2],
+    ends: [Location; 2],
+    points: [Point<3>; 2],
+}
+
+/// A half-edge of a face boundary
+struct BoundaryHalfEdge {
+    half_edge: Handle<HalfEdge>,
+    end_vertex: Handle<Vertex>,
+    geometry: HalfEdgeGeom,
+}
+
+impl BoundaryHalfEdge {
+    /// Locate a point on the half-edge, if it's located there
+    fn locate(
+        &self,
+        point: Point<2>,
+        surface: &SurfaceGeom,
+        tolerance: Scalar,
+    ) -> Option<Location> {
+        let target = surface.point_from_surface_coords(point);
+        let is_near = |t: Point<1>| {
+            let point = self.geometry.path.point_from_path_coords(t);
+            (surface.point_from_surface_coords(point) - target).magnitude()
+                <= tolerance
+        };
+
+        let [start, end] = self.geometry.boundary.inner;
+        if is_near(start) {
+            return Some(Location::Vertex(
+                self.half_edge.start_vertex().clone(),
+            ));
+        }
+        if is_near(end) {
+            return Some(Location::Vertex(self.end_vertex.clone()));
+        }
+
+        let [min, max] = if start < end {
+            [start, end]
+        } else {
+            [end, start]
+        };
+        let t = self.geometry.path.project_point(point);
+        let t = match self.geometry.path {
+            SurfacePath::Circle(_) => {
+                let middle = (min.t + max.t) / 2.;
+                t.t + Scalar::TAU * ((middle - t.t) / Scalar::TAU).round()
+            }
+            SurfacePath::Line(_) | SurfacePath::Spline(_) => t.t,
+        };
+        let t = Point::from([t]);
+
+        (t > min && t < max && is_near(t))
+            .then(|| Location::Edge(self.half_edge.clone(), t))
+    }
+}
+
+/// Imprint cuts onto the faces of a shell
+///
+/// Splits the edges of the shell where cuts end on them, then splits each face
+/// along its cuts. Returns each face of the updated shell, together with the
+/// face of the original shell that it originates from.
+///
+/// `tolerance` is the distance, below which points are considered to be
+/// identical.
+pub(super) fn imprint(
+    shell: &Shell,
+    cuts: BTreeMap<Handle<Face>, Vec<Cut>>,
+    tolerance: Scalar,
+    core: &mut Core,
+) -> Vec<(Handle<Face>, Handle<Face>)> {
+    let mut vertices = Vertices::new(tolerance);
+    let mut origins = BTreeMap::new();
+
+    for face in shell.faces() {
+        let surface = core.layers.geometry.of_surface(face.surface());
+
+        for cycle in face.region().all_cycles() {
+            for half_edge in cycle.half_edges() {
+                let position = surface.point_from_surface_coords(
+                    core.layers
+                        .geometry
+                        .of_half_edge(half_edge)
+                        .start_position(),
+                );
+
+                origins.insert(half_edge.id(), face.clone());
+                vertices.insert(position, half_edge.start_vertex().clone());
+            }
+        }
+    }
+
+    // Cuts can end on the edges of a face. Those edges need to be split there
+    // first. A half-edge shares its path coordinates with its sibling, so it
+    // doesn't matter which of them a cut refers to.
+    let mut splits = BTreeMap::new();
+    for cut in cuts.values().flatten() {
+        for (end, point) in cut.ends.iter().zip(cut.points) {
+            let Location::Edge(half_edge, t) = end else {
+                continue;
+            };
+            let sibling = shell
+                .get_sibling_of(half_edge, &core.layers.geometry)
+                .expect("Expected half-edge of shell to have a sibling");
+
+            let (_, points) = splits
+                .entry(half_edge.id().min(sibling.id()))
+                .or_insert_with(|| (half_edge.clone(), Vec::new()));
+            if points.iter().all(|&(_, other): &(Point<1>, Point<3>)| {
+                (other - point).magnitude() > tolerance
+            }) {
+                points.push((*t, point));
+            }
+        }
+    }
+
+    let mut shell = shell.clone();
+
+    for (half_edge, mut points) in splits.into_values() {
+        let [start, end] =
+            core.layers.geometry.of_half_edge(&half_edge).boundary.inner;
+        points.sort_by_key(|(t, _)| (t.t - start.t) / (end.t - start.t));
+
+        // Splitting at the point closest to the end first, means all other
+        // points are located on the first of the resulting half-edges.
+        let mut half_edge = half_edge;
+        for (t, position) in points.into_iter().rev() {
+            let sibling = shell
+                .get_sibling_of(&half_edge, &core.layers.geometry)
+                .expect("Expected half-edge of shell to have a sibling");
+            let faces = [&half_edge, &sibling].map(|half_edge| {
+                origins
+                    .get(&half_edge.id())
+                    .cloned()
+                    .expect("Expected to know origin of every half-edge")
+            });
+
+            let (split, half_edges) = shell.split_edge(&half_edge, t, core);
+            shell = split;
+
+            for (half_edges, face) in half_edges.iter().zip(faces) {
+                for half_edge in half_edges {
+                    origins.insert(half_edge.id(), face.clone());
+                }
+            }
+
+            let [[a, b], _] = half_edges;
+            vertices.insert(position, b.start_vertex().clone());
+            half_edge = a;
+        }
+    }
+
+    let mut cuts = cuts;
+    let mut faces = Vec::new();
+
+    for face in shell.faces().iter().cloned().collect::<Vec<_>>() {
+        let original = origins
+            .get(&face.region().exterior().half_edges().first().id())
+            .cloned()
+            .expect("Expected to know origin of every half-edge");
+
+        let Some(cuts) = cuts.remove(&original) else {
+            faces.push((face, original));
+            continue;
+        };
+
+        let surface = core.layers.geometry.of_surface(face.surface()).clone();
+        let mut half_edges = Vec::new();
+        let mut imprinted: Vec<(BTreeSet<ObjectId>, Point<3>)> = Vec::new();
+
+        for cut in cuts {
+            let [start, end] = [0, 1].map(|i| match &cut.ends[i] {
+                Location::Vertex(vertex) => vertex.clone(),
+                _ => vertices.find_or_insert(cut.points[i], core),
+            });
+
+            // Neighboring faces can result in the same cut, if they touch this
+            // one along an edge. The cut only needs to be imprinted once.
+            let key = BTreeSet::from([start.id(), end.id()]);
+            let middle = surface.point_from_surface_coords(
+                cut.path.point_from_path_coords([(cut.boundary[0].t
+                    + cut.boundary[1].t)
+                    / 2.]),
+            );
+            if imprinted.iter().any(|(other_key, other_middle)| {
+                other_key == &key
+                    && (other_middle - middle).magnitude() <= tolerance
+            }) {
+                continue;
+            }
+            imprinted.push((key, middle));
+
+            let curve = Curve::new().insert(core);
+            core.layers.geometry.define_curve(
+                curve.clone(),
+                face.surface().clone(),
+                LocalCurveGeom {
+                    path: cut.path.clone(),
+                },
+            );
+
+            let boundary = CurveBoundary::from(cut.boundary);
+            let [a, b] = [(start, boundary), (end, boundary.reverse())].map(
+                |(vertex, boundary)| {
+                    let half_edge =
+                        HalfEdge::new(curve.clone(), vertex).insert(core);
+                    core.layers.geometry.define_half_edge(
+                        half_edge.clone(),
+                        HalfEdgeGeom {
+                            path: cut.path.clone(),
+                            boundary,
+                        },
+                    );
+                    half_edge
+                },
+            );
+            half_edges.push([a, b]);
+        }
+
+        let (split, pieces) = shell.split_face_along(&face, half_edges, core);
+        shell = split;
+
+        faces.extend(pieces.into_iter().map(|piece| (piece, original.clone())));
+    }
+
+    faces
+}
+
+/// Assemble faces into shells
+///
+/// The faces can originate from different shells. Where those shells touch,
+/// vertices at the same position are unified, and coincident half-edges are
+/// turned into siblings. Adjacent faces on the same plane, that have the same
+/// orientation and color, are merged. Afterwards, the faces are grouped into
+/// shells, according to how they are connected.
+///
+/// `tolerance` is the distance, below which points are considered to be
+/// identical.
+pub(super) fn assemble(
+    faces: Vec<Handle<Face>>,
+    tolerance: Scalar,
+    core: &mut Core,
+) -> Vec<Handle<Shell>> {
+    let faces = connect_faces(faces, tolerance, core);
+    let faces = merge_coplanar_faces(faces, core);
+
+    group_faces(&faces)
+        .into_iter()
+        .map(|faces| Shell::new(faces).insert(core))
+        .collect()
+}
+
+/// Unify vertices, and make siblings of coincident half-edges
+fn connect_faces(
+    faces: Vec<Handle<Face>>,
+    tolerance: Scalar,
+    core: &mut Core,
+) -> Vec<Handle<Face>> {
+    struct Entry {
+        half_edge: Handle<HalfEdge>,
+        curve: Handle<Curve>,
+        geometry: HalfEdgeGeom,
+        surface: Handle<Surface>,
+        vertices: [Handle<Vertex>; 2],
+        changed: bool,
+    }
+
+    let mut vertices = Vertices::new(tolerance);
+
+    let mut entries = Vec::new();
+    for face in &faces {
+        let surface = core.layers.geometry.of_surface(face.surface());
+
+        let mut cycles = Vec::new();
+        for cycle in face.region().all_cycles() {
+            let starts = cycle
+                .half_edges()
+                .iter()
+                .map(|half_edge| {
+                    let position = surface.point_from_surface_coords(
+                        core.layers
+                            .geometry
+                            .of_half_edge(half_edge)
+                            .start_position(),
+                    );
+                    vertices
+                        .find_or_register(position, half_edge.start_vertex())
+                })
+                .collect::<Vec<_>>();
+
+            let entries_of_cycle = cycle
+                .half_edges()
+                .iter()
+                .enumerate()
+                .map(|(i, half_edge)| Entry {
+                    half_edge: half_edge.clone(),
+                    curve: half_edge.curve().clone(),
+                    geometry: core
+                        .layers
+                        .geometry
+                        .of_half_edge(half_edge)
+                        .clone(),
+                    surface: face.surface().clone(),
+                    vertices: [
+                        starts[i].clone(),
+                        starts[(i + 1) % starts.len()].clone(),
+                    ],
+                    changed: starts[i].id() != half_edge.start_vertex().id(),
+                })
+                .collect::<Vec<_>>();
+            cycles.push(entries_of_cycle);
+        }
+
+        entries.push(cycles);
+    }
+
+    let key_of = |entry: &Entry| -> HalfEdgeKey {
+        let [start, end] = &entry.vertices;
+        (
+            entry.curve.id(),
+            entry.geometry.boundary,
+            start.id(),
+            end.id(),
+        )
+    };
+    let sibling_key_of = |entry: &Entry| -> HalfEdgeKey {
+        let [start, end] = &entry.vertices;
+        (
+            entry.curve.id(),
+            entry.geometry.boundary.reverse(),
+            end.id(),
+            start.id(),
+        )
+    };
+
+    let all_keys = entries
+        .iter()
+        .flatten()
+        .flatten()
+        .map(key_of)
+        .collect::<BTreeSet<_>>();
+
+    // Half-edges that don't have a sibling yet, indexed by their vertices.
+    let mut unmatched = BTreeMap::new();
+    for (i, cycles) in entries.iter().enumerate() {
+        for (j, cycle) in cycles.iter().enumerate() {
+            for (k, entry) in cycle.iter().enumerate() {
+                if all_keys.contains(&sibling_key_of(entry)) {
+                    continue;
+                }
+
+                let [start, end] = &entry.vertices;
+                unmatched
+                    .entry((start.id(), end.id()))
+                    .or_insert_with(Vec::new)
+                    .push([i, j, k]);
+            }
+        }
+    }
+
+    // Points along a half-edge, at a fraction of its boundary
+    let point_on = |entry: &Entry, fraction: f64, core: &Core| {
+        let [a, b] = entry.geometry.boundary.inner;
+        core.layers
+            .geometry
+            .of_surface(&entry.surface)
+            .point_from_surface_coords(
+                entry
+                    .geometry
+                    .path
+                    .point_from_path_coords([a.t + (b.t - a.t) * fraction]),
+            )
+    };
+
+    let mut paired = BTreeSet::new();
+    let locations = unmatched.values().flatten().copied().collect::<Vec<_>>();
+    for [i, j, k] in locations {
+        if paired.contains(&[i, j, k]) {
+            continue;
+        }
+
+        let entry = &entries[i][j][k];
+        let [start, end] = &entry.vertices;
+
+        // Coincident half-edges that run in opposite directions pass the same
+        // points, in reverse order. Checking more than one point is required
+        // for half-edges that start and end at the same vertex.
+        let partner = unmatched
+            .get(&(end.id(), start.id()))
+            .into_iter()
+            .flatten()
+            .copied()
+            .find(|&location| {
+                let [pi, pj, pk] = location;
+                let partner = &entries[pi][pj][pk];
+
+                location != [i, j, k]
+                    && !paired.contains(&location)
+                    && [0.25, 0.5].into_iter().all(|fraction| {
+                        let a = point_on(entry, fraction, core);
+                        let b = point_on(partner, 1. - fraction, core);
+                        (a - b).magnitude() <= tolerance
+                    })
+            });
+        let Some([pi, pj, pk]) = partner else {
+            continue;
+        };
+
+        // One of the half-edges needs to adopt the curve of the other. Only
+        // circles that are traversed at the same rate can do that.
+        let partner = &entries[pi][pj][pk];
+        let adopted = adopt_curve(&partner.geometry, &entry.geometry)
+            .map(|geometry| ([pi, pj, pk], entry.curve.clone(), geometry))
+            .or_else(|| {
+                adopt_curve(&entry.geometry, &partner.geometry).map(
+                    |geometry| ([i, j, k], partner.curve.clone(), geometry),
+                )
+            });
+        let Some(([ai, aj, ak], curve, geometry)) = adopted else {
+            continue;
+        };
+
+        let adopting = &mut entries[ai][aj][ak];
+        core.layers.geometry.define_curve(
+            curve.clone(),
+            adopting.surface.clone(),
+            LocalCurveGeom {
+                path: geometry.path.clone(),
+            },
+        );
+        adopting.curve = curve;
+        adopting.geometry = geometry;
+        adopting.changed = true;
+
+        paired.insert([i, j, k]);
+        paired.insert([pi, pj, pk]);
+    }
+
+    faces
+        .into_iter()
+        .zip(entries)
+        .map(|(face, cycles)| {
+            if !cycles.iter().flatten().any(|entry| entry.changed) {
+                return face;
+            }
+
+            let mut new_cycles = cycles
+                .into_iter()
+                .zip(face.region().all_cycles())
+                .map(|(entries, cycle)| {
+                    let half_edges = entries
+                        .into_iter()
+                        .map(|entry| {
+                            if !entry.changed {
+                                return entry.half_edge;
+                            }
+
+                            let [start, _] = entry.vertices;
+                            HalfEdge::new(entry.curve, start)
+                                .insert(core)
+                                .derive_from(&entry.half_edge, core)
+                                .set_geometry(
+                                    entry.geometry,
+                                    &mut core.layers.geometry,
+                                )
+                        })
+                        .collect::<Vec<_>>();
+
+                    Cycle::new(half_edges).insert(core).derive_from(cycle, core)
+                })
+                .collect::<Vec<_>>()
+                .into_iter();
+
+            let exterior = new_cycles
+                .next()
+                .expect("Region must have an exterior cycle");
+            let region = Region::new(exterior, new_cycles)
+                .insert(core)
+                .derive_from(face.region(), core);
+
+            Face::new(face.surface().clone(), region)
+                .insert(core)
+                .derive_from(&face, core)
+        })
+        .collect()
+}
+
+/// Compute the geometry of a half-edge, when adopting another's curve
+///
+/// The half-edge must be coincident with `sibling`, but run in the opposite
+/// direction. Returns `None`, if the path of the half-edge can't be expressed
+/// in the coordinates of the sibling's curve.
+fn adopt_curve(
+    geometry: &HalfEdgeGeom,
+    sibling: &HalfEdgeGeom,
+) -> Option<HalfEdgeGeom> {
+    let boundary = sibling.boundary.reverse();
+
+    // The path coordinates of the half-edge, as a function of the coordinates
+    // of the sibling's curve, are `alpha * t + beta`.
+    let [t0, t1] = boundary.inner.map(|t| t.t);
+    let [q0, q1] = geometry.boundary.inner.map(|t| t.t);
+    let alpha = (q1 - q0) / (t1 - t0);
+    let beta = q0 - alpha * t0;
+
+    let path = match &geometry.path {
+        SurfacePath::Line(line) => {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                line.point_from_line_coords([beta]),
+                line.direction() * alpha,
+            ))
+        }
+        SurfacePath::Circle(circle) => {
+            if (alpha.abs() - Scalar::ONE).abs() > Scalar::from(1e-9) {
+                return None;
+            }
+            SurfacePath::Circle(reparameterize_circle(circle, alpha, beta))
+        }
+        SurfacePath::Spline(_) => return None,
+    };
+
+    Some(HalfEdgeGeom { path, boundary })
+}
+
+/// Merge adjacent faces on the same plane
+fn merge_coplanar_faces(
+    faces: Vec<Handle<Face>>,
+    core: &mut Core,
+) -> Vec<Handle<Face>> {
+    let mut groups = UnionFind::new(faces.len());
+
+    let mut half_edges = BTreeMap::new();
+    for (i, face) in faces.iter().enumerate() {
+        for (half_edge, key, sibling_key) in keyed_half_edges(face, core) {
+            if let Some(&(j, _)) = half_edges.get(&sibling_key) {
+                if j != i && can_merge(&faces[i], &faces[j], core) {
+                    groups.union(i, j);
+                }
+            }
+            half_edges.insert(key, (i, half_edge));
+        }
+    }
+
+    let mut merged = Vec::new();
+
+    for group in groups.groups() {
+        if let [i] = group.as_slice() {
+            merged.push(faces[*i].clone());
+            continue;
+        }
+
+        let base = faces[group[0]].clone();
+        let keys = group
+            .iter()
+            .flat_map(|&i| keyed_half_edges(&faces[i], core))
+            .map(|(_, key, _)| key)
+            .collect::<BTreeSet<_>>();
+
+        let mut boundary = Vec::new();
+        for &i in &group {
+            let face = &faces[i];
+
+            for cycle in face.region().all_cycles() {
+                for (half_edge, next) in cycle.half_edges().pairs() {
+                    let (_, _, sibling_key) =
+                        key_of_half_edge(half_edge, next, core);
+                    if keys.contains(&sibling_key) {
+                        // The half-edge separates two of the merged faces.
+                        continue;
+                    }
+
+                    let half_edge = if face.surface() == base.surface() {
+                        half_edge.clone()
+                    } else {
+                        let mut geometry = core
+                            .layers
+                            .geometry
+                            .of_half_edge(half_edge)
+                            .clone();
+                        let global = core
+                            .layers
+                            .geometry
+                            .of_surface(face.surface())
+                            .path_from_surface_coords(geometry.path);
+                        geometry.path = project_onto_plane(
+                            &global,
+                            core.layers.geometry.of_surface(base.surface()),
+                        );
+
+                        core.layers.geometry.define_curve(
+                            half_edge.curve().clone(),
+                            base.surface().clone(),
+                            LocalCurveGeom {
+                                path: geometry.path.clone(),
+                            },
+                        );
+
+                        HalfEdge::new(
+                            half_edge.curve().clone(),
+                            half_edge.start_vertex().clone(),
+                        )
+                        .insert(core)
+                        .derive_from(half_edge, core)
+                        .set_geometry(geometry, &mut core.layers.geometry)
+                    };
+
+                    boundary.push((half_edge, next.start_vertex().clone()));
+                }
+            }
+        }
+
+        merged.extend(faces_from_half_edges(&base, boundary, core));
+    }
+
+    merged
+}
+
+/// Determine whether two adjacent faces can be merged
+fn can_merge(a: &Handle<Face>, b: &Handle<Face>, core: &mut Core) -> bool {
+    let same_orientation = {
+        let geometry = &core.layers.geometry;
+        let [surface_a, surface_b] =
+            [a, b].map(|face| geometry.of_surface(face.surface()));
+
+        if !is_plane(surface_a) || !is_plane(surface_b) {
+            return false;
+        }
+        if (surface_a, surface_b).intersect()
+            != Some(SurfaceSurfaceIntersection::Coincident)
+        {
+            return false;
+        }
+
+        let origin = Point::from([0., 0.]);
+        match (a.normal_at(origin, geometry), b.normal_at(origin, geometry)) {
+            (Some(normal_a), Some(normal_b)) => {
+                normal_a.dot(&normal_b) > Scalar::ZERO
+            }
+            _ => false,
+        }
+    };
+
+    same_orientation && a.region().get_color(core) == b.region().get_color(core)
+}
+
+/// Group faces according to how they are connected
+fn group_faces(faces: &[Handle<Face>]) -> Vec<Vec<Handle<Face>>> {
+    let mut groups = UnionFind::new(faces.len());
+
+    let mut by_curve = BTreeMap::new();
+    for (i, face) in faces.iter().enumerate() {
+        for cycle in face.region().all_cycles() {
+            for half_edge in cycle.half_edges() {
+                if let Some(&j) = by_curve.get(&half_edge.curve().id()) {
+                    groups.union(i, j);
+                }
+                by_curve.insert(half_edge.curve().id(), i);
+            }
+        }
+    }
+
+    groups
+        .groups()
+        .into_iter()
+        .map(|group| group.into_iter().map(|i| faces[i].clone()).collect())
+        .collect()
+}
+
+pub(super) type HalfEdgeKey =
+    (ObjectId, CurveBoundary<Point<1>>, ObjectId, ObjectId);
+
+/// Compute the key of each half-edge of a face, and the key of its sibling
+fn keyed_half_edges(
+    face: &Handle<Face>,
+    core: &Core,
+) -> Vec<(Handle<HalfEdge>, HalfEdgeKey, HalfEdgeKey)> {
+    face.region()
+        .all_cycles()
+        .flat_map(|cycle| cycle.half_edges().pairs())
+        .map(|(half_edge, next)| key_of_half_edge(half_edge, next, core))
+        .collect()
+}
+
+/// Compute the key of a half-edge, and the key of its sibling
+///
+/// Half-edges are siblings, if the key of one equals the sibling key of the
+/// other. `next` is the half-edge that follows in the same cycle.
+pub(super) fn key_of_half_edge(
+    half_edge: &Handle<HalfEdge>,
+    next: &Handle<HalfEdge>,
+    core: &Core,
+) -> (Handle<HalfEdge>, HalfEdgeKey, HalfEdgeKey) {
+    let curve = half_edge.curve().id();
+    let boundary = core.layers.geometry.of_half_edge(half_edge).boundary;
+    let [start, end] =
+        [half_edge, next].map(|half_edge| half_edge.start_vertex().id());
+
+    (
+        half_edge.clone(),
+        (curve, boundary, start, end),
+        (curve, boundary.reverse(), end, start),
+    )
+}
+
+/// Groups of indices, that can be merged
+struct UnionFind {
+    parents: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+        }
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+        self.parents[i] = root;
+        root
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let [a, b] = [a, b].map(|i| self.find(i));
+        self.parents[a.max(b)] = a.min(b);
+    }
+
+    fn groups(mut self) -> Vec<Vec<usize>> {
+        let mut groups = BTreeMap::new();
+        for i in 0..self.parents.len() {
+            groups.entry(self.find(i)).or_insert_with(Vec::new).push(i);
+        }
+        groups.into_values().collect()
+    }
+}
+
+/// Finds vertices by their position
+///
+/// Positions are sorted into cells of a grid, so only vertices in neighboring
+/// cells need to be compared.
+struct Vertices {
+    cells: BTreeMap<[i64; 3], Vec<(Point<3>, Handle<Vertex>)>>,
+    tolerance: Scalar,
+}
+
+impl Vertices {
+    fn new(tolerance: Scalar) -> Self {
+        Self {
+            cells: BTreeMap::new(),
+            tolerance,
+        }
+    }
+
+    fn cell_of(&self, point: Point<3>) -> [i64; 3] {
+        point
+            .coords
+            .components
+            .map(|c| (c / (self.tolerance * 2.)).floor().into_f64() as i64)
+    }
+
+    fn find(&self, point: Point<3>) -> Option<Handle<Vertex>> {
+        let [x, y, z] = self.cell_of(point);
+
+        let mut nearest: Option<(Scalar, &Handle<Vertex>)> = None;
+        for cell in (x - 1..=x + 1).flat_map(|x| {
+            (y - 1..=y + 1)
+                .flat_map(move |y| (z - 1..=z + 1).map(move |z| [x, y, z]))
+        }) {
+            for (position, vertex) in
+                self.cells.get(&cell).into_iter().flatten()
+            {
+                let distance = (position - point).magnitude();
+                if distance <= self.tolerance
+                    && nearest.map_or(true, |(nearest, _)| distance < nearest)
+                {
+                    nearest = Some((distance, vertex));
+                }
+            }
+        }
+
+        nearest.map(|(_, vertex)| vertex.clone())
+    }
+
+    fn insert(&mut self, point: Point<3>, vertex: Handle<Vertex>) {
+        self.cells
+            .entry(self.cell_of(point))
+            .or_default()
+            .push((point, vertex));
+    }
+
+    /// Find the vertex at the provided position, or create a new one
+    fn find_or_insert(
+        &mut self,
+        point: Point<3>,
+        core: &mut Core,
+    ) -> Handle<Vertex> {
+        self.find(point).unwrap_or_else(|| {
+            let vertex = Vertex::new().insert(core);
+            self.insert(point, vertex.clone());
+            vertex
+        })
+    }
+
+    /// Find the vertex at the provided position, or register the given one
+    fn find_or_register(
+        &mut self,
+        point: Point<3>,
+        vertex: &Handle<Vertex>,
+    ) -> Handle<Vertex> {
+        self.find(point).unwrap_or_else(|| {
+            self.insert(point, vertex.clone());
+            vertex.clone()
+        })
+    }
+}
+
+/// Determine whether two surfaces are tangent along their intersection
+///
+/// The surfaces are checked at a single point of the intersection, which is
+/// enough for the surfaces that can be intersected: planes and cylinders touch
+/// along straight lines, if they touch without crossing.
+fn are_tangent(
+    [(surface_a, path_a), (surface_b, path_b)]: [(&SurfaceGeom, &SurfacePath);
+        2],
+    tolerance: Scalar,
+) -> bool {
+    let [normal_a, normal_b] =
+        [(surface_a, path_a), (surface_b, path_b)].map(|(surface, path)| {
+            surface.normal_at(path.point_from_path_coords([Scalar::ZERO]))
+        });
+
+    let (Some(normal_a), Some(normal_b)) = (normal_a, normal_b) else {
+        return false;
+    };
+
+    normal_a.cross(&normal_b).magnitude() <= tolerance
+}
+
+/// Find a point within a face, that is well clear of its boundary
+///
+/// Returns the point in surface coordinates. Tries a number of horizontal
+/// lines through the face, and returns the middle of the widest part of the
+/// face that any of them cross.
+pub(super) fn sample_point(face: &Face, geometry: &Geometry) -> Point<2> {
+    let cycles = face
+        .region()
+        .all_cycles()
+        .map(|cycle| {
+            cycle
+                .half_edges()
+                .iter()
+                .flat_map(|half_edge| sample_half_edge(half_edge, geometry))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let [min, max] = {
+        let v = cycles.first().into_iter().flatten().map(|point| point.v);
+        [
+            v.clone().min().unwrap_or_default(),
+            v.max().unwrap_or_default(),
+        ]
+    };
+
+    // A line that passes through a vertex of the boundary might not register
+    // all crossings there. Such lines are moved between the nearest vertices.
+    let mut vertices = cycles
+        .iter()
+        .flatten()
+        .map(|point| point.v)
+        .collect::<Vec<_>>();
+    vertices.sort();
+    vertices.dedup();
+
+    let mut widest: Option<(Scalar, Point<2>)> = None;
+
+    for fraction in [0.5, 0.25, 0.75, 0.125, 0.375, 0.625, 0.875] {
+        let mut v = min + (max - min) * fraction;
+
+        let i = vertices.partition_point(|&vertex| vertex < v);
+        let [below, above] = [
+            i.checked_sub(1).and_then(|i| vertices.get(i)),
+            vertices.get(i),
+        ];
+        if let (Some(&below), Some(&above)) = (below, above) {
+            let clearance = (above - below) / 4.;
+            if v - below < clearance || above - v < clearance {
+                v = (below + above) / 2.;
+            }
+        }
+
+        let mut crossings = Vec::new();
+        for points in &cycles {
+            for (i, a) in points.iter().enumerate() {
+                let b = points[(i + 1) % points.len()];
+
+                if (a.v > v) != (b.v > v) {
+                    crossings.push(a.u + (v - a.v) / (b.v - a.v) * (b.u - a.u));
+                }
+            }
+        }
+        crossings.sort();
+
+        for pair in crossings.chunks_exact(2) {
+            let width = pair[1] - pair[0];
+
+            if widest.map_or(true, |(widest, _)| width > widest) {
+                widest =
+                    Some((width, Point::from([(pair[0] + pair[1]) / 2., v])));
+            }
+        }
+    }
+
+    let (_, point) = widest.expect("Face must have an area");
+    point
+}
+
+/// Divide a curve into intervals, between the provided path coordinates
+///
+/// Coordinates that refer to the same point are only considered once. If the
+/// curve is closed, it's assumed to repeat after a full turn, and an interval
+/// that wraps around connects the last coordinate with the first one.
+fn intervals(
+    mut coords: Vec<Scalar>,
+    is_closed: bool,
+    point: impl Fn(Scalar) -> Point<3>,
+    tolerance: Scalar,
+) -> Vec<[Scalar; 2]> {
+    if is_closed {
+        for t in &mut coords {
+            *t -= Scalar::TAU * (*t / Scalar::TAU).floor();
+        }
+    }
+    coords.sort();
+
+    let mut distinct: Vec<Scalar> = Vec::new();
+    for t in coords {
+        let is_new = distinct.last().map_or(true, |&last| {
+            (point(t) - point(last)).magnitude() > tolerance
+        });
+        if is_new {
+            distinct.push(t);
+        }
+    }
+
+    let mut intervals = distinct
+        .windows(2)
+        .map(|window| [window[0], window[1]])
+        .collect::<Vec<_>>();
+
+    if is_closed {
+        match (distinct.first(), distinct.last()) {
+            (Some(&first), Some(&last)) => {
+                if distinct.len() > 1
+                    && (point(last) - point(first)).magnitude() <= tolerance
+                {
+                    intervals.pop();
+                    let last = intervals.last().map_or(first, |&[_, end]| end);
+                    intervals.push([last, first + Scalar::TAU]);
+                } else {
+                    intervals.push([last, first + Scalar::TAU]);
+                }
+            }
+            _ => intervals.push([Scalar::ZERO, Scalar::TAU]),
+        }
+    }
+
+    intervals
+}
+
+/// Project a global path into a plane
+///
+/// The projected path has the same path coordinates as the global one.
+pub(super) fn project_onto_plane(
+    path: &GlobalPath,
+    plane: &SurfaceGeom,
+) -> SurfacePath {
+    let GlobalPath::Circle(circle) = path else {
+        return plane.project_global_path(path.clone());
+    };
+
+    let center = plane.project_global_point(circle.center());
+    let [a, b] = [circle.a(), circle.b()].map(|vector| {
+        plane.project_global_point(circle.center() + vector) - center
+    });
+
+    let Some(circle) = Circle::try_new(center, a, b) else {
+        todo!(
+            "Projecting a circle into a plane, where it doesn't remain a \
+            circle, is not supported."
+        )
+    };
+
+    SurfacePath::Circle(circle)
+}
+
+/// Compute the global form of a path on a plane or cylinder
+///
+/// The global path has the same path coordinates as the provided one.
+pub(super) fn global_path(
+    path: &SurfacePath,
+    surface: &SurfaceGeom,
+) -> GlobalPath {
+    if is_plane(surface) {
+        return surface.path_from_surface_coords(path.clone());
+    }
+
+    if let (
+        SurfaceGeom::Swept {
+            u,
+            v: SweepPath::Line(axis),
+        },
+        SurfacePath::Line(line),
+    ) = (surface, path)
+    {
+        let direction = line.direction();
+
+        if direction.u == Scalar::ZERO {
+            // The line is parallel to the axis of the cylinder.
+            let points = [0., 1.].map(|t| {
+                (
+                    [t],
+                    surface.point_from_surface_coords(
+                        line.point_from_line_coords([t]),
+                    ),
+                )
+            });
+            return GlobalPath::Line(Line::from_points_with_line_coords(
+                points,
+            ));
+        }
+
+        if let GlobalPath::Circle(circle) = u {
+            let is_around_axis = direction.v == Scalar::ZERO
+                && (direction.u.abs() - Scalar::ONE).abs()
+                    <= Scalar::from(1e-12);
+
+            if is_around_axis {
+                let origin = line.origin();
+                let circle = Circle::new(
+                    circle.center() + *axis * origin.v,
+                    circle.a(),
+                    circle.b(),
+                );
+                return GlobalPath::Circle(reparameterize_circle(
+                    &circle,
+                    direction.u,
+                    origin.u,
+                ));
+            }
+        }
+    }
+
+    todo!(
+        "Only paths on planes, and lines along or around the axis of a \
+        cylinder, can be converted into global coordinates."
+    )
+}
+
+/// Change the coordinates of a circle
+///
+/// The coordinate `t` of the new circle refers to the point that the
+/// coordinate `sign * t + offset` refers to on the original circle.
+fn reparameterize_circle<const D: usize>(
+    circle: &Circle<D>,
+    sign: Scalar,
+    offset: Scalar,
+) -> Circle<D> {
+    let sign = if sign < Scalar::ZERO {
+        -Scalar::ONE
+    } else {
+        Scalar::ONE
+    };
+    let (sin, cos) = offset.sin_cos();
+
+    Circle::new(
+        circle.center(),
+        circle.a() * cos + circle.b() * sin,
+        (circle.b() * cos - circle.a() * sin) * sign,
+    )
+}
+
+fn translate(path: &SurfacePath, offset: Vector<2>) -> SurfacePath {
+    match path {
+        SurfacePath::Circle(circle) => SurfacePath::Circle(Circle::new(
+            circle.center() + offset,
+            circle.a(),
+            circle.b(),
+        )),
+        SurfacePath::Line(line) => {
+            SurfacePath::Line(Line::from_origin_and_direction(
+                line.origin() + offset,
+                line.direction(),
+            ))
+        }
+        SurfacePath::Spline(spline) => SurfacePath::Spline(
+            spline.clone().map_control_points(|point| point + offset),
+        ),
+    }
+}
+
+/// Determine whether a surface is a plane
+pub(super) fn is_plane(surface: &SurfaceGeom) -> bool {
+    matches!(
+        surface,
+        SurfaceGeom::Swept {
+            u: GlobalPath::Line(_),
+            v: SweepPath::Line(_),
+        }
+    )
+}
+
+/// Determine whether a surface repeats along its u-axis
+fn is_periodic(surface: &SurfaceGeom) -> bool {
+    matches!(
+        surface,
+        SurfaceGeom::Swept {
+            u: GlobalPath::Circle(_) | GlobalPath::Ellipse(_),
+            v: SweepPath::Line(_),
+        }
+    )
+}
@@ -137,10 +137,10 @@ impl JoinCycle for Cycle {
                                 )
                                 .insert(core)
                                 .set_geometry(
-                                    *core
-                                        .layers
+                                    core.layers
                                         .geometry
-                                        .of_half_edge(half_edge),
+                                        .of_half_edge(half_edge)
+                                        .clone(),
                                     &mut core.layers.geometry,
                                 )]
                         },
@@ -156,10 +156,10 @@ impl JoinCycle for Cycle {
                                 )
                                 .insert(core)
                                 .set_geometry(
-                                    *core
-                                        .layers
+                                    core.layers
                                         .geometry
-                                        .of_half_edge(half_edge),
+                                        .of_half_edge(half_edge)
+                                        .clone(),
                                     &mut core.layers.geometry,
                                 )]
                         },
@@ -0,0 +1,1047 @@
+//! Loft between [`Sketch`]es
+//!
+//! See [`LoftSketches`].
+
+use fj_interop::Color;
+use fj_math::{Circle, Ellipse, Line, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{
+        CurveBoundary, GlobalPath, HalfEdgeGeom, SurfaceGeom, SurfacePath,
+        SweepPath,
+    },
+    operations::{
+        build::BuildSurface,
+        derive::DeriveFrom,
+        geometry::{UpdateCurveGeometry, UpdateHalfEdgeGeometry},
+        insert::Insert,
+        presentation::{GetColor, SetColor},
+    },
+    storage::Handle,
+    topology::{
+        Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Surface, Vertex,
+    },
+    Core,
+};
+
+/// # Loft between [`Sketch`]es
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: self
+pub trait LoftSketches {
+    /// # Loft between the sketches
+    ///
+    /// Each sketch is placed on the surface it is paired with, which must be a
+    /// plane. The sketches are connected in the order they are provided in, by
+    /// ruled faces between corresponding half-edges. The first and the last
+    /// sketch become the caps of the resulting shell.
+    ///
+    /// Each sketch must consist of a single region, and all regions must have
+    /// the same number of cycles. If corresponding cycles have the same number
+    /// of half-edges, half-edges correspond to each other, if they are at the
+    /// same position within their respective cycles.
+    ///
+    /// Otherwise, the half-edges of the cycles are split, until all of them
+    /// have the same number of half-edges. Each vertex of one cycle is matched
+    /// with a point at the same fraction of the other cycles' length, counting
+    /// from the points where neighboring cycles are closest to each other. This
+    /// way, a circle can be lofted into a square, for example.
+    ///
+    /// All sketches must be winded counter-clockwise, and all surfaces must
+    /// face the same way.
+    ///
+    /// ## Implementation Note
+    ///
+    /// Arcs can only be connected to other arcs, if both have the same angle.
+    fn loft_sketches(&self, core: &mut Core) -> Shell;
+}
+
+impl LoftSketches for [(Sketch, Handle<Surface>)] {
+    fn loft_sketches(&self, core: &mut Core) -> Shell {
+        assert!(self.len() >= 2, "Loft requires at least two sketches");
+
+        let mut profiles = self
+            .iter()
+            .map(|(sketch, surface)| Profile::new(sketch, surface, core))
+            .collect::<Vec<_>>();
+
+        for pair in profiles.windows(2) {
+            let [a, b] = [&pair[0], &pair[1]];
+
+            assert_eq!(
+                a.cycles.len(),
+                b.cycles.len(),
+                "Lofted regions must have the same number of cycles"
+            );
+            assert!(
+                a.normal.dot(&b.normal) > Scalar::ZERO,
+                "Surfaces of lofted sketches must face the same way"
+            );
+        }
+
+        for c in 0..profiles[0].cycles.len() {
+            match_edges(&mut profiles, c);
+        }
+
+        // The bottom face is the face of the first sketch. It needs to face
+        // away from the direction of the loft, while the top face needs to face
+        // towards it.
+        let is_negative_loft = {
+            let [first, second] = [&profiles[0], &profiles[1]];
+            first.normal.dot(&(second.center() - first.center())) < Scalar::ZERO
+        };
+
+        // From here on, all cycles are winded like the cycles of the top face.
+        // The cycles of the bottom face are winded the other way.
+        let profiles = profiles
+            .into_iter()
+            .map(|profile| {
+                if is_negative_loft {
+                    profile.reverse()
+                } else {
+                    profile
+                }
+            })
+            .collect::<Vec<_>>();
+        let levels = profiles
+            .iter()
+            .map(|profile| Level::new(profile, core))
+            .collect::<Vec<_>>();
+
+        let mut faces = Vec::new();
+
+        let bottom_face = {
+            let (profile, level) = (&profiles[0], &levels[0]);
+
+            let cycles = profile
+                .cycles
+                .iter()
+                .zip(&level.cycles)
+                .map(|(edges, level)| {
+                    let num_edges = edges.len();
+
+                    let half_edges = edges
+                        .iter()
+                        .enumerate()
+                        .rev()
+                        .map(|(i, edge)| {
+                            new_half_edge(
+                                level.curves[i].clone(),
+                                level.vertices[(i + 1) % num_edges].clone(),
+                                HalfEdgeGeom {
+                                    path: edge.path.clone(),
+                                    boundary: edge.boundary.reverse(),
+                                },
+                                core,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+
+                    Cycle::new(half_edges).insert(core)
+                })
+                .collect::<Vec<_>>();
+
+            profile.cap_face(cycles, core)
+        };
+        let top_face = {
+            let last = profiles.len() - 1;
+            let (profile, level) = (&profiles[last], &levels[last]);
+
+            let cycles = profile
+                .cycles
+                .iter()
+                .zip(&level.cycles)
+                .map(|(edges, level)| {
+                    let half_edges = edges
+                        .iter()
+                        .enumerate()
+                        .map(|(i, edge)| {
+                            new_half_edge(
+                                level.curves[i].clone(),
+                                level.vertices[i].clone(),
+                                HalfEdgeGeom {
+                                    path: edge.path.clone(),
+                                    boundary: edge.boundary,
+                                },
+                                core,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+
+                    Cycle::new(half_edges).insert(core)
+                })
+                .collect::<Vec<_>>();
+
+            profile.cap_face(cycles, core)
+        };
+
+        faces.extend([bottom_face, top_face]);
+
+        for k in 1..profiles.len() {
+            let [bottom, top] = [&profiles[k - 1], &profiles[k]];
+            let [bottom_level, top_level] = [&levels[k - 1], &levels[k]];
+
+            let color = bottom.region.get_color(core);
+
+            for (c, (bottom_edges, top_edges)) in
+                bottom.cycles.iter().zip(&top.cycles).enumerate()
+            {
+                let [bottom_level, top_level] =
+                    [&bottom_level.cycles[c], &top_level.cycles[c]];
+                let num_edges = bottom_edges.len();
+
+                // The curves that connect the vertices of the bottom cycle to
+                // the vertices of the top cycle. Each of them is shared by two
+                // neighboring faces.
+                let side_curves = (0..num_edges)
+                    .map(|_| Curve::new().insert(core))
+                    .collect::<Vec<_>>();
+
+                for i in 0..num_edges {
+                    let j = (i + 1) % num_edges;
+
+                    let face = loft_edges(
+                        [&bottom_edges[i], &top_edges[i]],
+                        [&bottom_level.curves[i], &top_level.curves[i]],
+                        [&side_curves[i], &side_curves[j]],
+                        [
+                            &bottom_level.vertices[i],
+                            &bottom_level.vertices[j],
+                            &top_level.vertices[j],
+                            &top_level.vertices[i],
+                        ],
+                        color,
+                        core,
+                    );
+                    faces.push(face);
+                }
+            }
+        }
+
+        let faces = faces.into_iter().map(|face| face.insert(core));
+        Shell::new(faces)
+    }
+}
+
+/// A sketch, as it is placed on its surface
+struct Profile {
+    region: Handle<Region>,
+    surface: Handle<Surface>,
+    normal: Vector<3>,
+
+    /// The edges of all cycles of the region, exterior first
+    cycles: Vec<Vec<ProfileEdge>>,
+}
+
+impl Profile {
+    fn new(sketch: &Sketch, surface: &Handle<Surface>, core: &Core) -> Self {
+        assert_eq!(
+            sketch.regions().len(),
+            1,
+            "Each lofted sketch must consist of exactly one region"
+        );
+        let region = sketch.regions().only().clone();
+
+        // The following code assumes that the sketch is winded counter-
+        // clockwise. Let's check that real quick.
+        assert!(region.exterior().winding(&core.layers.geometry).is_ccw());
+
+        let geometry = &core.layers.geometry;
+        let surface_geom = geometry.of_surface(surface).clone();
+
+        let SurfaceGeom::Swept {
+            u: GlobalPath::Line(u),
+            v: SweepPath::Line(v),
+        } = surface_geom
+        else {
+            todo!("Lofting sketches on curved surfaces is not supported")
+        };
+        let normal = u.direction().cross(&v);
+
+        let cycles = region
+            .all_cycles()
+            .map(|cycle| {
+                cycle
+                    .half_edges()
+                    .iter()
+                    .map(|half_edge| {
+                        let HalfEdgeGeom { path, boundary } =
+                            geometry.of_half_edge(half_edge).clone();
+
+                        ProfileEdge {
+                            global: surface_geom
+                                .path_from_surface_coords(path.clone()),
+                            path,
+                            boundary,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            region,
+            surface: surface.clone(),
+            normal,
+            cycles,
+        }
+    }
+
+    /// The average of the start points of the exterior's edges
+    fn center(&self) -> Point<3> {
+        let exterior = &self.cycles[0];
+
+        let sum = exterior
+            .iter()
+            .map(|edge| edge.start().coords)
+            .fold(Vector::from([0., 0., 0.]), |sum, point| sum + point);
+
+        Point {
+            coords: sum / exterior.len() as f64,
+        }
+    }
+
+    /// Reverse the direction of all cycles
+    fn reverse(mut self) -> Self {
+        for cycle in &mut self.cycles {
+            cycle.reverse();
+
+            for edge in cycle {
+                edge.boundary = edge.boundary.reverse();
+            }
+        }
+
+        self
+    }
+
+    fn cap_face(&self, cycles: Vec<Handle<Cycle>>, core: &mut Core) -> Face {
+        let mut cycles = cycles.into_iter();
+        let region =
+            Region::new(cycles.next().expect("Region has exterior"), cycles)
+                .insert(core)
+                .derive_from(&self.region, core);
+
+        Face::new(self.surface.clone(), region)
+    }
+}
+
+/// A half-edge of a sketch, as it is placed on its surface
+#[derive(Clone)]
+struct ProfileEdge {
+    /// The path of the half-edge, in surface coordinates
+    path: SurfacePath,
+
+    /// The boundary of the half-edge, in curve coordinates
+    boundary: CurveBoundary<Point<1>>,
+
+    /// The path of the half-edge, in global coordinates
+    ///
+    /// Uses the same curve coordinates as `path`.
+    global: GlobalPath,
+}
+
+impl ProfileEdge {
+    fn start(&self) -> Point<3> {
+        self.global.point_from_path_coords(self.boundary.inner[0])
+    }
+
+    /// Sample points along the edge, at equal distances in curve coordinates
+    fn sample(&self, segments: u32) -> Vec<Point<3>> {
+        let [t0, t1] = self.boundary.inner.map(|point| point.t);
+
+        (0..=segments)
+            .map(|i| {
+                let t = t0 + (t1 - t0) * (f64::from(i) / f64::from(segments));
+                self.global.point_from_path_coords([t])
+            })
+            .collect()
+    }
+
+    /// Create a copy of the edge, that covers only a part of it
+    fn with_boundary(&self, [t0, t1]: [Scalar; 2]) -> Self {
+        Self {
+            boundary: CurveBoundary::from([[t0], [t1]]),
+            ..self.clone()
+        }
+    }
+}
+
+/// Split the edges of corresponding cycles into the same number of edges
+///
+/// `c` is the index of the cycles within their profiles. Cycles that already
+/// have the same number of edges are left as they are.
+///
+/// The position of a point on a cycle is measured as the fraction of the
+/// cycle's length from its start. To make these fractions comparable, each
+/// cycle gets an offset, which places its start at the point of the previous
+/// cycle, that is closest to it. Then every cycle is split at the fractions
+/// of all vertices of all cycles.
+fn match_edges(profiles: &mut [Profile], c: usize) {
+    // Fractions that are closer than this, are considered to be the same.
+    const EPSILON: f64 = 1e-6;
+
+    let num_edges = profiles[0].cycles[c].len();
+    if profiles
+        .iter()
+        .all(|profile| profile.cycles[c].len() == num_edges)
+    {
+        return;
+    }
+
+    let wrap = |fraction: Scalar| {
+        let fraction = fraction - fraction.floor();
+        if fraction > Scalar::ONE - EPSILON {
+            Scalar::ZERO
+        } else {
+            fraction
+        }
+    };
+
+    let measures = profiles
+        .iter()
+        .map(|profile| CycleMeasure::new(&profile.cycles[c]))
+        .collect::<Vec<_>>();
+
+    let mut offsets = vec![Scalar::ZERO];
+    for k in 1..profiles.len() {
+        let [previous, next] = [&measures[k - 1], &measures[k]];
+
+        let start = profiles[k].cycles[c][0].start();
+        let start = previous.center + (start - next.center);
+        let fraction =
+            previous.closest_fraction(&profiles[k - 1].cycles[c], start);
+
+        offsets.push(wrap(offsets[k - 1] + fraction));
+    }
+
+    let mut fractions = measures
+        .iter()
+        .zip(&offsets)
+        .flat_map(|(measure, &offset)| {
+            measure
+                .ranges
+                .iter()
+                .map(move |[start, _]| wrap(*start + offset))
+        })
+        .collect::<Vec<_>>();
+    fractions.sort();
+    fractions.dedup_by(|b, a| *b - *a < Scalar::from(EPSILON));
+
+    for ((profile, measure), offset) in
+        profiles.iter_mut().zip(&measures).zip(offsets)
+    {
+        let mut edges = Vec::new();
+
+        for (edge, &[start, end]) in
+            profile.cycles[c].iter().zip(&measure.ranges)
+        {
+            let mut splits = fractions
+                .iter()
+                .map(|&fraction| wrap(fraction - offset))
+                .filter(|&fraction| {
+                    fraction > start + EPSILON && fraction < end - EPSILON
+                })
+                .collect::<Vec<_>>();
+            splits.sort();
+
+            let [t0, t1] = edge.boundary.inner.map(|point| point.t);
+            let mut previous = (start, t0);
+
+            for fraction in splits {
+                let t = t0 + (t1 - t0) * ((fraction - start) / (end - start));
+                edges.push((previous.0, edge.with_boundary([previous.1, t])));
+                previous = (fraction, t);
+            }
+            edges.push((previous.0, edge.with_boundary([previous.1, t1])));
+        }
+
+        // The cycles need to start at the same fraction, for their edges to
+        // correspond to each other.
+        let first = edges
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, (fraction, _))| wrap(*fraction + offset))
+            .map(|(i, _)| i)
+            .unwrap_or_default();
+        edges.rotate_left(first);
+
+        profile.cycles[c] = edges.into_iter().map(|(_, edge)| edge).collect();
+    }
+}
+
+/// The length of a cycle of edges, as far as [`match_edges`] is concerned
+struct CycleMeasure {
+    /// The fractions of the cycle's length at the start and end of each edge
+    ranges: Vec<[Scalar; 2]>,
+
+    /// The center of the cycle, weighted by length
+    center: Point<3>,
+}
+
+impl CycleMeasure {
+    /// The number of line segments that each edge is approximated with
+    const SEGMENTS_PER_EDGE: u32 = 64;
+
+    fn new(edges: &[ProfileEdge]) -> Self {
+        let mut lengths = Vec::new();
+        let mut weighted = Vector::from([0., 0., 0.]);
+
+        for edge in edges {
+            let points = edge.sample(Self::SEGMENTS_PER_EDGE);
+
+            let mut length = Scalar::ZERO;
+            for segment in points.windows(2) {
+                let distance = segment[0].distance_to(&segment[1]);
+                let middle = segment[0] + (segment[1] - segment[0]) / 2.;
+
+                length += distance;
+                weighted = weighted + middle.coords * distance;
+            }
+
+            lengths.push(length);
+        }
+
+        let total = lengths
+            .iter()
+            .fold(Scalar::ZERO, |total, &length| total + length);
+
+        let mut ranges = Vec::new();
+        let mut start = Scalar::ZERO;
+        for length in lengths {
+            let end = start + length / total;
+            ranges.push([start, end]);
+            start = end;
+        }
+        if let Some([_, end]) = ranges.last_mut() {
+            *end = Scalar::ONE;
+        }
+
+        Self {
+            ranges,
+            center: Point {
+                coords: weighted / total,
+            },
+        }
+    }
+
+    /// Find the fraction of the cycle's length that is closest to a point
+    fn closest_fraction(
+        &self,
+        edges: &[ProfileEdge],
+        point: Point<3>,
+    ) -> Scalar {
+        let segments = Scalar::from(f64::from(Self::SEGMENTS_PER_EDGE));
+
+        let mut closest: Option<(Scalar, Scalar)> = None;
+
+        for (edge, &[start, end]) in edges.iter().zip(&self.ranges) {
+            let [t0, t1] = edge.boundary.inner.map(|point| point.t);
+            let distance = |s: Scalar| {
+                let t = t0 + (t1 - t0) * s;
+                edge.global.point_from_path_coords([t]).distance_to(&point)
+            };
+
+            // Find the closest sample, then refine around it.
+            let i = (0..=Self::SEGMENTS_PER_EDGE)
+                .map(|i| Scalar::from(f64::from(i)) / segments)
+                .min_by_key(|&s| distance(s))
+                .unwrap_or_default();
+            let [mut a, mut b] = [
+                (i - Scalar::ONE / segments).max(Scalar::ZERO),
+                (i + Scalar::ONE / segments).min(Scalar::ONE),
+            ];
+            for _ in 0..64 {
+                let [x, y] = [a + (b - a) / 3., b - (b - a) / 3.];
+                if distance(x) < distance(y) {
+                    b = y;
+                } else {
+                    a = x;
+                }
+            }
+            let s = (a + b) / 2.;
+
+            let distance = distance(s);
+            if closest.map_or(true, |(closest, _)| distance < closest) {
+                closest = Some((distance, start + (end - start) * s));
+            }
+        }
+
+        closest.map(|(_, fraction)| fraction).unwrap_or_default()
+    }
+}
+
+/// The topological objects that make up the edges of a sketch in the loft
+struct Level {
+    cycles: Vec<LevelCycle>,
+}
+
+impl Level {
+    fn new(profile: &Profile, core: &mut Core) -> Self {
+        let cycles = profile
+            .cycles
+            .iter()
+            .map(|edges| {
+                let vertices =
+                    edges.iter().map(|_| Vertex::new().insert(core)).collect();
+                let curves = edges
+                    .iter()
+                    .map(|edge| {
+                        Curve::new().insert(core).make_path_on_surface(
+                            edge.path.clone(),
+                            profile.surface.clone(),
+                            &mut core.layers.geometry,
+                        )
+                    })
+                    .collect();
+
+                LevelCycle { vertices, curves }
+            })
+            .collect();
+
+        Self { cycles }
+    }
+}
+
+struct LevelCycle {
+    /// The start vertex of each edge
+    vertices: Vec<Handle<Vertex>>,
+
+    /// The curve of each edge
+    curves: Vec<Handle<Curve>>,
+}
+
+/// Create the face that connects two corresponding edges
+///
+/// The vertices are expected in the order bottom start, bottom end, top end,
+/// top start.
+fn loft_edges(
+    [bottom, top]: [&ProfileEdge; 2],
+    [bottom_curve, top_curve]: [&Handle<Curve>; 2],
+    [down_curve, up_curve]: [&Handle<Curve>; 2],
+    [a, b, c, d]: [&Handle<Vertex>; 4],
+    color: Option<Color>,
+    core: &mut Core,
+) -> Face {
+    let (paths, range) = ruled_paths(bottom, top);
+    let [path_a, path_b] = paths;
+    let surface = Surface::ruled(path_a, path_b, core);
+
+    let zero = Scalar::ZERO;
+    let one = Scalar::ONE;
+    let [u0, u1] = range.inner.map(|point| point.t);
+    let [t0, t1] = bottom.boundary.inner.map(|point| point.t);
+    let [s0, s1] = top.boundary.inner.map(|point| point.t);
+
+    let line = |[(t0, p0), (t1, p1)]: [(Scalar, [Scalar; 2]); 2]| {
+        SurfacePath::line_from_points_with_coords([([t0], p0), ([t1], p1)])
+    };
+    let up = CurveBoundary::from([[zero], [one]]);
+
+    let half_edges = [
+        (
+            bottom_curve,
+            a,
+            line([(t0, [u0, zero]), (t1, [u1, zero])]),
+            bottom.boundary,
+        ),
+        (
+            up_curve,
+            b,
+            line([(zero, [u1, zero]), (one, [u1, one])]),
+            up,
+        ),
+        (
+            top_curve,
+            c,
+            line([(s1, [u1, one]), (s0, [u0, one])]),
+            top.boundary.reverse(),
+        ),
+        (
+            down_curve,
+            d,
+            line([(one, [u0, one]), (zero, [u0, zero])]),
+            up.reverse(),
+        ),
+    ]
+    .map(|(curve, vertex, path, boundary)| {
+        let curve = curve.clone().make_path_on_surface(
+            path.clone(),
+            surface.clone(),
+            &mut core.layers.geometry,
+        );
+        new_half_edge(
+            curve,
+            vertex.clone(),
+            HalfEdgeGeom { path, boundary },
+            core,
+        )
+    });
+
+    let region =
+        Region::new(Cycle::new(half_edges).insert(core), []).insert(core);
+    if let Some(color) = color {
+        region.set_color(color, core);
+    }
+
+    Face::new(surface, region)
+}
+
+/// Compute the paths of the ruled surface between two edges
+///
+/// Both paths are parameterized over the same range, which is returned too.
+/// Each point of the range corresponds to a point on each of the edges.
+fn ruled_paths(
+    bottom: &ProfileEdge,
+    top: &ProfileEdge,
+) -> ([GlobalPath; 2], CurveBoundary<Point<1>>) {
+    match (&bottom.global, &top.global) {
+        (
+            GlobalPath::Line(_) | GlobalPath::Spline(_),
+            GlobalPath::Circle(_) | GlobalPath::Ellipse(_),
+        ) => {
+            // Circles and ellipses can't be re-parameterized to an arbitrary
+            // range. Let's use the range of the circle or ellipse instead.
+            let range = top.boundary;
+            ([reparameterize(bottom, range), top.global.clone()], range)
+        }
+        _ => {
+            let range = bottom.boundary;
+            ([bottom.global.clone(), reparameterize(top, range)], range)
+        }
+    }
+}
+
+/// Re-parameterize the path of an edge, so its boundary maps to `range`
+fn reparameterize(
+    edge: &ProfileEdge,
+    range: CurveBoundary<Point<1>>,
+) -> GlobalPath {
+    let [u0, u1] = range.inner.map(|point| point.t);
+    let [t0, t1] = edge.boundary.inner.map(|point| point.t);
+
+    match &edge.global {
+        GlobalPath::Line(_) => {
+            GlobalPath::Line(Line::from_points_with_line_coords(
+                [(u0, t0), (u1, t1)].map(|(u, t)| {
+                    ([u], edge.global.point_from_path_coords([t]))
+                }),
+            ))
+        }
+        GlobalPath::Circle(_) | GlobalPath::Ellipse(_) => {
+            let [du, dt] = [u1 - u0, t1 - t0];
+
+            // Circles and ellipses are parameterized by angle, which means the
+            // only re-parameterizations that leave them circles or ellipses,
+            // are shifting and mirroring the angle.
+            if (du.abs() - dt.abs()).abs() > Scalar::from(1e-9) {
+                todo!(
+                    "Lofting between arcs of different angles is not \
+                    supported yet"
+                )
+            }
+            let k = if du.sign() == dt.sign() {
+                Scalar::ONE
+            } else {
+                -Scalar::ONE
+            };
+
+            // The circle coordinate that corresponds to a range coordinate of
+            // zero.
+            let offset = t0 - k * u0;
+            if k == Scalar::ONE && offset == Scalar::ZERO {
+                return edge.global.clone();
+            }
+
+            let (sin, cos) = offset.sin_cos();
+            let shift = |a: Vector<3>, b: Vector<3>| {
+                [a * cos + b * sin, (b * cos - a * sin) * k]
+            };
+
+            match &edge.global {
+                GlobalPath::Circle(circle) => {
+                    let [a, b] = shift(circle.a(), circle.b());
+                    GlobalPath::Circle(Circle::new(circle.center(), a, b))
+                }
+                GlobalPath::Ellipse(ellipse) => {
+                    let [a, b] = shift(ellipse.a(), ellipse.b());
+                    GlobalPath::Ellipse(Ellipse::new(ellipse.center(), a, b))
+                }
+                _ => unreachable!("Path has been matched as circle or ellipse"),
+            }
+        }
+        GlobalPath::Spline(spline) => GlobalPath::Spline(
+            spline.clone().reparameterize([t0, t1], [u0, u1]),
+        ),
+    }
+}
+
+fn new_half_edge(
+    curve: Handle<Curve>,
+    start_vertex: Handle<Vertex>,
+    geometry: HalfEdgeGeom,
+    core: &mut Core,
+) -> Handle<HalfEdge> {
+    HalfEdge::new(curve, start_vertex)
+        .insert(core)
+        .set_geometry(geometry, &mut core.layers.geometry)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        geometry::{CurveBoundary, HalfEdgeGeom, SurfacePath},
+        operations::{
+            build::{BuildHalfEdge, BuildRegion, BuildSketch},
+            geometry::UpdateHalfEdgeGeometry,
+            insert::Insert,
+            transform::TransformObject,
+            update::UpdateSketch,
+        },
+        storage::Handle,
+        topology::{Cycle, HalfEdge, Region, Sketch, Surface},
+        Core,
+    };
+
+    use super::LoftSketches;
+
+    #[test]
+    fn loft_square_to_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let bottom = square(2., &mut core);
+        let top = square(1., &mut core);
+        let [bottom_surface, top_surface] = xy_planes([0., 1.], &mut core);
+
+        let shell = [(bottom, bottom_surface), (top, top_surface)]
+            .loft_sketches(&mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 6);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loft_in_negative_direction() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let bottom = square(2., &mut core);
+        let top = square(1., &mut core);
+        let [bottom_surface, top_surface] = xy_planes([0., -1.], &mut core);
+
+        let shell = [(bottom, bottom_surface), (top, top_surface)]
+            .loft_sketches(&mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loft_twisted_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let bottom = square(2., &mut core);
+        let top =
+            polygon([[0., -1.], [1., 0.], [0., 1.], [-1., 0.]], &mut core);
+        let [bottom_surface, top_surface] = xy_planes([0., 1.], &mut core);
+
+        let shell = [(bottom, bottom_surface), (top, top_surface)]
+            .loft_sketches(&mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        // The side faces are not planar. Make sure they can be approximated
+        // anyway.
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loft_through_multiple_sketches() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let [a, b, c] = [1., 0.5, 1.].map(|radius| circle(radius, &mut core));
+        let [surface_a, surface_b] = xy_planes([0., 1.], &mut core);
+        let [surface_c, _] = xy_planes([2., 3.], &mut core);
+
+        let shell = [(a, surface_a), (b, surface_b), (c, surface_c)]
+            .loft_sketches(&mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 4);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loft_round_to_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // A circle through the corners of the square, made up of four arcs,
+        // so its half-edges correspond to the sides of the square.
+        let round = {
+            let path = SurfacePath::circle_from_center_and_radius(
+                [0., 0.],
+                Scalar::TWO.into_f64().sqrt(),
+            );
+            let half_edges = (0..4)
+                .map(|i| {
+                    let start = Scalar::PI / 4. * (2 * i - 1) as f64;
+                    let boundary = CurveBoundary::from([
+                        [start],
+                        [start + Scalar::PI / 2.],
+                    ]);
+
+                    HalfEdge::unjoined(&mut core)
+                        .insert(&mut core)
+                        .set_geometry(
+                            HalfEdgeGeom {
+                                path: path.clone(),
+                                boundary,
+                            },
+                            &mut core.layers.geometry,
+                        )
+                })
+                .collect::<Vec<_>>();
+            let exterior = Cycle::new(half_edges).insert(&mut core);
+
+            Sketch::empty(&core.layers.topology)
+                .add_regions([Region::new(exterior, [])], &mut core)
+        };
+        let square =
+            polygon([[1., -1.], [1., 1.], [-1., 1.], [-1., -1.]], &mut core);
+        let [bottom_surface, top_surface] = xy_planes([0., 2.], &mut core);
+
+        let shell = [(round, bottom_surface), (square, top_surface)]
+            .loft_sketches(&mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 6);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loft_circle_to_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let bottom = circle(Scalar::TWO.into_f64().sqrt(), &mut core);
+        let top = square(2., &mut core);
+        let [bottom_surface, top_surface] = xy_planes([0., 2.], &mut core);
+
+        let shell = [(bottom, bottom_surface), (top, top_surface)]
+            .loft_sketches(&mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        // The circle is split at the corners of the square. The side of the
+        // square, that's closest to the start of the circle, is split there.
+        assert_eq!(shell.faces().len(), 7);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loft_triangle_to_square() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let bottom = polygon([[-1., -1.], [1., -1.], [0., 1.]], &mut core);
+        let top = square(2., &mut core);
+        let [bottom_surface, top_surface] = xy_planes([0., 1.], &mut core);
+
+        let shell = [(bottom, bottom_surface), (top, top_surface)]
+            .loft_sketches(&mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        // The vertices of both sketches are located at different fractions of
+        // their length, except for the shared first one.
+        assert_eq!(shell.faces().len(), 2 + 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn loft_through_sketches_with_different_edges() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let a = square(2., &mut core);
+        let b = circle(1., &mut core);
+        let c = polygon([[-1., -1.], [1., -1.], [0., 1.]], &mut core);
+        let [surface_a, surface_b] = xy_planes([0., 1.], &mut core);
+        let [surface_c, _] = xy_planes([2., 3.], &mut core);
+
+        let shell = [(a, surface_a), (b, surface_b), (c, surface_c)]
+            .loft_sketches(&mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    fn square(size: f64, core: &mut Core) -> Sketch {
+        let half = size / 2.;
+        polygon(
+            [[-half, -half], [half, -half], [half, half], [-half, half]],
+            core,
+        )
+    }
+
+    fn polygon<const N: usize>(
+        points: [[f64; 2]; N],
+        core: &mut Core,
+    ) -> Sketch {
+        let surface = core.layers.topology.surfaces.space_2d();
+        let region = Region::polygon(points, surface, core);
+        Sketch::empty(&core.layers.topology).add_regions([region], core)
+    }
+
+    fn circle(radius: f64, core: &mut Core) -> Sketch {
+        let surface = core.layers.topology.surfaces.space_2d();
+        let region = Region::circle([0., 0.], radius, surface, core);
+        Sketch::empty(&core.layers.topology).add_regions([region], core)
+    }
+
+    fn xy_planes(heights: [f64; 2], core: &mut Core) -> [Handle<Surface>; 2] {
+        heights.map(|height| {
+            core.layers
+                .topology
+                .surfaces
+                .xy_plane()
+                .translate([0., 0., height], core)
+        })
+    }
+}
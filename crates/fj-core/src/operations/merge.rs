@@ -5,17 +5,23 @@
 
 use crate::{topology::Solid, Core};
 
-use super::update::UpdateSolid;
+use super::boolean::Union;
 
 /// Merge two [`Solid`]s
 pub trait Merge {
     /// Merge this solid with another
+    ///
+    /// This computes the [`Union`] of both solids. Where they overlap, their
+    /// shells are combined into one. Shells that don't touch the other solid
+    /// are re-used as-is.
+    ///
+    /// [`Union`]: super::boolean::Union
     #[must_use]
     fn merge(&self, other: &Self, core: &mut Core) -> Self;
 }
 
 impl Merge for Solid {
     fn merge(&self, other: &Self, core: &mut Core) -> Self {
-        self.add_shells(other.shells().iter().cloned(), core)
+        self.union(other, core)
     }
 }
@@ -38,16 +38,30 @@
 //! assume that the code in question is outdated. Feel free to open an issue or
 //! send a pull request!
 
+mod blend;
+mod imprint;
+
+#[cfg(test)]
+pub(crate) mod fixtures;
+
+pub mod boolean;
 pub mod build;
+pub mod chamfer;
 pub mod derive;
+pub mod fillet;
 pub mod geometry;
 pub mod holes;
+pub mod hollow;
 pub mod insert;
 pub mod join;
+pub mod loft;
 pub mod merge;
+pub mod pattern;
 pub mod presentation;
 pub mod replace;
 pub mod reverse;
+pub mod revolve;
+pub mod section;
 pub mod split;
 pub mod sweep;
 pub mod transform;
@@ -0,0 +1,258 @@
+//! # Operations to repeat objects in a pattern
+//!
+//! See [`Pattern`], which defines where the copies of an object go, and
+//! [`Repeat`], which creates those copies.
+//!
+//! To repeat holes in a pattern, see [`AddHole`].
+//!
+//! [`AddHole`]: super::holes::AddHole
+
+use fj_math::{Point, Scalar, Transform, Vector};
+
+use crate::{
+    topology::{Face, Solid},
+    Core,
+};
+
+use super::{merge::Merge, transform::TransformObject};
+
+/// A pattern that objects can be repeated in
+///
+/// A pattern is a list of transforms, one for each copy of the object. The
+/// first of those is always the identity transform, meaning the original object
+/// is always part of the pattern.
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    transforms: Vec<Transform>,
+}
+
+impl Pattern {
+    /// Construct a pattern that repeats objects along a straight line
+    ///
+    /// Each copy is offset from the previous one by `offset`. `count` is the
+    /// total number of copies, including the original.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `count` is zero.
+    pub fn linear(offset: impl Into<Vector<3>>, count: usize) -> Self {
+        assert!(count > 0, "A pattern must include at least one copy");
+
+        let offset = offset.into();
+
+        let transforms = (0..count)
+            .map(|i| Transform::translation(offset * index_to_scalar(i)))
+            .collect();
+
+        Self { transforms }
+    }
+
+    /// Construct a pattern that repeats objects around an axis
+    ///
+    /// The axis passes through `center` and points in the direction of `axis`.
+    /// The copies are spaced evenly over a full turn around the axis, in the
+    /// counter-clockwise direction, when looking down the axis. `count` is the
+    /// total number of copies, including the original.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `count` is zero, or if `axis` is the zero vector.
+    pub fn circular(
+        center: impl Into<Point<3>>,
+        axis: impl Into<Vector<3>>,
+        count: usize,
+    ) -> Self {
+        assert!(count > 0, "A pattern must include at least one copy");
+
+        let center = center.into();
+        let axis = axis.into();
+
+        assert!(axis.magnitude() != Scalar::ZERO, "Axis must not be zero");
+        let axis = axis.normalize();
+
+        let transforms = (0..count)
+            .map(|i| {
+                let angle =
+                    Scalar::TAU * index_to_scalar(i) / index_to_scalar(count);
+
+                Transform::translation(center.coords)
+                    * Transform::rotation(axis * angle)
+                    * Transform::translation(-center.coords)
+            })
+            .collect();
+
+        Self { transforms }
+    }
+
+    /// Construct a pattern that repeats objects in a grid
+    ///
+    /// This is the combination of two linear patterns. See
+    /// [`Pattern::linear`] and [`Pattern::combine`].
+    pub fn grid(
+        [offset_a, offset_b]: [impl Into<Vector<3>>; 2],
+        [count_a, count_b]: [usize; 2],
+    ) -> Self {
+        Self::linear(offset_a, count_a)
+            .combine(&Self::linear(offset_b, count_b))
+    }
+
+    /// Combine this pattern with another one
+    ///
+    /// The resulting pattern has a copy for each combination of a copy from
+    /// this pattern, and a copy from the other one. The transforms of the other
+    /// pattern are applied after those of this one.
+    #[must_use]
+    pub fn combine(&self, other: &Self) -> Self {
+        let transforms = other
+            .transforms
+            .iter()
+            .flat_map(|&b| self.transforms.iter().map(move |&a| b * a))
+            .collect();
+
+        Self { transforms }
+    }
+
+    /// Access the transforms that define the pattern
+    ///
+    /// There is one transform for each copy. The first one is always the
+    /// identity transform.
+    pub fn transforms(&self) -> impl Iterator<Item = &Transform> {
+        self.transforms.iter()
+    }
+}
+
+/// Repeat an object in a [`Pattern`]
+pub trait Repeat {
+    /// The result of the operation
+    type Repeated;
+
+    /// Repeat the object in the provided pattern
+    ///
+    /// The original object is the first copy in the pattern, and is re-used
+    /// as-is. Each other copy is transformed separately, meaning objects that
+    /// are shared within the original object, are also shared within each
+    /// copy.
+    #[must_use]
+    fn repeat(&self, pattern: &Pattern, core: &mut Core) -> Self::Repeated;
+}
+
+impl Repeat for Solid {
+    /// A solid that contains the shells of all copies
+    ///
+    /// Where the copies overlap, they are combined. See [`Merge`].
+    type Repeated = Solid;
+
+    fn repeat(&self, pattern: &Pattern, core: &mut Core) -> Self::Repeated {
+        let mut repeated = self.clone();
+
+        for transform in pattern.transforms().skip(1) {
+            let copy = self.transform(transform, core);
+            repeated = repeated.merge(&copy, core);
+        }
+
+        repeated
+    }
+}
+
+impl Repeat for Face {
+    /// All copies of the face, starting with the original
+    type Repeated = Vec<Face>;
+
+    fn repeat(&self, pattern: &Pattern, core: &mut Core) -> Self::Repeated {
+        let mut repeated = vec![self.clone()];
+
+        for transform in pattern.transforms().skip(1) {
+            repeated.push(self.transform(transform, core));
+        }
+
+        repeated
+    }
+}
+
+fn index_to_scalar(index: usize) -> Scalar {
+    Scalar::from_u64(index as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        algorithms::bounding_volume::BoundingVolume,
+        operations::{fixtures::cuboid, insert::Insert},
+        Core,
+    };
+
+    use super::{Pattern, Repeat};
+
+    #[test]
+    fn circular_pattern() {
+        let pattern = Pattern::circular([1., 1., 0.], [0., 0., 2.], 4);
+
+        let points = pattern
+            .transforms()
+            .map(|transform| {
+                transform.transform_point(&Point::from([2., 1., 3.]))
+            })
+            .collect::<Vec<_>>();
+
+        let expected = [[2., 1., 3.], [1., 2., 3.], [0., 1., 3.], [1., 0., 3.]];
+        assert_eq!(points.len(), expected.len());
+        for (point, expected) in points.into_iter().zip(expected) {
+            assert!(
+                (point - Point::from(expected)).magnitude()
+                    < Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn grid_pattern() {
+        let pattern = Pattern::grid([[1., 0., 0.], [0., 2., 0.]], [3, 2]);
+
+        let offsets = pattern
+            .transforms()
+            .map(|transform| transform.transform_point(&Point::origin()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            offsets,
+            [
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [2., 0., 0.],
+                [0., 2., 0.],
+                [1., 2., 0.],
+                [2., 2., 0.],
+            ]
+            .map(Point::from)
+        );
+    }
+
+    #[test]
+    fn repeat_solid() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = cuboid([0., 0., 0.], [1.; 3], &mut core);
+        let repeated = solid
+            .repeat(&Pattern::linear([2., 0., 0.], 3), &mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(repeated.shells().len(), 3);
+        assert_eq!(repeated.shells().first(), solid.shells().first());
+
+        let mins = repeated
+            .shells()
+            .iter()
+            .map(|shell| shell.aabb(&core.layers.geometry).map(|aabb| aabb.min))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            mins,
+            [[0., 0., 0.], [2., 0., 0.], [4., 0., 0.]]
+                .map(|min| Some(Point::from(min)))
+        );
+
+        Ok(())
+    }
+}
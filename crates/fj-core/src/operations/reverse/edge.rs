@@ -9,7 +9,8 @@ use super::ReverseCurveCoordinateSystems;
 
 impl ReverseCurveCoordinateSystems for Handle<HalfEdge> {
     fn reverse_curve_coordinate_systems(&self, core: &mut Core) -> Self {
-        let mut half_edge_geom = *core.layers.geometry.of_half_edge(self);
+        let mut half_edge_geom =
+            core.layers.geometry.of_half_edge(self).clone();
         half_edge_geom.path = half_edge_geom.path.reverse();
         half_edge_geom.boundary = half_edge_geom.boundary.reverse();
 
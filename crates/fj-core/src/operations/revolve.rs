@@ -0,0 +1,967 @@
+//! Revolve a [`Sketch`] around an axis
+//!
+//! See [`RevolveSketch`].
+
+use fj_interop::Color;
+use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+
+use crate::{
+    geometry::{
+        CurveBoundary, GlobalPath, HalfEdgeGeom, Helix, SurfaceGeom,
+        SurfacePath, SweepPath,
+    },
+    operations::{
+        build::BuildSurface,
+        derive::DeriveFrom,
+        geometry::{UpdateCurveGeometry, UpdateHalfEdgeGeometry},
+        insert::Insert,
+        presentation::{GetColor, SetColor},
+        reverse::Reverse,
+        sweep::{SweepCache, SweepVertex},
+        transform::TransformObject,
+    },
+    storage::Handle,
+    topology::{
+        Curve, Cycle, Face, HalfEdge, Region, Shell, Sketch, Solid, Surface,
+        Vertex,
+    },
+    Core,
+};
+
+/// # Revolve a [`Sketch`]
+///
+/// See [module documentation] for more information.
+///
+/// [module documentation]: self
+pub trait RevolveSketch {
+    /// # Revolve the [`Sketch`] around an axis
+    ///
+    /// The sketch is placed on the provided surface, which must be a plane.
+    /// The axis is defined in the coordinates of that surface, and the sketch
+    /// must be located completely on one side of it. Edges of the sketch may
+    /// lie on the axis.
+    ///
+    /// The angle is given in radians. Angles of 2π or larger result in a full
+    /// revolution, which creates a solid without any planar caps. Negative
+    /// angles revolve the sketch in the opposite direction.
+    ///
+    /// Edges are revolved into cylinders or planes, if they are straight lines
+    /// that are parallel or perpendicular to the axis. All other edges are
+    /// revolved into surfaces of revolution, whose u-axis is the edge. Other
+    /// straight lines result in cones, arcs result in tori or spheres.
+    fn revolve_sketch(
+        &self,
+        surface: Handle<Surface>,
+        axis: Line<2>,
+        angle: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Solid;
+}
+
+impl RevolveSketch for Sketch {
+    fn revolve_sketch(
+        &self,
+        surface: Handle<Surface>,
+        axis: Line<2>,
+        angle: impl Into<Scalar>,
+        core: &mut Core,
+    ) -> Solid {
+        let angle = angle.into();
+        assert_ne!(angle, Scalar::ZERO, "Can't revolve sketch by zero angle");
+
+        let (axis, angle) = if angle < Scalar::ZERO {
+            let axis = Line::from_origin_and_direction(
+                axis.origin(),
+                -axis.direction(),
+            );
+            (axis, -angle)
+        } else {
+            (axis, angle)
+        };
+        let angle = if angle >= Scalar::TAU {
+            Angle::Full
+        } else {
+            Angle::Partial(angle)
+        };
+
+        let axis = Axis::new(self, &surface, axis, angle, core);
+        let mut cache = SweepCache::default();
+
+        let mut shells = Vec::new();
+        for region in self.regions() {
+            // The following code assumes that the sketch is winded counter-
+            // clockwise. Let's check that real quick.
+            assert!(region.exterior().winding(&core.layers.geometry).is_ccw());
+
+            let color = region.get_color(core);
+
+            // The bottom face is the face at the start of the revolution. It
+            // needs to face away from the direction of the revolution.
+            let bottom_region = {
+                let normal = {
+                    let surface = core.layers.geometry.of_surface(&surface);
+                    let SurfaceGeom::Swept {
+                        u: GlobalPath::Line(u),
+                        v: SweepPath::Line(v),
+                    } = *surface
+                    else {
+                        unreachable!("Checked that surface is a plane above")
+                    };
+                    u.direction().cross(&v)
+                };
+
+                if normal.dot(&axis.tangential) < Scalar::ZERO {
+                    region.clone()
+                } else {
+                    region.reverse(core).insert(core).derive_from(region, core)
+                }
+            };
+
+            let mut faces = Vec::new();
+            let mut top_cycles = Vec::new();
+
+            for bottom_cycle in bottom_region.all_cycles() {
+                let cycle = bottom_cycle.reverse(core);
+
+                let mut top_edges = Vec::new();
+
+                for (half_edge, next) in cycle.half_edges().pairs() {
+                    let revolved = revolve_half_edge(
+                        half_edge,
+                        next.start_vertex().clone(),
+                        &surface,
+                        &axis,
+                        color,
+                        &mut cache,
+                        core,
+                    );
+
+                    faces.extend(revolved.face);
+                    top_edges.extend(revolved.top_edge);
+                }
+
+                top_cycles.push(Cycle::new(top_edges).insert(core));
+            }
+
+            if let Angle::Partial(angle) = angle {
+                let bottom_face =
+                    Face::new(surface.clone(), bottom_region.clone());
+
+                let top_face = {
+                    let top_surface =
+                        surface.transform(&axis.rotation(angle), core);
+
+                    let mut top_cycles = top_cycles.into_iter();
+                    let top_region = Region::new(
+                        top_cycles.next().expect("Region has exterior"),
+                        top_cycles,
+                    )
+                    .insert(core);
+                    if let Some(color) = color {
+                        top_region.set_color(color, core);
+                    }
+
+                    Face::new(top_surface, top_region)
+                };
+
+                faces.extend([bottom_face, top_face]);
+            }
+
+            let faces = faces.into_iter().map(|face| face.insert(core));
+            shells.push(Shell::new(faces).insert(core));
+        }
+
+        Solid::new(shells)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Angle {
+    Full,
+    Partial(Scalar),
+}
+
+impl Angle {
+    fn value(&self) -> Scalar {
+        match self {
+            Self::Full => Scalar::TAU,
+            Self::Partial(angle) => *angle,
+        }
+    }
+}
+
+/// The axis and angle of a revolution, and a coordinate system around the axis
+struct Axis {
+    origin: Point<3>,
+
+    /// The direction of the axis, which is the axis of the rotation
+    direction: Vector<3>,
+
+    /// The direction from the axis towards the sketch, within its plane
+    radial: Vector<3>,
+
+    /// The direction that the sketch is moving in, when starting to revolve
+    tangential: Vector<3>,
+
+    /// The angle of the revolution
+    angle: Angle,
+
+    /// Radii below this value are considered to be located on the axis
+    epsilon: Scalar,
+}
+
+impl Axis {
+    fn new(
+        sketch: &Sketch,
+        surface: &Handle<Surface>,
+        axis: Line<2>,
+        angle: Angle,
+        core: &mut Core,
+    ) -> Self {
+        let geometry = &core.layers.geometry;
+        let surface = geometry.of_surface(surface);
+
+        let SurfaceGeom::Swept {
+            u: GlobalPath::Line(u),
+            v: SweepPath::Line(v),
+        } = *surface
+        else {
+            todo!("Revolving a sketch on a curved surface is not supported")
+        };
+        let normal = u.direction().cross(&v).normalize();
+
+        let origin = surface.point_from_surface_coords(axis.origin());
+        let direction = surface
+            .vector_from_surface_coords(axis.direction())
+            .normalize();
+        let epsilon = core.layers.validation.config.distinct_min_distance;
+
+        // Figure out on which side of the axis the sketch is located. Besides
+        // the vertices, this considers the middle of each half-edge, as curved
+        // half-edges might bulge away from an axis that their vertices are on.
+        let perpendicular = normal.cross(&direction).normalize();
+        let (min, max) = sketch
+            .regions()
+            .iter()
+            .flat_map(|region| region.all_cycles())
+            .flat_map(|cycle| cycle.half_edges())
+            .flat_map(|half_edge| {
+                let half_edge = geometry.of_half_edge(half_edge);
+                let [start, end] = half_edge.boundary.inner;
+                let middle = start + (end - start) / 2.;
+
+                [start, middle]
+                    .map(|point| half_edge.path.point_from_path_coords(point))
+            })
+            .map(|point| {
+                let point = surface.point_from_surface_coords(point);
+                (point - origin).dot(&perpendicular)
+            })
+            .fold((Scalar::ZERO, Scalar::ZERO), |(min, max), distance| {
+                (min.min(distance), max.max(distance))
+            });
+
+        let radial = if min > -epsilon && max > epsilon {
+            perpendicular
+        } else if max < epsilon && min < -epsilon {
+            -perpendicular
+        } else {
+            panic!("Sketch must be located on exactly one side of the axis");
+        };
+        let tangential = direction.cross(&radial);
+
+        Self {
+            origin,
+            direction,
+            radial,
+            tangential,
+            angle,
+            epsilon,
+        }
+    }
+
+    /// Compute the radius and height of a point, relative to the axis
+    fn cylindrical_coords(&self, point: Point<3>) -> (Scalar, Scalar) {
+        let radius = (point - self.origin).dot(&self.radial);
+        let height = (point - self.origin).dot(&self.direction);
+
+        let radius = if radius < self.epsilon {
+            Scalar::ZERO
+        } else {
+            radius
+        };
+
+        (radius, height)
+    }
+
+    /// The circle that a point with the given coordinates moves along
+    fn circle(&self, radius: Scalar, height: Scalar) -> Circle<3> {
+        Circle::new(
+            self.origin + self.direction * height,
+            self.radial * radius,
+            self.tangential * radius,
+        )
+    }
+
+    /// The plane that is perpendicular to the axis at the given height
+    ///
+    /// The coordinates of the plane are chosen such, that a point at angle 0
+    /// and the given radius is located at `[radius, 0]`.
+    fn plane(&self, height: Scalar, core: &mut Core) -> Handle<Surface> {
+        Surface::from_uv(
+            GlobalPath::Line(Line::from_origin_and_direction(
+                self.origin + self.direction * height,
+                self.radial,
+            )),
+            self.tangential,
+            core,
+        )
+    }
+
+    /// The helix that moves points along the revolution
+    fn helix(&self) -> Helix {
+        Helix::new(
+            Line::from_origin_and_direction(self.origin, self.direction),
+            self.angle.value(),
+            Scalar::ZERO,
+        )
+    }
+
+    /// The rotation around the axis by the given angle
+    fn rotation(&self, angle: Scalar) -> Transform {
+        Transform::translation(self.origin.coords)
+            * Transform::rotation(self.direction * angle)
+            * Transform::translation(-self.origin.coords)
+    }
+}
+
+struct RevolvedHalfEdge {
+    /// The face that the half-edge was revolved into, if any
+    ///
+    /// Half-edges that are located on the axis don't result in a face.
+    face: Option<Face>,
+
+    /// The half-edge that bounds the top face, if the revolution is partial
+    top_edge: Option<Handle<HalfEdge>>,
+}
+
+fn revolve_half_edge(
+    half_edge: &Handle<HalfEdge>,
+    end_vertex: Handle<Vertex>,
+    surface: &Handle<Surface>,
+    axis: &Axis,
+    color: Option<Color>,
+    cache: &mut SweepCache,
+    core: &mut Core,
+) -> RevolvedHalfEdge {
+    let half_edge_geom = core.layers.geometry.of_half_edge(half_edge).clone();
+    let surface_geom = core.layers.geometry.of_surface(surface).clone();
+
+    let [t0, t1] = half_edge_geom.boundary.inner;
+    let [(ra, ha), (rb, hb)] = [t0, t1].map(|point_curve| {
+        let point_surface =
+            half_edge_geom.path.point_from_path_coords(point_curve);
+        let point = surface_geom.point_from_surface_coords(point_surface);
+        axis.cylindrical_coords(point)
+    });
+
+    let [a, b] = [half_edge.start_vertex().clone(), end_vertex];
+
+    // Sweep the vertices of the half-edge. Vertices on the axis don't move,
+    // and in a full revolution, the vertices end up where they started.
+    let mut sweep_vertex = |vertex: &Handle<Vertex>, radius: Scalar| {
+        if radius == Scalar::ZERO {
+            return (None, vertex.clone());
+        }
+
+        let (curve, end_vertex) = vertex.sweep_vertex(cache, core);
+        let end_vertex = match axis.angle {
+            Angle::Full => vertex.clone(),
+            Angle::Partial(_) => end_vertex,
+        };
+
+        (Some(curve), end_vertex)
+    };
+    let (curve_a, a_top) = sweep_vertex(&a, ra);
+    let (curve_b, b_top) = sweep_vertex(&b, rb);
+
+    let top_edge_for_cap =
+        |curve: Handle<Curve>, core: &mut Core| match axis.angle {
+            Angle::Full => None,
+            Angle::Partial(_) => Some(new_half_edge(
+                curve,
+                a_top.clone(),
+                half_edge_geom.clone(),
+                core,
+            )),
+        };
+
+    // Only straight edges are located on the axis completely, if their
+    // vertices are. A curved edge, like an arc that ends on the axis, bulges
+    // away from it in between.
+    let is_line = matches!(half_edge_geom.path, SurfacePath::Line(_));
+
+    let is_on_axis = is_line && ra == Scalar::ZERO && rb == Scalar::ZERO;
+    if is_on_axis {
+        // The half-edge doesn't move. It ends up in the bottom and top faces,
+        // unchanged.
+        let top_edge = top_edge_for_cap(half_edge.curve().clone(), core);
+        return RevolvedHalfEdge {
+            face: None,
+            top_edge,
+        };
+    }
+
+    let is_parallel = is_line && (ra - rb).abs() < axis.epsilon;
+    let is_perpendicular = is_line && (ha - hb).abs() < axis.epsilon;
+
+    let angle_value = axis.angle.value();
+    let arc_boundaries = [
+        CurveBoundary::from([[Scalar::ZERO], [angle_value]]),
+        CurveBoundary::from([[angle_value], [Scalar::ZERO]]),
+    ];
+
+    // The paths of the half-edges in the new face, in the order bottom, up,
+    // top, down.
+    let (face_surface, paths) = if is_parallel {
+        // The half-edge is revolved into a cylinder. The cylinder's surface
+        // coordinates are the angle of the revolution (u) and the position
+        // along the half-edge (v).
+        let surface = Surface::from_uv(
+            GlobalPath::Circle(axis.circle(ra, ha)),
+            axis.direction * (hb - ha),
+            core,
+        );
+
+        let line = |[(t0, p0), (t1, p1)]: [(Scalar, [Scalar; 2]); 2]| {
+            Some(SurfacePath::line_from_points_with_coords([
+                ([t0], p0),
+                ([t1], p1),
+            ]))
+        };
+        let zero = Scalar::ZERO;
+        let one = Scalar::ONE;
+        let u = angle_value;
+
+        let paths = [
+            line([(t0.t, [zero, zero]), (t1.t, [zero, one])]),
+            line([(zero, [zero, one]), (u, [u, one])]),
+            line([(t1.t, [u, one]), (t0.t, [u, zero])]),
+            line([(u, [u, zero]), (zero, [zero, zero])]),
+        ];
+
+        (surface, paths)
+    } else if is_perpendicular {
+        // The half-edge is revolved into a plane (or part of one) that is
+        // perpendicular to the axis.
+        let surface = axis.plane(ha, core);
+
+        let (sin, cos) = angle_value.sin_cos();
+        let arc = |radius: Scalar| {
+            (radius != Scalar::ZERO).then(|| {
+                SurfacePath::Circle(Circle::from_center_and_radius(
+                    [Scalar::ZERO, Scalar::ZERO],
+                    radius,
+                ))
+            })
+        };
+
+        let paths = [
+            Some(SurfacePath::line_from_points_with_coords([
+                ([t0.t], [ra, Scalar::ZERO]),
+                ([t1.t], [rb, Scalar::ZERO]),
+            ])),
+            arc(rb),
+            Some(SurfacePath::line_from_points_with_coords([
+                ([t1.t], [rb * cos, rb * sin]),
+                ([t0.t], [ra * cos, ra * sin]),
+            ])),
+            arc(ra),
+        ];
+
+        (surface, paths)
+    } else {
+        // Any other half-edge is revolved into a surface of revolution. Its
+        // u-axis is the path of the half-edge, which makes it a cone, if the
+        // path is a line, and a torus or sphere, if it is a circle. The
+        // v-axis goes around the axis of the revolution.
+        let surface = Surface::from_uv(
+            surface_geom.path_from_surface_coords(half_edge_geom.path.clone()),
+            axis.helix(),
+            core,
+        );
+
+        let line = |[(t0, p0), (t1, p1)]: [(Scalar, [Scalar; 2]); 2]| {
+            Some(SurfacePath::line_from_points_with_coords([
+                ([t0], p0),
+                ([t1], p1),
+            ]))
+        };
+        let zero = Scalar::ZERO;
+        let one = Scalar::ONE;
+        let u = angle_value;
+
+        let paths = [
+            line([(t0.t, [t0.t, zero]), (t1.t, [t1.t, zero])]),
+            line([(zero, [t1.t, zero]), (u, [t1.t, one])]),
+            line([(t1.t, [t1.t, one]), (t0.t, [t0.t, one])]),
+            line([(u, [t0.t, one]), (zero, [t0.t, zero])]),
+        ];
+
+        (surface, paths)
+    };
+
+    let [bottom, up, top, down] = paths;
+
+    let new_edge = |curve: Handle<Curve>,
+                    start_vertex: &Handle<Vertex>,
+                    path: Option<SurfacePath>,
+                    boundary: CurveBoundary<Point<1>>,
+                    core: &mut Core| {
+        path.map(|path| {
+            new_half_edge(
+                curve,
+                start_vertex.clone(),
+                HalfEdgeGeom { path, boundary },
+                core,
+            )
+        })
+    };
+    let [arc_up, arc_down] = arc_boundaries;
+
+    let (cycles, top_edge) = match axis.angle {
+        Angle::Partial(_) => {
+            let top_curve = cache
+                .end_curves
+                .entry(half_edge.curve().id())
+                .or_insert_with(|| Curve::new().insert(core))
+                .clone();
+            if let Some(path) = &top {
+                top_curve.clone().make_path_on_surface(
+                    path.clone(),
+                    face_surface.clone(),
+                    &mut core.layers.geometry,
+                );
+            }
+
+            let half_edges = [
+                new_edge(
+                    half_edge.curve().clone(),
+                    &a,
+                    bottom,
+                    half_edge_geom.boundary,
+                    core,
+                ),
+                curve_b.and_then(|curve| new_edge(curve, &b, up, arc_up, core)),
+                new_edge(
+                    top_curve.clone(),
+                    &b_top,
+                    top,
+                    half_edge_geom.boundary.reverse(),
+                    core,
+                ),
+                curve_a.and_then(|curve| {
+                    new_edge(curve, &a_top, down, arc_down, core)
+                }),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+            (vec![half_edges], top_edge_for_cap(top_curve, core))
+        }
+        Angle::Full if !is_perpendicular => {
+            let half_edges = [
+                new_edge(
+                    half_edge.curve().clone(),
+                    &a,
+                    bottom,
+                    half_edge_geom.boundary,
+                    core,
+                ),
+                curve_b.and_then(|curve| new_edge(curve, &b, up, arc_up, core)),
+                new_edge(
+                    half_edge.curve().clone(),
+                    &b,
+                    top,
+                    half_edge_geom.boundary.reverse(),
+                    core,
+                ),
+                curve_a.and_then(|curve| {
+                    new_edge(curve, &a, down, arc_down, core)
+                }),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+            (vec![half_edges], None)
+        }
+        Angle::Full => {
+            // A full revolution of an edge that is perpendicular to the axis
+            // results in a disc or an annulus. Its boundary consists of the
+            // full circles that the vertices moved along.
+            let circle_b =
+                curve_b.and_then(|curve| new_edge(curve, &b, up, arc_up, core));
+            let circle_a = curve_a
+                .and_then(|curve| new_edge(curve, &a, down, arc_down, core));
+
+            let mut cycles = [(rb, circle_b), (ra, circle_a)];
+            cycles.sort_by_key(|(radius, _)| -*radius);
+
+            let cycles = cycles
+                .into_iter()
+                .filter_map(|(_, half_edge)| half_edge)
+                .map(|half_edge| vec![half_edge])
+                .collect();
+
+            (cycles, None)
+        }
+    };
+
+    let mut cycles = cycles
+        .into_iter()
+        .map(|half_edges| Cycle::new(half_edges).insert(core));
+    let region = Region::new(
+        cycles.next().expect("Revolved face must have a boundary"),
+        cycles,
+    )
+    .insert(core);
+    if let Some(color) = color {
+        region.set_color(color, core);
+    }
+
+    RevolvedHalfEdge {
+        face: Some(Face::new(face_surface, region)),
+        top_edge,
+    }
+}
+
+fn new_half_edge(
+    curve: Handle<Curve>,
+    start_vertex: Handle<Vertex>,
+    geometry: HalfEdgeGeom,
+    core: &mut Core,
+) -> Handle<HalfEdge> {
+    HalfEdge::new(curve, start_vertex)
+        .insert(core)
+        .set_geometry(geometry, &mut core.layers.geometry)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fj_math::{Line, Scalar};
+
+    use crate::{
+        algorithms::{
+            approx::Tolerance, mass_properties::ComputeMassProperties,
+            triangulate::Triangulate,
+        },
+        geometry::{CurveBoundary, HalfEdgeGeom, SurfacePath},
+        operations::{
+            build::{BuildHalfEdge, BuildRegion, BuildSketch},
+            geometry::UpdateHalfEdgeGeometry,
+            insert::Insert,
+            join::JoinCycle,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        topology::{Cycle, HalfEdge, Region, Sketch, Solid},
+        Core,
+    };
+
+    use super::RevolveSketch;
+
+    #[test]
+    fn partial_revolution() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid =
+            revolve_rectangle([[1., 0.], [2., 1.]], Scalar::PI / 2., &mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_revolution_in_negative_direction() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = revolve_rectangle(
+            [[1., 0.], [2., 1.]],
+            -Scalar::PI / 2.,
+            &mut core,
+        );
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_revolution_touching_axis() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid =
+            revolve_rectangle([[0., 0.], [1., 1.]], Scalar::PI, &mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn full_revolution() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid =
+            revolve_rectangle([[1., 0.], [2., 1.]], Scalar::TAU, &mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn full_revolution_touching_axis() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid =
+            revolve_rectangle([[0., 0.], [1., 1.]], Scalar::TAU, &mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 3);
+
+        // Make sure the curved faces can be approximated.
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*solid, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_revolution_with_shared_edge() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let a = Region::polygon(
+            [[1., 0.], [2., 0.], [2., 1.], [1., 1.]],
+            surface.clone(),
+            &mut core,
+        );
+        let b = Region::polygon(
+            [[2., 0.], [3., 0.], [3., 1.], [2., 1.]],
+            surface,
+            &mut core,
+        )
+        .update_exterior(
+            |exterior, core| exterior.join_to(a.exterior(), 3..=3, 1..=1, core),
+            &mut core,
+        );
+
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions([a, b], &mut core)
+            .revolve_sketch(
+                core.layers.topology.surfaces.xz_plane(),
+                Line::from_points([[0., 0.], [0., 1.]]).0,
+                Scalar::PI / 2.,
+                &mut core,
+            )
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        // The shared edge, its copy at the end of the revolution, and the arcs
+        // that its vertices move along, must be shared between both shells.
+        let [curves_a, curves_b] = [0, 1].map(|i| {
+            solid
+                .shells()
+                .nth(i)
+                .unwrap()
+                .faces()
+                .iter()
+                .flat_map(|face| face.region().all_cycles())
+                .flat_map(|cycle| cycle.half_edges())
+                .map(|half_edge| half_edge.curve().clone())
+                .collect::<BTreeSet<_>>()
+        });
+        assert_eq!(curves_a.intersection(&curves_b).count(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_slanted_edges() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // A right triangle with its hypotenuse slanted towards the axis. It is
+        // revolved into a cylinder with a conical hole.
+        let triangle = [[1., 0.], [2., 0.], [1., 1.]];
+        for (angle, faces) in [(Scalar::PI / 2., 5), (Scalar::TAU, 3)] {
+            let solid = revolve_polygon(triangle, angle, &mut core);
+            core.layers.validation.take_errors()?;
+
+            assert_eq!(solid.shells().only().faces().len(), faces);
+
+            // Pappus's centroid theorem
+            let volume = Scalar::from(0.5) * angle * 4. / 3.;
+            assert_volume(&solid, volume, &core);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_into_cone() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = revolve_polygon(
+            [[0., 0.], [1., 0.], [0., 1.]],
+            Scalar::TAU,
+            &mut core,
+        );
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 2);
+        assert_volume(&solid, Scalar::PI / 3., &core);
+
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_into_sphere() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // A half-disk, whose straight edge is located on the axis.
+        let half_disk = {
+            let path = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+            let [a, b] = [[-0.5, 0.], [0., 0.5]].map(|range| {
+                let boundary =
+                    CurveBoundary::from(range.map(|t| [Scalar::PI * t]));
+
+                HalfEdge::unjoined(&mut core)
+                    .insert(&mut core)
+                    .set_geometry(
+                        HalfEdgeGeom {
+                            path: path.clone(),
+                            boundary,
+                        },
+                        &mut core.layers.geometry,
+                    )
+            });
+            let line = HalfEdge::line_segment(
+                [[0., 1.], [0., -1.]],
+                None,
+                core.layers.topology.surfaces.space_2d(),
+                &mut core,
+            )
+            .insert(&mut core);
+
+            let exterior = Cycle::new([a, b, line]).insert(&mut core);
+            Region::new(exterior, [])
+        };
+
+        for (angle, faces) in [(Scalar::PI, 4), (Scalar::TAU, 2)] {
+            let solid = revolve_region(half_disk.clone(), angle, &mut core);
+            core.layers.validation.take_errors()?;
+
+            assert_eq!(solid.shells().only().faces().len(), faces);
+            assert_volume(
+                &solid,
+                Scalar::PI * 2. / 3. * angle / Scalar::PI,
+                &core,
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn revolve_into_torus() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let disk = Region::circle(
+            [2., 0.],
+            1.,
+            core.layers.topology.surfaces.space_2d(),
+            &mut core,
+        );
+
+        for (angle, faces) in [(Scalar::PI / 2., 3), (Scalar::TAU, 1)] {
+            let solid = revolve_region(disk.clone(), angle, &mut core);
+            core.layers.validation.take_errors()?;
+
+            assert_eq!(solid.shells().only().faces().len(), faces);
+
+            // Pappus's centroid theorem
+            assert_volume(&solid, Scalar::PI * angle * 2., &core);
+        }
+
+        Ok(())
+    }
+
+    fn assert_volume(solid: &Solid, volume: Scalar, core: &Core) {
+        let properties = solid.mass_properties(1., &core.layers.geometry);
+        assert!(
+            (properties.volume - volume).abs() < Scalar::from(1e-9),
+            "{:?} != {volume:?}",
+            properties.volume,
+        );
+    }
+
+    fn revolve_polygon(
+        points: [[f64; 2]; 3],
+        angle: Scalar,
+        core: &mut Core,
+    ) -> crate::storage::Handle<Solid> {
+        let region = Region::polygon(
+            points,
+            core.layers.topology.surfaces.space_2d(),
+            core,
+        );
+        revolve_region(region, angle, core)
+    }
+
+    fn revolve_region(
+        region: Region,
+        angle: Scalar,
+        core: &mut Core,
+    ) -> crate::storage::Handle<Solid> {
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let axis = Line::from_points([[0., 0.], [0., 1.]]).0;
+
+        Sketch::empty(&core.layers.topology)
+            .add_regions([region], core)
+            .revolve_sketch(surface, axis, angle, core)
+            .insert(core)
+    }
+
+    fn revolve_rectangle(
+        [min, max]: [[f64; 2]; 2],
+        angle: Scalar,
+        core: &mut Core,
+    ) -> crate::storage::Handle<Solid> {
+        let [[x0, y0], [x1, y1]] = [min, max];
+
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let axis = Line::from_points([[0., 0.], [0., 1.]]).0;
+
+        Sketch::empty(&core.layers.topology)
+            .add_regions(
+                [Region::polygon(
+                    [[x0, y0], [x1, y0], [x1, y1], [x0, y1]],
+                    core.layers.topology.surfaces.space_2d(),
+                    core,
+                )],
+                core,
+            )
+            .revolve_sketch(surface, axis, angle, core)
+            .insert(core)
+    }
+}
@@ -0,0 +1,549 @@
+//! # Compute cross-sections of shapes
+//!
+//! See [`CrossSection`].
+
+use fj_math::{Point, Scalar, Winding};
+
+use crate::{
+    algorithms::{
+        approx::{Approx, Tolerance},
+        intersect::{surface_surface::SurfaceSurfaceIntersection, Intersect},
+        triangulate::polygon::Polygon,
+    },
+    geometry::{
+        Geometry, GlobalPath, HalfEdgeGeom, LocalCurveGeom, SurfaceGeom,
+        SurfacePath, SweepPath,
+    },
+    storage::Handle,
+    topology::{Cycle, Face, HalfEdge, Region, Sketch, Solid, Surface},
+    Core,
+};
+
+use super::{build::BuildHalfEdge, insert::Insert};
+
+/// Compute the cross-section of a shape
+pub trait CrossSection {
+    /// Cut the shape with a plane, returning the cut regions as a [`Sketch`]
+    ///
+    /// `surface` must be a plane. The sketch is defined in the surface
+    /// coordinates of that plane, meaning sweeping it from `surface` results in
+    /// a shape that starts where the cut was made.
+    ///
+    /// The exterior cycle of each region is wound counter-clockwise, its
+    /// interior cycles clockwise, as required by the validation of sketches.
+    ///
+    /// Each face is approximated using the provided tolerance, to find where
+    /// its boundary crosses the plane. Where that boundary is not a straight
+    /// line in surface coordinates, the corners of the cross-section can be
+    /// off by up to the tolerance.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `surface` is not a plane, or if the cross-section's boundary
+    /// isn't closed. The latter can happen, if the plane passes through an
+    /// edge or vertex of the shape, without cutting into its faces.
+    ///
+    /// # Implementation Note
+    ///
+    /// Only shapes made from planar and cylindrical faces are supported so far,
+    /// and cylindrical faces must be cut either along or across their axis.
+    /// Trying to compute any other cross-section results in a panic.
+    #[must_use]
+    fn cross_section(
+        &self,
+        surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Sketch;
+}
+
+impl CrossSection for Solid {
+    fn cross_section(
+        &self,
+        surface: &Handle<Surface>,
+        tolerance: impl Into<Tolerance>,
+        core: &mut Core,
+    ) -> Sketch {
+        let tolerance = tolerance.into();
+
+        let plane = core.layers.geometry.of_surface(surface).clone();
+        assert!(
+            matches!(
+                plane,
+                SurfaceGeom::Swept {
+                    u: GlobalPath::Line(_),
+                    v: SweepPath::Line(_),
+                }
+            ),
+            "Cross-sections can only be computed using planes"
+        );
+
+        let pieces = self
+            .shells()
+            .iter()
+            .flat_map(|shell| shell.faces())
+            .flat_map(|face| {
+                pieces_within_face(
+                    face,
+                    &plane,
+                    tolerance,
+                    &core.layers.geometry,
+                )
+            })
+            .collect();
+        let loops = connect_pieces(pieces, tolerance)
+            .into_iter()
+            .map(Loop::new)
+            .collect::<Vec<_>>();
+
+        // The loops don't intersect each other, so each of them is either the
+        // exterior of a region, or an interior within the region of the
+        // innermost loop that contains it.
+        let containing = loops
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                loops
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, b)| j != i && b.contains(a))
+                    .map(|(j, _)| j)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let depth = |i: usize| containing[i].len();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+
+        let mut regions = Vec::new();
+        for (i, exterior) in loops.iter().enumerate() {
+            if depth(i) % 2 != 0 {
+                continue;
+            }
+
+            let interiors = loops
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| {
+                    depth(j) == depth(i) + 1 && containing[j].contains(&i)
+                })
+                .map(|(_, interior)| {
+                    interior.build_cycle(Winding::Cw, surface.clone(), core)
+                })
+                .collect::<Vec<_>>();
+            let exterior =
+                exterior.build_cycle(Winding::Ccw, surface.clone(), core);
+
+            regions.push(Region::new(exterior, interiors).insert(core));
+        }
+
+        Sketch::new(surface, regions)
+    }
+}
+
+/// A piece of the cross-section's boundary, within a single face
+#[derive(Clone)]
+struct Piece {
+    /// The path of the piece, in the surface coordinates of the plane
+    path: SurfacePath,
+    boundary: [Point<1>; 2],
+}
+
+impl Piece {
+    fn points(&self) -> [Point<2>; 2] {
+        self.boundary
+            .map(|point| self.path.point_from_path_coords(point))
+    }
+
+    fn reverse(self) -> Self {
+        let [a, b] = self.boundary;
+
+        Self {
+            path: self.path,
+            boundary: [b, a],
+        }
+    }
+}
+
+/// A closed loop of pieces that bounds the cross-section
+struct Loop {
+    pieces: Vec<Piece>,
+    points: Vec<Point<2>>,
+    polygon: Polygon,
+}
+
+impl Loop {
+    fn new(pieces: Vec<Piece>) -> Self {
+        // Circles are sampled, so the points are an adequate representation of
+        // the loop, for the purpose of determining winding and containment.
+        const POINTS_PER_CIRCLE: u32 = 16;
+
+        let mut points = Vec::new();
+        for piece in &pieces {
+            let [a, b] = piece.boundary;
+
+            let num_points = match piece.path {
+                SurfacePath::Line(_) => 1,
+                SurfacePath::Circle(_) | SurfacePath::Spline(_) => {
+                    POINTS_PER_CIRCLE
+                }
+            };
+            for i in 0..num_points {
+                let t =
+                    a.t + (b.t - a.t) * (f64::from(i) / f64::from(num_points));
+                points.push(piece.path.point_from_path_coords([t]));
+            }
+        }
+
+        let polygon = Polygon::new()
+            .with_exterior(points.iter().chain(points.first()).copied());
+
+        Self {
+            pieces,
+            points,
+            polygon,
+        }
+    }
+
+    fn contains(&self, other: &Self) -> bool {
+        other
+            .points
+            .first()
+            .is_some_and(|&point| self.polygon.contains_point(point))
+    }
+
+    fn winding(&self) -> Winding {
+        let mut sum = Scalar::ZERO;
+
+        for (i, a) in self.points.iter().enumerate() {
+            let b = self.points[(i + 1) % self.points.len()];
+            sum += a.u * b.v - b.u * a.v;
+        }
+
+        if sum > Scalar::ZERO {
+            Winding::Ccw
+        } else {
+            Winding::Cw
+        }
+    }
+
+    fn build_cycle(
+        &self,
+        winding: Winding,
+        surface: Handle<Surface>,
+        core: &mut Core,
+    ) -> Handle<Cycle> {
+        let pieces = if self.winding() == winding {
+            self.pieces.clone()
+        } else {
+            self.pieces
+                .iter()
+                .rev()
+                .cloned()
+                .map(Piece::reverse)
+                .collect()
+        };
+
+        // Adjacent pieces were computed from different faces, and their end
+        // points might not match exactly. Lines can be adjusted to fit their
+        // neighbors, so prefer the end points of circles where possible.
+        let vertices = (0..pieces.len())
+            .map(|i| {
+                let previous = &pieces[(i + pieces.len() - 1) % pieces.len()];
+                let current = &pieces[i];
+
+                match (&previous.path, &current.path) {
+                    (SurfacePath::Line(_), _) => current.points()[0],
+                    (_, SurfacePath::Line(_)) => previous.points()[1],
+                    _ => current.points()[0],
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let half_edges = pieces
+            .iter()
+            .enumerate()
+            .map(|(i, piece)| match piece.path {
+                SurfacePath::Line(_) => HalfEdge::line_segment(
+                    [vertices[i], vertices[(i + 1) % vertices.len()]],
+                    None,
+                    surface.clone(),
+                    core,
+                ),
+                _ => half_edge_from_piece(piece, surface.clone(), core),
+            })
+            .collect::<Vec<_>>();
+
+        Cycle::new(half_edges).insert(core)
+    }
+}
+
+/// Compute the pieces of the cross-section's boundary within a face
+fn pieces_within_face(
+    face: &Handle<Face>,
+    plane: &SurfaceGeom,
+    tolerance: Tolerance,
+    geometry: &Geometry,
+) -> Vec<Piece> {
+    let surface = geometry.of_surface(face.surface());
+
+    // If the face is coincident with the plane, its boundary is also the
+    // boundary of the neighboring faces, which will provide the pieces.
+    let Some(SurfaceSurfaceIntersection::Curves(curves)) =
+        (surface, plane).intersect()
+    else {
+        return Vec::new();
+    };
+
+    let approx = face.clone().approx(tolerance, geometry);
+    let cycles = [&approx.exterior]
+        .into_iter()
+        .chain(&approx.interiors)
+        .map(|cycle| {
+            cycle
+                .points()
+                .into_iter()
+                .map(|point| point.local_form)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let polygon = Polygon::new()
+        .with_exterior(cycles[0].clone())
+        .with_interiors(cycles[1..].iter().cloned());
+
+    let mut pieces = Vec::new();
+
+    for curve in curves {
+        let [Some(SurfacePath::Line(line)), Some(path)] = curve.local else {
+            todo!(
+                "Cross-sections are only supported, if the intersection with \
+                each face is a line in the face's surface coordinates, and a \
+                line or circle on the plane."
+            );
+        };
+
+        // Find all points where the intersection crosses the boundary of the
+        // face. Between each pair of neighboring crossings, the intersection is
+        // either fully inside or fully outside of the face.
+        let mut crossings = Vec::new();
+        for points in &cycles {
+            for segment in points.windows(2) {
+                let [a, b] = [segment[0], segment[1]];
+
+                let ab = b - a;
+                let denominator = line.direction().cross2d(&ab);
+                if denominator == Scalar::ZERO {
+                    // The intersection is parallel to this segment. If it's
+                    // coincident, the neighboring segments will provide the
+                    // crossings.
+                    continue;
+                }
+
+                let origin_to_a = a - line.origin();
+                let t = origin_to_a.cross2d(&ab) / denominator;
+                let s = origin_to_a.cross2d(&line.direction()) / denominator;
+
+                if s >= Scalar::ZERO && s <= Scalar::ONE {
+                    crossings.push(t);
+                }
+            }
+        }
+        crossings.sort();
+        crossings.dedup();
+
+        for range in crossings.windows(2) {
+            let [start, end] = [range[0], range[1]];
+            let middle = (start + end) / 2.;
+
+            let is_degenerate = (curve.global.point_from_path_coords([middle])
+                - curve.global.point_from_path_coords([start]))
+            .magnitude()
+                < tolerance.inner();
+            if is_degenerate {
+                continue;
+            }
+
+            if polygon.contains_point(line.point_from_line_coords([middle])) {
+                pieces.push(Piece {
+                    path: path.clone(),
+                    boundary: [start, end].map(|t| Point::from([t])),
+                });
+            }
+        }
+    }
+
+    pieces
+}
+
+/// Connect the pieces of the cross-section's boundary into closed loops
+fn connect_pieces(
+    mut pieces: Vec<Piece>,
+    tolerance: Tolerance,
+) -> Vec<Vec<Piece>> {
+    let mut loops = Vec::new();
+
+    while let Some(first) = pieces.pop() {
+        let [start, _] = first.points();
+        let mut current = vec![first];
+
+        loop {
+            let [_, end] = current
+                .last()
+                .expect("Loop starts with a piece and only grows")
+                .points();
+            if (end - start).magnitude() <= tolerance.inner() {
+                break;
+            }
+
+            let next = pieces
+                .iter()
+                .enumerate()
+                .flat_map(|(i, piece)| {
+                    let [a, b] = piece.points();
+                    [(i, false, a), (i, true, b)]
+                })
+                .map(|(i, reverse, point)| {
+                    (i, reverse, (point - end).magnitude())
+                })
+                .min_by_key(|&(_, _, distance)| distance)
+                .filter(|&(_, _, distance)| distance <= tolerance.inner());
+            let Some((i, reverse, _)) = next else {
+                panic!(
+                    "Boundary of cross-section is not closed. The plane might \
+                    pass through an edge or vertex."
+                );
+            };
+
+            let piece = pieces.swap_remove(i);
+            current.push(if reverse { piece.reverse() } else { piece });
+        }
+
+        loops.push(current);
+    }
+
+    loops
+}
+
+fn half_edge_from_piece(
+    piece: &Piece,
+    surface: Handle<Surface>,
+    core: &mut Core,
+) -> Handle<HalfEdge> {
+    let half_edge = HalfEdge::unjoined(core).insert(core);
+
+    core.layers.geometry.define_curve(
+        half_edge.curve().clone(),
+        surface,
+        LocalCurveGeom {
+            path: piece.path.clone(),
+        },
+    );
+    core.layers.geometry.define_half_edge(
+        half_edge.clone(),
+        HalfEdgeGeom {
+            path: piece.path.clone(),
+            boundary: piece.boundary.into(),
+        },
+    );
+
+    half_edge
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Winding;
+
+    use crate::{
+        geometry::SurfacePath,
+        operations::{
+            build::BuildSurface,
+            fixtures::{assert_close, cylinder, solid_with_hole, volume},
+            insert::Insert,
+            sweep::SweepSketch,
+        },
+        topology::Surface,
+        Core,
+    };
+
+    use super::CrossSection;
+
+    #[test]
+    fn cross_section_with_hole() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = solid_with_hole(&mut core);
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 0.5], [1., 0., 0.5], [0., 1., 0.5]],
+            &mut core,
+        );
+
+        let sketch = solid.cross_section(&surface, 0.001, &mut core);
+
+        assert_eq!(sketch.regions().len(), 1);
+        let region = sketch.regions().first();
+        assert_eq!(region.exterior().half_edges().len(), 4);
+        assert_eq!(region.interiors().len(), 1);
+
+        let geometry = &core.layers.geometry;
+        assert_eq!(region.exterior().winding(geometry), Winding::Ccw);
+        for interior in region.interiors() {
+            assert_eq!(interior.winding(geometry), Winding::Cw);
+        }
+
+        let section = sketch
+            .clone()
+            .insert(&mut core)
+            .sweep_sketch(surface, [0., 0., 1.], &mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_close(volume(&section, &core), 3.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cross_section_of_cylinder() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cylinder = cylinder([0., 0., 0.], 1., 1., &mut core);
+
+        // across the axis
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 0.5], [1., 0., 0.5], [0., 1., 0.5]],
+            &mut core,
+        );
+        let sketch = cylinder
+            .cross_section(&surface, 0.001, &mut core)
+            .insert(&mut core);
+
+        assert_eq!(sketch.regions().len(), 1);
+        let exterior = sketch.regions().first().exterior();
+        assert_eq!(exterior.half_edges().len(), 1);
+        assert!(matches!(
+            core.layers
+                .geometry
+                .of_half_edge(exterior.half_edges().first())
+                .path,
+            SurfacePath::Circle(_)
+        ));
+
+        // along the axis
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+        let sketch = cylinder
+            .cross_section(&surface, 0.001, &mut core)
+            .insert(&mut core);
+
+        assert_eq!(sketch.regions().len(), 1);
+        let region = sketch.regions().first();
+        assert_eq!(region.exterior().half_edges().len(), 4);
+        assert!(region.interiors().is_empty());
+
+        core.layers.validation.take_errors()?;
+
+        Ok(())
+    }
+}
@@ -1,20 +1,21 @@
+use std::collections::{BTreeMap, BTreeSet};
+
 use fj_interop::ext::ArrayExt;
-use fj_math::Point;
-use itertools::Itertools;
+use fj_math::{Point, Scalar, Sign};
 
 use crate::{
+    algorithms::triangulate::polygon::Polygon,
+    geometry::{Geometry, SurfacePath},
     operations::{
-        build::{BuildCycle, BuildHalfEdge},
+        build::BuildHalfEdge,
         derive::DeriveFrom,
         geometry::UpdateHalfEdgeGeometry,
         insert::Insert,
         split::SplitEdge,
-        update::{
-            UpdateCycle, UpdateFace, UpdateHalfEdge, UpdateRegion, UpdateShell,
-        },
+        update::{UpdateHalfEdge, UpdateShell},
     },
     storage::Handle,
-    topology::{Cycle, Face, HalfEdge, Shell},
+    topology::{Cycle, Face, HalfEdge, Region, Shell, Vertex},
     Core,
 };
 
@@ -43,6 +44,29 @@ pub trait SplitFace: Sized {
         line: [(&Handle<HalfEdge>, impl Into<Point<1>>); 2],
         core: &mut Core,
     ) -> (Self, [Handle<Face>; 2]);
+
+    /// Split the face along the provided pairs of sibling half-edges
+    ///
+    /// The half-edges must be defined on the face's surface, and each pair
+    /// must connect vertices of the face's boundary, or of other pairs. Each
+    /// closed path that the pairs form, divides the face. This includes paths
+    /// that run through the face without touching its boundary, which cut out
+    /// a piece and leave a hole in the rest of the face.
+    ///
+    /// Returns the pieces of the face. Interior cycles of the face end up in
+    /// the piece that contains them.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the provided half-edges don't connect to the boundary of the
+    /// face, or to each other.
+    #[must_use]
+    fn split_face_along(
+        &self,
+        face: &Handle<Face>,
+        half_edges: impl IntoIterator<Item = [Handle<HalfEdge>; 2]>,
+        core: &mut Core,
+    ) -> (Self, Vec<Handle<Face>>);
 }
 
 impl SplitFace for Shell {
@@ -116,7 +140,7 @@ impl SplitFace for Shell {
                 .update_start_vertex(|_, _| b.start_vertex().clone(), core)
                 .insert(core)
                 .set_geometry(
-                    *core.layers.geometry.of_half_edge(&half_edge),
+                    core.layers.geometry.of_half_edge(&half_edge).clone(),
                     &mut core.layers.geometry,
                 )
         };
@@ -126,80 +150,348 @@ impl SplitFace for Shell {
             core,
         );
 
-        let mut half_edges_of_face_starting_at_b =
-            updated_face_after_split_edges
-                .region()
-                .exterior()
-                .half_edges()
-                .iter()
-                .cloned()
-                .cycle()
-                .skip_while(|half_edge| half_edge != &b);
-
-        let half_edges_b_to_c_inclusive = half_edges_of_face_starting_at_b
-            .take_while_ref(|half_edge| half_edge != &d);
-        let split_face_a = updated_face_after_split_edges
-            .update_region(
-                |region, core| {
-                    region.update_exterior(
-                        |_, core| {
-                            Cycle::empty()
-                                .add_half_edges(
-                                    half_edges_b_to_c_inclusive,
-                                    core,
-                                )
-                                .add_half_edges(
-                                    [dividing_half_edge_c_to_b],
-                                    core,
-                                )
-                        },
-                        core,
-                    )
-                },
-                core,
-            )
-            .insert(core)
-            .derive_from(updated_face_after_split_edges, core);
-
-        // The previous operation has moved the iterator along.
-        let half_edges_of_face_starting_at_d = half_edges_of_face_starting_at_b;
-
-        let half_edges_d_to_a_inclusive = half_edges_of_face_starting_at_d
-            .take_while(|half_edge| half_edge != &b);
-        let split_face_b = updated_face_after_split_edges
-            .update_region(
-                |region, core| {
-                    region.update_exterior(
-                        |_, core| {
-                            Cycle::empty()
-                                .add_half_edges(
-                                    half_edges_d_to_a_inclusive,
-                                    core,
-                                )
-                                .add_half_edges(
-                                    [dividing_half_edge_a_to_d],
-                                    core,
-                                )
-                        },
-                        core,
-                    )
-                },
-                core,
-            )
-            .insert(core)
-            .derive_from(updated_face_after_split_edges, core);
-
-        let faces = [split_face_a, split_face_b];
-        let self_ = self_.update_face(
+        let (self_, faces) = self_.split_face_along(
             updated_face_after_split_edges,
-            |_, _| faces.clone(),
+            [[
+                dividing_half_edge_a_to_d.clone(),
+                dividing_half_edge_c_to_b.clone(),
+            ]],
             core,
         );
 
+        // Return the face that contains the dividing half-edge from `c` to `b`
+        // first, to keep the order of the faces defined.
+        let [face_a, face_b] = match faces.as_slice() {
+            [a, b] => [a.clone(), b.clone()],
+            _ => unreachable!("Splitting a face along a line results in two"),
+        };
+        let faces = if face_a
+            .region()
+            .exterior()
+            .half_edges()
+            .contains(&dividing_half_edge_c_to_b)
+        {
+            [face_a, face_b]
+        } else {
+            [face_b, face_a]
+        };
+
+        (self_, faces)
+    }
+
+    fn split_face_along(
+        &self,
+        face: &Handle<Face>,
+        half_edges: impl IntoIterator<Item = [Handle<HalfEdge>; 2]>,
+        core: &mut Core,
+    ) -> (Self, Vec<Handle<Face>>) {
+        let mut all_half_edges = Vec::new();
+        for cycle in face.region().all_cycles() {
+            for (half_edge, next) in cycle.half_edges().pairs() {
+                all_half_edges
+                    .push((half_edge.clone(), next.start_vertex().clone()));
+            }
+        }
+        for [a, b] in half_edges {
+            all_half_edges.push((a.clone(), b.start_vertex().clone()));
+            all_half_edges.push((b, a.start_vertex().clone()));
+        }
+
+        let faces = faces_from_half_edges(face, all_half_edges, core);
+        let self_ = self.update_face(face, |_, _| faces.clone(), core);
+
         (self_, faces)
     }
 }
 
+/// Build faces from the half-edges that bound them
+///
+/// Each half-edge is provided with the vertex it ends at, and must be defined
+/// on the surface of `original`. See [`cycles_from_half_edges`] for how the
+/// half-edges are arranged into faces. The orientation of the new faces matches
+/// `original`, and they are derived from it.
+///
+/// # Panics
+///
+/// Panics, if the half-edges don't form closed cycles.
+pub(crate) fn faces_from_half_edges(
+    original: &Handle<Face>,
+    half_edges: Vec<(Handle<HalfEdge>, Handle<Vertex>)>,
+    core: &mut Core,
+) -> Vec<Handle<Face>> {
+    let orientation = {
+        let points = original
+            .region()
+            .exterior()
+            .half_edges()
+            .iter()
+            .flat_map(|half_edge| {
+                sample_half_edge(half_edge, &core.layers.geometry)
+            })
+            .collect::<Vec<_>>();
+        signed_area(&points).sign()
+    };
+
+    cycles_from_half_edges(half_edges, orientation, &core.layers.geometry)
+        .into_iter()
+        .map(|(exterior, interiors)| {
+            let exterior = exterior.insert(core);
+            let interiors = interiors
+                .into_iter()
+                .map(|cycle| cycle.insert(core))
+                .collect::<Vec<_>>();
+
+            let region = Region::new(exterior, interiors)
+                .insert(core)
+                .derive_from(original.region(), core);
+
+            Face::new(original.surface().clone(), region)
+                .insert(core)
+                .derive_from(original, core)
+        })
+        .collect()
+}
+
+/// Arrange half-edges into the exterior and interior cycles of faces
+///
+/// Each half-edge is provided with the vertex it ends at. All half-edges must
+/// be defined on the same surface. They are linked into cycles, and each cycle
+/// becomes the exterior of a face, or an interior of the face that contains it,
+/// depending on whether its winding matches `orientation`. That is the sign of
+/// the area that an exterior cycle encloses in surface coordinates, as per the
+/// shoelace formula.
+///
+/// Returns each exterior cycle, together with its interior cycles.
+///
+/// # Panics
+///
+/// Panics, if the half-edges don't form closed cycles.
+pub(crate) fn cycles_from_half_edges(
+    half_edges: Vec<(Handle<HalfEdge>, Handle<Vertex>)>,
+    orientation: Sign,
+    geometry: &Geometry,
+) -> Vec<(Cycle, Vec<Cycle>)> {
+    let loops = link_half_edges(&half_edges, orientation, geometry)
+        .into_iter()
+        .map(|half_edges| {
+            let points = half_edges
+                .iter()
+                .flat_map(|half_edge| sample_half_edge(half_edge, geometry))
+                .collect::<Vec<_>>();
+            let area = signed_area(&points);
+            let polygon = Polygon::new()
+                .with_exterior(points.iter().chain(points.first()).copied());
+
+            (half_edges, points, area, polygon)
+        })
+        .collect::<Vec<_>>();
+
+    let (exteriors, interiors): (Vec<_>, Vec<_>) = loops
+        .into_iter()
+        .partition(|(_, _, area, _)| area.sign() == orientation);
+
+    // Each interior belongs to the smallest exterior that contains it. If an
+    // exterior contains siblings of the interior's half-edges, it is located on
+    // the other side of them, and can't be the one.
+    let mut interiors_of_exteriors = vec![Vec::new(); exteriors.len()];
+    for (half_edges, points, _, _) in interiors {
+        let siblings = half_edges
+            .iter()
+            .map(|half_edge| {
+                let boundary = geometry.of_half_edge(half_edge).boundary;
+                (half_edge.curve().id(), boundary.reverse())
+            })
+            .collect::<BTreeSet<_>>();
+
+        let exterior = exteriors
+            .iter()
+            .enumerate()
+            .filter(|(_, (half_edges, _, _, polygon))| {
+                let shares_edge = half_edges.iter().any(|half_edge| {
+                    let boundary = geometry.of_half_edge(half_edge).boundary;
+                    siblings.contains(&(half_edge.curve().id(), boundary))
+                });
+
+                !shares_edge
+                    && points.iter().all(|&point| polygon.contains_point(point))
+            })
+            .min_by_key(|(_, (_, _, area, _))| area.abs())
+            .map(|(i, _)| i)
+            .expect("Interior cycle must be located within an exterior cycle");
+
+        interiors_of_exteriors[exterior].push(Cycle::new(half_edges));
+    }
+
+    exteriors
+        .into_iter()
+        .map(|(half_edges, _, _, _)| Cycle::new(half_edges))
+        .zip(interiors_of_exteriors)
+        .collect()
+}
+
+/// Link half-edges into cycles
+///
+/// Where more than one half-edge continues from the vertex that a half-edge
+/// ends at, the one that keeps the area on the half-edge's inner side smallest
+/// is chosen. `orientation` is the sign of the area of that side, as per the
+/// shoelace formula, in surface coordinates.
+///
+/// A vertex can appear in more than one place within the surface coordinates,
+/// like on the seam of a cylinder. Only half-edges that start at the same
+/// place are candidates to continue a cycle.
+fn link_half_edges(
+    half_edges: &[(Handle<HalfEdge>, Handle<Vertex>)],
+    orientation: Sign,
+    geometry: &Geometry,
+) -> Vec<Vec<Handle<HalfEdge>>> {
+    struct Link {
+        start: Point<2>,
+        end: Point<2>,
+        angle_out: Scalar,
+        angle_in: Scalar,
+    }
+
+    let links = half_edges
+        .iter()
+        .map(|(half_edge, _)| {
+            let geometry = geometry.of_half_edge(half_edge);
+            let [a, b] = geometry.boundary.inner;
+
+            let direction = |t: Point<1>| {
+                let [derivative, _] = geometry.path.derivatives_at(t);
+                let direction = if b < a { -derivative } else { derivative };
+                Scalar::atan2(direction.v, direction.u)
+            };
+
+            Link {
+                start: geometry.path.point_from_path_coords(a),
+                end: geometry.path.point_from_path_coords(b),
+                angle_out: direction(a),
+                // Looking back from the end along the half-edge.
+                angle_in: direction(b) + Scalar::PI,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut starting_at = BTreeMap::new();
+    for (i, (half_edge, _)) in half_edges.iter().enumerate() {
+        starting_at
+            .entry(half_edge.start_vertex().id())
+            .or_insert_with(Vec::new)
+            .push(i);
+    }
+
+    let next = |i: usize| {
+        let (_, end_vertex) = &half_edges[i];
+        let link = &links[i];
+
+        let candidates = starting_at
+            .get(&end_vertex.id())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let distance = |j: usize| (links[j].start - link.end).magnitude();
+        let min_distance = candidates
+            .iter()
+            .map(|&j| distance(j))
+            .min()
+            .expect("Half-edges must form closed cycles");
+
+        candidates
+            .iter()
+            .copied()
+            .filter(|&j| {
+                distance(j) <= (min_distance * 10.).max(Scalar::from(1e-9))
+            })
+            .min_by_key(|&j| {
+                // The angle we need to turn from the reverse direction of the
+                // half-edge, to reach the next one. Turning back onto the
+                // reverse direction itself is the last resort.
+                let turn = match orientation {
+                    Sign::Negative => links[j].angle_out - link.angle_in,
+                    _ => link.angle_in - links[j].angle_out,
+                };
+                let turn = turn - Scalar::TAU * (turn / Scalar::TAU).floor();
+
+                if turn <= Scalar::from(1e-12) {
+                    turn + Scalar::TAU
+                } else {
+                    turn
+                }
+            })
+            .expect("Candidates can't be empty, as minimum distance exists")
+    };
+
+    let mut visited = vec![false; half_edges.len()];
+    let mut cycles = Vec::new();
+
+    for start in 0..half_edges.len() {
+        if visited[start] {
+            continue;
+        }
+
+        let mut cycle = Vec::new();
+        let mut current = start;
+
+        loop {
+            assert!(
+                !visited[current],
+                "Half-edges must form closed cycles, without branching"
+            );
+            visited[current] = true;
+            cycle.push(half_edges[current].0.clone());
+
+            current = next(current);
+            if current == start {
+                break;
+            }
+        }
+
+        cycles.push(cycle);
+    }
+
+    cycles
+}
+
+/// Sample a half-edge in surface coordinates, excluding its end point
+pub(crate) fn sample_half_edge(
+    half_edge: &Handle<HalfEdge>,
+    geometry: &Geometry,
+) -> Vec<Point<2>> {
+    // Circles are sampled, so the points are an adequate representation of the
+    // cycle, for the purpose of determining winding and containment.
+    const POINTS_PER_CIRCLE: f64 = 32.;
+
+    let geometry = geometry.of_half_edge(half_edge);
+    let [a, b] = geometry.boundary.inner;
+
+    let num_points = match geometry.path {
+        SurfacePath::Line(_) => 1,
+        SurfacePath::Circle(_) | SurfacePath::Spline(_) => {
+            let fraction = ((b.t - a.t) / Scalar::TAU).abs().into_f64();
+            (fraction * POINTS_PER_CIRCLE).ceil().max(2.) as u32
+        }
+    };
+
+    (0..num_points)
+        .map(|i| {
+            let t = a.t + (b.t - a.t) * (f64::from(i) / f64::from(num_points));
+            geometry.path.point_from_path_coords([t])
+        })
+        .collect()
+}
+
+/// Compute the signed area of a polygon, using the shoelace formula
+pub(crate) fn signed_area(points: &[Point<2>]) -> Scalar {
+    let mut sum = Scalar::ZERO;
+
+    for (i, a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        sum += a.u * b.v - b.u * a.v;
+    }
+
+    sum / 2.
+}
+
 #[cfg(test)]
 mod tests {
     use fj_interop::Color;
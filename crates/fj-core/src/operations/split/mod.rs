@@ -8,5 +8,11 @@
 mod edge;
 mod face;
 mod half_edge;
+mod solid;
 
-pub use self::{edge::SplitEdge, face::SplitFace, half_edge::SplitHalfEdge};
+pub use self::{
+    edge::SplitEdge, face::SplitFace, half_edge::SplitHalfEdge,
+    solid::SplitSolid,
+};
+
+pub(crate) use self::face::{faces_from_half_edges, sample_half_edge};
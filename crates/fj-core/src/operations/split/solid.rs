@@ -0,0 +1,408 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_math::{Scalar, Sign};
+
+use crate::{
+    algorithms::intersect::{
+        surface_surface::SurfaceSurfaceIntersection, Intersect,
+    },
+    geometry::{
+        GlobalPath, HalfEdgeGeom, LocalCurveGeom, SurfaceGeom, SweepPath,
+    },
+    operations::{
+        imprint::{
+            assemble, global_path, imprint, key_of_half_edge,
+            project_onto_plane, sample_point, FaceBoundary,
+        },
+        insert::Insert,
+    },
+    storage::Handle,
+    topology::{Face, HalfEdge, Region, Solid, Surface},
+    Core,
+};
+
+use super::face::cycles_from_half_edges;
+
+/// Split a solid into two
+pub trait SplitSolid: Sized {
+    /// Split the solid into two, along the provided plane
+    ///
+    /// Returns the part of the solid behind the plane first, then the part in
+    /// front of it. The front of the plane is the side its normal points to.
+    /// Both parts are closed, with cap faces that lie within the plane. If the
+    /// solid is fully on one side of the plane, the part for the other side
+    /// has no shells.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `surface` is not a plane.
+    ///
+    /// Panics, if the plane intersects a face of the solid along a curve that
+    /// is neither a line nor a circle. This is the case for a plane that is
+    /// slanted relative to the axis of a cylinder, which it intersects along an
+    /// ellipse.
+    ///
+    /// # Implementation Note
+    ///
+    /// The faces of the solid are split along the plane, using [`SplitEdge`]
+    /// and [`SplitFace`]. Only solids that are bounded by planar and
+    /// cylindrical faces are supported, and cylindrical faces can only be
+    /// split by planes that are parallel or perpendicular to their axis.
+    ///
+    /// [`SplitEdge`]: super::SplitEdge
+    /// [`SplitFace`]: super::SplitFace
+    #[must_use]
+    fn split_solid(
+        &self,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> [Self; 2];
+}
+
+impl SplitSolid for Solid {
+    fn split_solid(
+        &self,
+        surface: &Handle<Surface>,
+        core: &mut Core,
+    ) -> [Self; 2] {
+        let tolerance = core.layers.validation.config.distinct_min_distance;
+
+        let plane = core.layers.geometry.of_surface(surface).clone();
+        let SurfaceGeom::Swept {
+            u: GlobalPath::Line(u),
+            v: SweepPath::Line(v),
+        } = &plane
+        else {
+            panic!("Solids can only be split using planes");
+        };
+        let normal = u.direction().cross(v).normalize();
+        let origin = u.origin();
+
+        for face in self.shells().iter().flat_map(|shell| shell.faces()) {
+            let surface = core.layers.geometry.of_surface(face.surface());
+
+            if let Some(SurfaceSurfaceIntersection::Curves(curves)) =
+                (surface, &plane).intersect()
+            {
+                assert!(
+                    curves
+                        .iter()
+                        .all(|curve| curve.local.iter().all(Option::is_some)),
+                    "Solids can only be split by planes that intersect their \
+                    faces along lines and circles."
+                );
+            }
+        }
+
+        let side_of = |face: &Handle<Face>, core: &Core| {
+            let geometry = &core.layers.geometry;
+
+            let point_surface = sample_point(face, geometry);
+            let point = geometry
+                .of_surface(face.surface())
+                .point_from_surface_coords(point_surface);
+            let distance = normal.dot(&(point - origin));
+
+            // A face within the plane faces away from the part it belongs to.
+            let distance = if distance.abs() <= tolerance {
+                face.normal_at(point_surface, geometry)
+                    .map(|face_normal| -face_normal.dot(&normal))
+                    .unwrap_or(distance)
+            } else {
+                distance
+            };
+
+            usize::from(distance > Scalar::ZERO)
+        };
+
+        let plane_boundary = FaceBoundary::unbounded(plane.clone());
+
+        let mut shells = [Vec::new(), Vec::new()];
+        let mut faces = [Vec::new(), Vec::new()];
+
+        for shell in self.shells() {
+            let mut cuts = BTreeMap::new();
+            for face in shell.faces() {
+                let [face_cuts, _] = FaceBoundary::new(face, core)
+                    .intersect(&plane_boundary, tolerance)
+                    .cuts;
+
+                if !face_cuts.is_empty() {
+                    cuts.insert(face.clone(), face_cuts);
+                }
+            }
+
+            if cuts.is_empty() {
+                // The shell is located fully on one side of the plane.
+                let side = side_of(shell.faces().first(), core);
+                shells[side].push(shell.clone());
+                continue;
+            }
+
+            for (piece, _) in imprint(shell, cuts, tolerance, core) {
+                let side = side_of(&piece, core);
+                faces[side].push(piece);
+            }
+        }
+
+        // The caps of the part behind the plane face the same direction as
+        // the plane, and vice versa.
+        let [shells_behind, shells_in_front] = shells;
+        let [faces_behind, faces_in_front] = faces;
+
+        [
+            (shells_behind, faces_behind, Sign::Positive),
+            (shells_in_front, faces_in_front, Sign::Negative),
+        ]
+        .map(|(mut shells, mut faces, orientation)| {
+            faces.extend(caps(&faces, surface, &plane, orientation, core));
+            shells.extend(assemble(faces, tolerance, core));
+
+            Solid::new(shells)
+        })
+    }
+}
+
+/// Create the faces that close the openings of a part of a split solid
+///
+/// All half-edges of the part that don't have a sibling within it, are located
+/// within the plane. The caps are bounded by their siblings. `orientation` is
+/// the sign of the area that the exterior cycles of the caps enclose, in the
+/// coordinates of the plane.
+fn caps(
+    faces: &[Handle<Face>],
+    surface: &Handle<Surface>,
+    plane: &SurfaceGeom,
+    orientation: Sign,
+    core: &mut Core,
+) -> Vec<Handle<Face>> {
+    let mut keyed = Vec::new();
+    for face in faces {
+        for cycle in face.region().all_cycles() {
+            for (half_edge, next) in cycle.half_edges().pairs() {
+                let (_, key, sibling_key) =
+                    key_of_half_edge(half_edge, next, core);
+                keyed.push((face, half_edge, next, key, sibling_key));
+            }
+        }
+    }
+    let keys = keyed
+        .iter()
+        .map(|(_, _, _, key, _)| *key)
+        .collect::<BTreeSet<_>>();
+
+    let mut half_edges = Vec::new();
+    for (face, half_edge, next, _, sibling_key) in keyed {
+        if keys.contains(&sibling_key) {
+            continue;
+        }
+
+        let geometry = core.layers.geometry.of_half_edge(half_edge).clone();
+        let global = global_path(
+            &geometry.path,
+            core.layers.geometry.of_surface(face.surface()),
+        );
+        let path = project_onto_plane(&global, plane);
+
+        core.layers.geometry.define_curve(
+            half_edge.curve().clone(),
+            surface.clone(),
+            LocalCurveGeom { path: path.clone() },
+        );
+
+        let sibling = HalfEdge::new(
+            half_edge.curve().clone(),
+            next.start_vertex().clone(),
+        )
+        .insert(core);
+        core.layers.geometry.define_half_edge(
+            sibling.clone(),
+            HalfEdgeGeom {
+                path,
+                boundary: geometry.boundary.reverse(),
+            },
+        );
+
+        half_edges.push((sibling, half_edge.start_vertex().clone()));
+    }
+
+    cycles_from_half_edges(half_edges, orientation, &core.layers.geometry)
+        .into_iter()
+        .map(|(exterior, interiors)| {
+            let exterior = exterior.insert(core);
+            let interiors = interiors
+                .into_iter()
+                .map(|cycle| cycle.insert(core))
+                .collect::<Vec<_>>();
+
+            let region = Region::new(exterior, interiors).insert(core);
+            Face::new(surface.clone(), region).insert(core)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Scalar, Vector};
+
+    use crate::{
+        operations::{
+            build::BuildSurface,
+            fixtures::{assert_close, cuboid, cylinder, volume},
+            hollow::Hollow,
+            insert::Insert,
+        },
+        topology::Surface,
+        Core,
+    };
+
+    use super::SplitSolid;
+
+    #[test]
+    fn split_cuboid() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 0.5], [1., 0., 0.5], [0., 1., 0.5]],
+            &mut core,
+        );
+
+        let [below, above] = solid
+            .split_solid(&surface, &mut core)
+            .map(|solid| solid.insert(&mut core));
+        core.layers.validation.take_errors()?;
+
+        for (part, expected) in [(below, 2.), (above, 6.)] {
+            let shell = part.shells().only();
+            assert_eq!(shell.faces().len(), 6);
+
+            assert_close(volume(&part, &core), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_cuboid_with_plane_outside() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = cuboid([0., 0., 0.], [2.; 3], &mut core);
+        let (surface, _) = Surface::plane_from_points(
+            [[3., 0., 0.], [3., 1., 0.], [3., 0., 1.]],
+            &mut core,
+        );
+
+        let [behind, in_front] = solid
+            .split_solid(&surface, &mut core)
+            .map(|solid| solid.insert(&mut core));
+        core.layers.validation.take_errors()?;
+
+        // The normal of the plane points to positive x, so the solid is behind
+        // it.
+        assert_eq!(behind.shells().len(), 1);
+        assert!(in_front.shells().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_cylinder_across_axis() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = cylinder([0., 0., 0.], 1., 2., &mut core);
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 0.5], [1., 0., 0.5], [0., 1., 0.5]],
+            &mut core,
+        );
+
+        let [below, above] = solid
+            .split_solid(&surface, &mut core)
+            .map(|solid| solid.insert(&mut core));
+        core.layers.validation.take_errors()?;
+
+        for (part, height) in [(below, 0.5), (above, 1.5)] {
+            let shell = part.shells().only();
+            assert_eq!(shell.faces().len(), 3);
+
+            assert_close(volume(&part, &core), Scalar::PI * height);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_cylinder_along_axis() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = cylinder([0., 0., 0.], 1., 2., &mut core);
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+
+        let [behind, in_front] = solid
+            .split_solid(&surface, &mut core)
+            .map(|solid| solid.insert(&mut core));
+        core.layers.validation.take_errors()?;
+
+        for part in [behind, in_front] {
+            part.shells().only();
+
+            assert_close(volume(&part, &core), Scalar::PI);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_hollow_cylinder() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cylinder = cylinder([0., 0., 0.], 1., 2., &mut core);
+        let top = cylinder
+            .shells()
+            .only()
+            .faces()
+            .iter()
+            .find(|face| {
+                face.normal_at([0., 0.], &core.layers.geometry)
+                    == Some(Vector::unit_z())
+            })
+            .unwrap()
+            .clone();
+        let solid = cylinder.hollow(0.25, &[top], &mut core);
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 0.], [0., 1., 0.], [0., 0., 1.]],
+            &mut core,
+        );
+
+        let [behind, in_front] = solid
+            .split_solid(&surface, &mut core)
+            .map(|solid| solid.insert(&mut core));
+        core.layers.validation.take_errors()?;
+
+        let expected = (Scalar::PI * 2. - Scalar::PI * 0.75 * 0.75 * 1.75) / 2.;
+        for part in [behind, in_front] {
+            part.shells().only();
+
+            assert_close(volume(&part, &core), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "faces along lines and circles")]
+    fn split_cylinder_with_slanted_plane() {
+        let mut core = Core::new();
+
+        let solid = cylinder([0., 0., 0.], 1., 2., &mut core);
+        let (surface, _) = Surface::plane_from_points(
+            [[0., 0., 1.], [1., 0., 1.5], [0., 1., 1.]],
+            &mut core,
+        );
+
+        let _ = solid.split_solid(&surface, &mut core);
+    }
+}
@@ -1,7 +1,7 @@
 use fj_interop::Color;
-use fj_math::Vector;
 
 use crate::{
+    geometry::SweepPath,
     operations::{
         build::BuildCycle, join::JoinCycle, sweep::half_edge::SweepHalfEdge,
     },
@@ -24,6 +24,11 @@ pub trait SweepCycle {
     /// cycle is swept into a face, meaning all resulting faces form a connected
     /// set of side walls.
     ///
+    /// The exception are the two half-edges of a seam, where a cycle that goes
+    /// all the way around a closed surface meets itself. The faces created by
+    /// sweeping them would coincide, so none are created, and the faces on
+    /// either side of the seam are connected directly.
+    ///
     /// Requires the surface that the half-edges of the cycle are defined in,
     /// and optionally the color of the created faces.
     ///
@@ -40,7 +45,7 @@ pub trait SweepCycle {
         &self,
         surface: Handle<Surface>,
         color: Option<Color>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptCycle;
@@ -51,7 +56,7 @@ impl SweepCycle for Cycle {
         &self,
         surface: Handle<Surface>,
         color: Option<Color>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptCycle {
@@ -73,11 +78,21 @@ impl SweepCycle for Cycle {
                 core,
             );
 
-            faces.push(side_face);
+            let is_seam = self
+                .half_edges()
+                .iter()
+                .filter(|half_edge| {
+                    half_edge.curve() == bottom_half_edge.curve()
+                })
+                .count()
+                > 1;
+            if !is_seam {
+                faces.push(side_face);
+            }
 
             top_edges.push((
                 top_edge,
-                *core.layers.geometry.of_half_edge(bottom_half_edge),
+                core.layers.geometry.of_half_edge(bottom_half_edge).clone(),
             ));
         }
 
@@ -99,7 +114,7 @@ pub struct SweptCycle {
     /// A cycle made up of the "top" half-edges of the resulting faces
     ///
     /// "Top" here refers to the place that the sweep path points to from the
-    /// original cycle. Essentially, this is a translated (along the sweep path)
+    /// original cycle. Essentially, this is a moved (along the sweep path)
     /// and reversed version of the original cycle.
     pub top_cycle: Cycle,
 }
@@ -1,6 +1,7 @@
-use fj_math::Vector;
+use fj_math::Scalar;
 
 use crate::{
+    geometry::SweepPath,
     operations::{insert::Insert, presentation::GetColor},
     storage::Handle,
     topology::{Face, Shell},
@@ -16,9 +17,14 @@ use super::{SweepCache, SweepRegion};
 /// [module documentation]: super
 pub trait SweepFace {
     /// # Sweep the [`Face`] into a [`Shell`]
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `path` is an arc of a full turn or more. The shell would
+    /// overlap itself.
     fn sweep_face(
         &self,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> Shell;
@@ -27,7 +33,7 @@ pub trait SweepFace {
 impl SweepFace for Handle<Face> {
     fn sweep_face(
         &self,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> Shell {
@@ -37,7 +43,7 @@ impl SweepFace for Handle<Face> {
         // - "Bottom" refers to the origin of the sweep. The bottom face is the
         //   original face, or a face in the same place.
         // - "Top" refers to the location of the face that was created by
-        //   translating the bottom face along the path.
+        //   moving the bottom face along the path.
         // - "Side" refers to new faces created in between bottom and top.
         //
         // These words are specifically *not* meant in the sense of z-axis
@@ -46,6 +52,14 @@ impl SweepFace for Handle<Face> {
 
         let path = path.into();
 
+        if let SweepPath::Helix(helix) = &path {
+            assert!(
+                helix.pitch() != Scalar::ZERO
+                    || helix.angle().abs() < Scalar::TAU,
+                "Sweeping along an arc of a full turn or more is not supported."
+            );
+        }
+
         let bottom_face = self;
         let other_faces = bottom_face
             .region()
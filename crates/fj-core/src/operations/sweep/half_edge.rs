@@ -1,8 +1,8 @@
 use fj_interop::{ext::ArrayExt, Color};
-use fj_math::{Point, Scalar, Vector};
+use fj_math::{Point, Scalar};
 
 use crate::{
-    geometry::CurveBoundary,
+    geometry::{CurveBoundary, SweepPath},
     operations::{
         build::{BuildCycle, BuildHalfEdge},
         geometry::UpdateHalfEdgeGeometry,
@@ -11,7 +11,7 @@ use crate::{
         update::{UpdateCycle, UpdateHalfEdge},
     },
     storage::Handle,
-    topology::{Cycle, Face, HalfEdge, Region, Surface, Vertex},
+    topology::{Curve, Cycle, Face, HalfEdge, Region, Surface, Vertex},
     Core,
 };
 
@@ -27,7 +27,7 @@ pub trait SweepHalfEdge {
     ///
     /// Returns a face, the result of sweeping the edge, as well as the top edge
     /// of that face, i.e. the edge that is the version of the original edge
-    /// that was translated along the sweep path.
+    /// that was moved along the sweep path.
     ///
     /// In addition to the usual arguments that many sweep operations require,
     /// some other ones are needed:
@@ -41,7 +41,7 @@ pub trait SweepHalfEdge {
         end_vertex: Handle<Vertex>,
         surface: Handle<Surface>,
         color: Option<Color>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> (Face, Handle<HalfEdge>);
@@ -53,18 +53,19 @@ impl SweepHalfEdge for Handle<HalfEdge> {
         end_vertex: Handle<Vertex>,
         surface: Handle<Surface>,
         color: Option<Color>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> (Face, Handle<HalfEdge>) {
         let path = path.into();
 
-        let half_edge_geom = *core.layers.geometry.of_half_edge(self);
-        let surface_geom = *core.layers.geometry.of_surface(&surface);
-        let surface =
+        let half_edge_geom = core.layers.geometry.of_half_edge(self).clone();
+        let surface_geom = core.layers.geometry.of_surface(&surface).clone();
+        let swept_path =
             half_edge_geom
                 .path
                 .sweep_surface_path(&surface_geom, path, core);
+        let surface = swept_path.surface.clone();
 
         // Next, we need to define the boundaries of the face. Let's start with
         // the global vertices and edges.
@@ -72,27 +73,30 @@ impl SweepHalfEdge for Handle<HalfEdge> {
             let [a, b] = [self.start_vertex().clone(), end_vertex];
             let (curve_up, c) = b.clone().sweep_vertex(cache, core);
             let (curve_down, d) = a.clone().sweep_vertex(cache, core);
+            let curve_top = cache
+                .end_curves
+                .entry(self.curve().id())
+                .or_insert_with(|| Curve::new().insert(core))
+                .clone();
 
             (
                 [a, b, c, d],
-                [
-                    Some(self.curve().clone()),
-                    Some(curve_up),
-                    None,
-                    Some(curve_down),
-                ],
+                [self.curve().clone(), curve_up, curve_top, curve_down],
             )
         };
 
         // Let's figure out the surface coordinates of the edge vertices.
         let surface_points = {
-            let [a, b] = half_edge_geom.boundary.inner;
+            let [a, b] = half_edge_geom
+                .boundary
+                .inner
+                .map(|point| swept_path.u_from_path_coords(point));
 
             [
-                [a.t, Scalar::ZERO],
-                [b.t, Scalar::ZERO],
-                [b.t, Scalar::ONE],
-                [a.t, Scalar::ONE],
+                [a, Scalar::ZERO],
+                [b, Scalar::ZERO],
+                [b, Scalar::ONE],
+                [a, Scalar::ONE],
             ]
             .map(Point::from)
         };
@@ -129,14 +133,13 @@ impl SweepHalfEdge for Handle<HalfEdge> {
                     let half_edge = line_segment
                         .update_start_vertex(|_, _| start_vertex, core);
 
-                    let half_edge = if let Some(curve) = curve {
-                        half_edge.update_curve(|_, _| curve, core)
-                    } else {
-                        half_edge
-                    };
+                    let half_edge = half_edge.update_curve(|_, _| curve, core);
 
                     half_edge.insert(core).set_geometry(
-                        *core.layers.geometry.of_half_edge(&line_segment),
+                        core.layers
+                            .geometry
+                            .of_half_edge(&line_segment)
+                            .clone(),
                         &mut core.layers.geometry,
                     )
                 };
@@ -1,7 +1,11 @@
 //! Sweep objects along a path to create new objects
 //!
-//! Sweeps 1D or 2D objects along a straight path, creating a 2D or 3D object,
-//! respectively.
+//! Sweeps 1D or 2D objects along a path, creating a 2D or 3D object,
+//! respectively. The path can be straight, or a helix around an axis. The
+//! latter includes circle arcs, which are helices that don't advance along
+//! their axis. See [`SweepPath`].
+//!
+//! [`SweepPath`]: crate::geometry::SweepPath
 
 mod cycle;
 mod face;
@@ -16,7 +20,7 @@ pub use self::{
     cycle::{SweepCycle, SweptCycle},
     face::SweepFace,
     half_edge::SweepHalfEdge,
-    path::SweepSurfacePath,
+    path::{SweepSurfacePath, SweptSurfacePath},
     region::{SweepRegion, SweptRegion},
     shell_face::{ShellExtendedBySweep, SweepFaceOfShell},
     sketch::SweepSketch,
@@ -38,4 +42,11 @@ pub struct SweepCache {
 
     /// Cache for vertices
     pub vertices: BTreeMap<ObjectId, Handle<Vertex>>,
+
+    /// Cache for the curves at the end of a sweep
+    ///
+    /// Maps the curve that is being swept to its copy at the end of the sweep.
+    /// This makes sure that curves which are shared at the start of the sweep,
+    /// are also shared at its end.
+    pub end_curves: BTreeMap<ObjectId, Handle<Curve>>,
 }
@@ -1,7 +1,7 @@
-use fj_math::{Circle, Line, Vector};
+use fj_math::{Circle, Ellipse, Line, Point, Scalar};
 
 use crate::{
-    geometry::{GlobalPath, SurfaceGeom, SurfacePath},
+    geometry::{GlobalPath, SurfaceGeom, SurfacePath, SweepPath},
     operations::build::BuildSurface,
     storage::Handle,
     topology::Surface,
@@ -18,72 +18,259 @@ pub trait SweepSurfacePath {
     ///
     /// Requires a reference to the surface that the path is defined on.
     ///
+    /// The u-axis of the resulting surface is the swept path, in global
+    /// coordinates. Its coordinates don't necessarily match the coordinates
+    /// of the swept path. Use [`SweptSurfacePath::u_from_path_coords`] to
+    /// convert between them.
+    ///
+    /// Any path on a plane can be swept. On curved surfaces, only lines
+    /// that are parallel to one of the axes of the surface are supported:
+    ///
+    /// - Lines along the u-axis, unless the surface is ruled and its paths
+    ///   can't be blended into a [`GlobalPath`] (like a line and a circle).
+    /// - Lines along the v-axis, unless the surface is swept along a helix
+    ///   that advances along its axis, or the line is located on that axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the path is not supported, as listed above.
     ///
     /// ## Implementation Note
     ///
-    /// Sweeping a `SurfacePath` that is defined on a curved surface is
-    /// currently not supported:
-    /// <https://github.com/hannobraun/fornjot/issues/1112>
+    /// The u-axis of the resulting surface must be a [`GlobalPath`]. Other
+    /// paths on curved surfaces, like circles, splines, or lines that are not
+    /// parallel to an axis, can't be represented as one. A line on a cylinder
+    /// that isn't parallel to one of its axes, for example, is a helix.
+    /// Supporting these requires a new kind of path.
     fn sweep_surface_path(
         &self,
         surface: &SurfaceGeom,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         core: &mut Core,
-    ) -> Handle<Surface>;
+    ) -> SweptSurfacePath;
 }
 
 impl SweepSurfacePath for SurfacePath {
     fn sweep_surface_path(
         &self,
         surface: &SurfaceGeom,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         core: &mut Core,
-    ) -> Handle<Surface> {
-        match surface.u {
-            GlobalPath::Circle(_) => {
-                // Sweeping a `Curve` creates a `Surface`. The u-axis of that
-                // `Surface` is a `GlobalPath`, which we are computing below.
-                // That computation might or might not work with an arbitrary
-                // surface. Probably not, but I'm not sure.
-                //
-                // What definitely won't work, is computing the bottom edge of
-                // the sweep. The edge sweeping code currently assumes that the
-                // bottom edge is a line (which is true when sweeping from a
-                // flat surface). But is the surface we're sweeping from is
-                // curved, there's simply no way to represent the curve of the
-                // resulting bottom edge.
-                todo!(
-                    "Sweeping a curve that is defined on a curved surface is \
-                    not supported yet."
-                )
-            }
-            GlobalPath::Line(_) => {
-                // We're sweeping from a curve on a flat surface, which is
-                // supported. Carry on.
-            }
+    ) -> SweptSurfacePath {
+        let (u, [u_origin, u_end]) = global_path(self, surface);
+
+        SweptSurfacePath {
+            surface: Surface::from_uv(u, path, core),
+            u_origin,
+            u_direction: u_end - u_origin,
         }
+    }
+}
 
-        let u = match self {
-            SurfacePath::Circle(circle) => {
-                let center = surface.point_from_surface_coords(circle.center());
-                let a = surface.vector_from_surface_coords(circle.a());
-                let b = surface.vector_from_surface_coords(circle.b());
+/// The result of sweeping a [`SurfacePath`]
+///
+/// See [`SweepSurfacePath`].
+pub struct SweptSurfacePath {
+    /// The surface created by the sweep
+    pub surface: Handle<Surface>,
 
-                let circle = Circle::new(center, a, b);
+    u_origin: Scalar,
+    u_direction: Scalar,
+}
 
-                GlobalPath::Circle(circle)
-            }
-            SurfacePath::Line(line) => {
-                let origin = surface.point_from_surface_coords(line.origin());
-                let direction =
-                    surface.vector_from_surface_coords(line.direction());
+impl SweptSurfacePath {
+    /// Convert a coordinate on the swept path into a u-coordinate on the
+    /// surface
+    pub fn u_from_path_coords(&self, point: impl Into<Point<1>>) -> Scalar {
+        self.u_origin + self.u_direction * point.into().t
+    }
+}
+
+/// Compute the global form of the surface path
+///
+/// Returns the global path, as well as the coordinates on that global path,
+/// that correspond to the coordinates `0` and `1` on the surface path.
+fn global_path(
+    path: &SurfacePath,
+    surface: &SurfaceGeom,
+) -> (GlobalPath, [Scalar; 2]) {
+    let zero_and_one = [Scalar::ZERO, Scalar::ONE];
+
+    if let SurfaceGeom::Swept {
+        u: GlobalPath::Line(_),
+        v: SweepPath::Line(_),
+    } = surface
+    {
+        // Every path on a plane can be represented in global coordinates.
+        return (surface.path_from_surface_coords(path.clone()), zero_and_one);
+    }
+
+    let SurfacePath::Line(line) = path else {
+        panic!(
+            "Sweeping a circle or spline that is defined on a curved surface \
+            is not supported, as it can't be represented in global \
+            coordinates."
+        )
+    };
+
+    let is_along_u = line.direction().v == Scalar::ZERO;
+    let is_along_v = line.direction().u == Scalar::ZERO;
 
-                let line = Line::from_origin_and_direction(origin, direction);
+    let is_straight = match surface {
+        SurfaceGeom::Swept {
+            v: SweepPath::Line(_),
+            ..
+        } => is_along_v,
+        SurfaceGeom::Ruled {
+            a: GlobalPath::Line(_),
+            b: GlobalPath::Line(_),
+        } => is_along_u || is_along_v,
+        SurfaceGeom::Ruled { .. } => is_along_v,
+        SurfaceGeom::Swept { .. } => false,
+    };
+    if is_straight {
+        let [a, b] = zero_and_one.map(|t| {
+            surface.point_from_surface_coords(line.point_from_line_coords([t]))
+        });
+        let line = Line::from_points_with_line_coords([([0.], a), ([1.], b)]);
 
-                GlobalPath::Line(line)
+        return (GlobalPath::Line(line), zero_and_one);
+    }
+
+    let [start, end] = zero_and_one.map(|t| line.point_from_line_coords([t]));
+
+    let global = match surface {
+        SurfaceGeom::Swept { u, v: sweep } if is_along_u => Some((
+            u.clone().transform(&sweep.to_transform_at(start.v)),
+            [start.u, end.u],
+        )),
+        SurfaceGeom::Ruled { a, b } if is_along_u => {
+            blend_paths(a, b, start.v).map(|path| (path, [start.u, end.u]))
+        }
+        SurfaceGeom::Swept {
+            u,
+            v: SweepPath::Helix(helix),
+        } if is_along_v && helix.pitch() == Scalar::ZERO => helix
+            .circle_around_axis(u.point_from_path_coords([start.u]))
+            .map(|circle| {
+                (
+                    GlobalPath::Circle(circle),
+                    [start.v, end.v].map(|v| helix.angle() * v),
+                )
+            }),
+        _ => None,
+    };
+    global.unwrap_or_else(|| {
+        panic!(
+            "Sweeping a line on a curved surface is only supported, if the \
+            line can be represented in global coordinates."
+        )
+    })
+}
+
+/// Blend two paths, like a ruled surface does
+///
+/// Returns `None`, if the result can't be represented as a [`GlobalPath`].
+fn blend_paths(
+    a: &GlobalPath,
+    b: &GlobalPath,
+    v: Scalar,
+) -> Option<GlobalPath> {
+    if v == Scalar::ZERO {
+        return Some(a.clone());
+    }
+    if v == Scalar::ONE {
+        return Some(b.clone());
+    }
+
+    // Circles and ellipses share a coordinate system. Blending any of them
+    // results in another circle or ellipse.
+    let as_ellipse = |path: &GlobalPath| match path {
+        GlobalPath::Circle(circle) => Some(Ellipse::from(*circle)),
+        GlobalPath::Ellipse(ellipse) => Some(*ellipse),
+        GlobalPath::Line(_) | GlobalPath::Spline(_) => None,
+    };
+    let (Some(a), Some(b)) = (as_ellipse(a), as_ellipse(b)) else {
+        return None;
+    };
+
+    let center = a.center() + (b.center() - a.center()) * v;
+    let [vector_a, vector_b] = [(a.a(), b.a()), (a.b(), b.b())]
+        .map(|(from, to)| from + (to - from) * v);
+
+    if let Some(circle) = Circle::try_new(center, vector_a, vector_b) {
+        return Some(GlobalPath::Circle(circle));
+    }
+
+    // The blended vectors can become parallel, or even zero, in which case
+    // the result degenerates into a line segment or a point.
+    let is_degenerate = vector_a.cross(&vector_b).magnitude()
+        <= vector_a.magnitude() * vector_b.magnitude() * f64::EPSILON * 16.;
+    if is_degenerate {
+        return None;
+    }
+
+    Some(GlobalPath::Ellipse(Ellipse::new(
+        center, vector_a, vector_b,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+
+    use crate::geometry::{GlobalPath, SurfaceGeom, SurfacePath};
+
+    use super::{blend_paths, global_path};
+
+    #[test]
+    fn global_path_of_lines_on_sphere() {
+        let surface = SurfaceGeom::sphere([1., 2., 3.], 2.);
+
+        // A line along a meridian, and one along the equator.
+        let lines = [([0.5, 0.25], [1., 0.]), ([FRAC_PI_2, 0.25], [0., 0.5])];
+
+        for (origin, direction) in lines {
+            let line = Line::from_origin_and_direction(
+                Point::from(origin),
+                Vector::from(direction),
+            );
+            let path = SurfacePath::Line(line);
+            let (global, [start, end]) = global_path(&path, &surface);
+
+            for t in [0., 0.5, 1.] {
+                let expected = surface.point_from_surface_coords(
+                    line.point_from_line_coords([t]),
+                );
+                let actual =
+                    global.point_from_path_coords([start + (end - start) * t]);
+
+                assert!((actual - expected).magnitude() < Scalar::from(1e-12));
             }
-        };
+        }
+    }
 
-        Surface::from_uv(u, path, core)
+    #[test]
+    fn blend_circles_with_rounding_error() {
+        // Rotated vectors are no longer exactly perpendicular, due to numerical
+        // inaccuracy. Blending circles defined by them must still result in a
+        // circle, regardless of its size.
+        let rotation =
+            Transform::rotation(Vector::from([1., 2., 3.]).normalize());
+
+        for radius in [1e-6, 1., 1e6] {
+            let [a, b] = [0., 1.].map(|z| {
+                let [a, b] =
+                    [Vector::unit_x(), Vector::unit_y()].map(|vector| {
+                        rotation.transform_vector(&(vector * radius))
+                    });
+                GlobalPath::Circle(Circle::new(Point::from([0., 0., z]), a, b))
+            });
+
+            let blended = blend_paths(&a, &b, Scalar::from(0.5));
+            assert!(matches!(blended, Some(GlobalPath::Circle(_))));
+        }
     }
 }
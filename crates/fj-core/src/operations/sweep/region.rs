@@ -1,7 +1,7 @@
 use fj_interop::Color;
-use fj_math::Vector;
 
 use crate::{
+    geometry::SweepPath,
     operations::{
         insert::Insert, reverse::Reverse, transform::TransformObject,
     },
@@ -33,7 +33,7 @@ pub trait SweepRegion {
         &self,
         surface: Handle<Surface>,
         color: Option<Color>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptRegion;
@@ -44,7 +44,7 @@ impl SweepRegion for Region {
         &self,
         surface: Handle<Surface>,
         color: Option<Color>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         cache: &mut SweepCache,
         core: &mut Core,
     ) -> SweptRegion {
@@ -79,7 +79,8 @@ impl SweepRegion for Region {
             .collect::<Vec<_>>();
 
         let top_face = {
-            let top_surface = surface.translate(path, core).insert(core);
+            let top_surface =
+                surface.transform(&path.to_transform(), core).insert(core);
             let top_region =
                 Region::new(top_exterior, top_interiors).insert(core);
 
@@ -98,7 +99,7 @@ fn sweep_cycle(
     bottom_surface: Handle<Surface>,
     color: Option<Color>,
     faces: &mut Vec<Face>,
-    path: Vector<3>,
+    path: SweepPath,
     cache: &mut SweepCache,
     core: &mut Core,
 ) -> Handle<Cycle> {
@@ -1,6 +1,5 @@
-use fj_math::Vector;
-
 use crate::{
+    geometry::SweepPath,
     operations::{
         derive::DeriveFrom,
         insert::Insert,
@@ -35,7 +34,7 @@ pub trait SweepFaceOfShell {
     fn sweep_face_of_shell(
         &self,
         face: Handle<Face>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         core: &mut Core,
     ) -> ShellExtendedBySweep;
 }
@@ -44,7 +43,7 @@ impl SweepFaceOfShell for Shell {
     fn sweep_face_of_shell(
         &self,
         face: Handle<Face>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         core: &mut Core,
     ) -> ShellExtendedBySweep {
         let path = path.into();
@@ -98,3 +97,218 @@ pub struct ShellExtendedBySweep {
     /// The top face created by the sweep
     pub top_face: Face,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{
+            approx::{Approx, Tolerance},
+            triangulate::Triangulate,
+        },
+        geometry::{
+            CurveBoundary, GlobalPath, HalfEdgeGeom, SurfaceGeom, SurfacePath,
+            SweepPath,
+        },
+        operations::{
+            build::{BuildCycle, BuildHalfEdge, BuildRegion, BuildSketch},
+            geometry::UpdateHalfEdgeGeometry,
+            insert::Insert,
+            reverse::Reverse,
+            sweep::SweepSketch,
+            update::{UpdateRegion, UpdateSketch},
+        },
+        topology::{Cycle, HalfEdge, Region, Sketch},
+        Core,
+    };
+
+    use super::SweepFaceOfShell;
+
+    #[test]
+    fn sweep_curved_face_of_shell() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // A half-cylinder, with its curved side pointing towards positive y.
+        // That side is made up of two faces.
+        let half_disk = {
+            let path = SurfacePath::circle_from_center_and_radius([0., 0.], 1.);
+            let arcs = [[0., 0.5], [0.5, 1.]].map(|range| {
+                let boundary =
+                    CurveBoundary::from(range.map(|t| [Scalar::PI * t]));
+
+                HalfEdge::unjoined(&mut core)
+                    .insert(&mut core)
+                    .set_geometry(
+                        HalfEdgeGeom {
+                            path: path.clone(),
+                            boundary,
+                        },
+                        &mut core.layers.geometry,
+                    )
+            });
+            let line = HalfEdge::line_segment(
+                [[-1., 0.], [1., 0.]],
+                None,
+                core.layers.topology.surfaces.space_2d(),
+                &mut core,
+            )
+            .insert(&mut core);
+
+            let [a, b] = arcs;
+            let exterior = Cycle::new([a, b, line]).insert(&mut core);
+            Region::new(exterior, [])
+        };
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions([half_disk], &mut core)
+            .sweep_sketch(
+                core.layers.topology.surfaces.xy_plane(),
+                [0., 0., 1.],
+                &mut core,
+            );
+        let shell = solid.shells().only();
+
+        let curved_face = shell
+            .faces()
+            .iter()
+            .find(|face| {
+                matches!(
+                    core.layers.geometry.of_surface(face.surface()),
+                    SurfaceGeom::Swept {
+                        u: GlobalPath::Circle(_),
+                        ..
+                    }
+                )
+            })
+            .expect("Expected shell to have a curved face")
+            .clone();
+
+        let shell = shell
+            .sweep_face_of_shell(curved_face, [0., 1., 0.], &mut core)
+            .shell
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 9);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*shell, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_round_face_of_spacer() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        // The same shape as the `spacer` model: a cylinder with a round hole.
+        let annulus = Region::circle(
+            [0., 0.],
+            1.,
+            core.layers.topology.surfaces.space_2d(),
+            &mut core,
+        )
+        .add_interiors(
+            [Cycle::circle(
+                [0., 0.],
+                0.5,
+                core.layers.topology.surfaces.space_2d(),
+                &mut core,
+            )
+            .reverse(&mut core)],
+            &mut core,
+        );
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions([annulus], &mut core)
+            .sweep_sketch(
+                core.layers.topology.surfaces.xy_plane(),
+                [0., 0., 1.],
+                &mut core,
+            );
+        let shell = solid.shells().only();
+
+        let outer_face = shell
+            .faces()
+            .iter()
+            .find(|face| {
+                let SurfaceGeom::Swept {
+                    u: GlobalPath::Circle(circle),
+                    ..
+                } = core.layers.geometry.of_surface(face.surface())
+                else {
+                    return false;
+                };
+
+                circle.radius() == Scalar::ONE
+            })
+            .expect("Expected spacer to have an outer round face")
+            .clone();
+
+        // The seam of the round face is not swept into side faces. The faces
+        // swept from its top and bottom edges meet along it instead.
+        let swept =
+            shell.sweep_face_of_shell(outer_face, [2., 0., 0.], &mut core);
+        let side_faces = swept
+            .side_faces
+            .into_iter()
+            .map(|face| face.insert(&mut core))
+            .collect::<Vec<_>>();
+        let top_face = swept.top_face.insert(&mut core);
+        let shell = swept.shell.insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(shell.faces().len(), 6);
+
+        let surfaces = side_faces
+            .iter()
+            .map(|face| core.layers.geometry.of_surface(face.surface()))
+            .collect::<Vec<_>>();
+        let is_swept_circle = |surface: &SurfaceGeom| {
+            matches!(
+                surface,
+                SurfaceGeom::Swept {
+                    u: GlobalPath::Circle(_),
+                    v: SweepPath::Line(path),
+                } if *path == Vector::from([2., 0., 0.])
+            )
+        };
+        assert_eq!(surfaces.len(), 2);
+        assert!(surfaces.iter().all(|surface| is_swept_circle(surface)));
+
+        let SurfaceGeom::Swept {
+            u: GlobalPath::Circle(circle),
+            v: SweepPath::Line(axis),
+        } = core.layers.geometry.of_surface(top_face.surface())
+        else {
+            panic!("Expected top face to be round");
+        };
+        assert_eq!(circle.center(), Point::from([2., 0., 0.]));
+        assert_eq!(circle.radius(), Scalar::ONE);
+        assert_eq!(*axis, Vector::from([0., 0., 1.]));
+
+        // Both halves of the seam must still share a curve, at the end of the
+        // sweep.
+        let seam = top_face
+            .region()
+            .exterior()
+            .half_edges()
+            .iter()
+            .filter(|half_edge| {
+                matches!(
+                    core.layers.geometry.of_half_edge(half_edge).path,
+                    SurfacePath::Line(line) if line.direction().u == Scalar::ZERO
+                )
+            })
+            .map(|half_edge| half_edge.curve().id())
+            .collect::<Vec<_>>();
+        assert_eq!(seam.len(), 2);
+        assert_eq!(seam[0], seam[1]);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let approx = top_face.approx(tolerance, &core.layers.geometry);
+        assert!(!approx.triangles().is_empty());
+
+        Ok(())
+    }
+}
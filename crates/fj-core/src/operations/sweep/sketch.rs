@@ -1,7 +1,7 @@
-use fj_math::{Scalar, Vector};
+use fj_math::Scalar;
 
 use crate::{
-    geometry::GlobalPath,
+    geometry::{GlobalPath, SurfaceGeom, SweepPath},
     operations::{derive::DeriveFrom, insert::Insert, reverse::Reverse},
     storage::Handle,
     topology::{Face, Sketch, Solid, Surface},
@@ -17,10 +17,15 @@ use super::{face::SweepFace, SweepCache};
 /// [module documentation]: super
 pub trait SweepSketch {
     /// # Sweep the [`Sketch`]
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `path` is an arc of a full turn or more. See
+    /// [`SweepFace::sweep_face`].
     fn sweep_sketch(
         &self,
         surface: Handle<Surface>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         core: &mut Core,
     ) -> Solid;
 }
@@ -29,7 +34,7 @@ impl SweepSketch for Sketch {
     fn sweep_sketch(
         &self,
         surface: Handle<Surface>,
-        path: impl Into<Vector<3>>,
+        path: impl Into<SweepPath>,
         core: &mut Core,
     ) -> Solid {
         let path = path.into();
@@ -46,18 +51,33 @@ impl SweepSketch for Sketch {
                     .is_ccw());
 
                 let is_negative_sweep = {
-                    let u = match core.layers.geometry.of_surface(&surface).u {
-                        GlobalPath::Circle(_) => todo!(
+                    let geometry = &core.layers.geometry;
+                    let surface_geom = geometry.of_surface(&surface);
+
+                    let SurfaceGeom::Swept {
+                        u: GlobalPath::Line(u),
+                        v: SweepPath::Line(v),
+                    } = *surface_geom
+                    else {
+                        todo!(
                             "Sweeping sketch from a rounded surfaces is not \
                             supported"
-                        ),
-                        GlobalPath::Line(line) => line.direction(),
+                        )
                     };
-                    let v = core.layers.geometry.of_surface(&surface).v;
 
-                    let normal = u.cross(&v);
+                    let normal = u.direction().cross(&v);
+
+                    // If the path is curved, its direction depends on where
+                    // we look. Any point of the region will do though, as the
+                    // path must not pass through the region.
+                    let point = {
+                        let half_edge = region.exterior().half_edges().first();
+                        let position =
+                            geometry.of_half_edge(half_edge).start_position();
+                        surface_geom.point_from_surface_coords(position)
+                    };
 
-                    normal.dot(&path) < Scalar::ZERO
+                    normal.dot(&path.tangent_at(point)) < Scalar::ZERO
                 };
 
                 if is_negative_sweep {
@@ -75,3 +95,172 @@ impl SweepSketch for Sketch {
         Solid::new(shells)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Scalar, Spline};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        geometry::SweepPath,
+        operations::{
+            build::{BuildHalfEdge, BuildRegion, BuildSketch},
+            insert::Insert,
+            update::UpdateSketch,
+        },
+        storage::Handle,
+        topology::{Cycle, HalfEdge, Region, Sketch, Solid},
+        Core,
+    };
+
+    use super::SweepSketch;
+
+    #[test]
+    fn sweep_along_arc() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let path = SweepPath::arc(&z_circle(), Scalar::PI / 2.);
+        let solid = sweep_square(path, &mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 6);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*solid, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_along_arc_in_negative_direction() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let path = SweepPath::arc(&z_circle(), -Scalar::PI / 2.);
+        let solid = sweep_square(path, &mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "arc of a full turn")]
+    fn sweep_along_full_circle() {
+        let mut core = Core::new();
+
+        let path = SweepPath::arc(&z_circle(), Scalar::TAU);
+        sweep_square(path, &mut core);
+    }
+
+    #[test]
+    fn sweep_along_helix() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let path = SweepPath::helix(&z_circle(), Scalar::TAU * 1.5, 2.);
+        let solid = sweep_square(path, &mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 6);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*solid, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_spline() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.space_2d();
+        let spline = HalfEdge::spline(
+            Spline::from_control_points(
+                3,
+                [[4., 0.], [3., 2.], [2., 1.], [1., 2.], [0., 0.]],
+            ),
+            surface.clone(),
+            &mut core,
+        );
+        let line = HalfEdge::line_segment(
+            [[0., 0.], [4., 0.]],
+            None,
+            surface,
+            &mut core,
+        )
+        .insert(&mut core);
+        let region =
+            Region::new(Cycle::new([spline, line]).insert(&mut core), []);
+
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions([region], &mut core)
+            .sweep_sketch(
+                core.layers.topology.surfaces.xy_plane(),
+                [0., 0., 1.],
+                &mut core,
+            )
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 4);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*solid, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sweep_circle_along_arc() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xz_plane();
+        let path = SweepPath::arc(&z_circle(), Scalar::PI);
+        let solid = Sketch::empty(&core.layers.topology)
+            .add_regions(
+                [Region::circle(
+                    [3., 0.],
+                    1.,
+                    core.layers.topology.surfaces.space_2d(),
+                    &mut core,
+                )],
+                &mut core,
+            )
+            .sweep_sketch(surface, path, &mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_eq!(solid.shells().only().faces().len(), 3);
+
+        // The side face is curved in two directions. Make sure it can be
+        // approximated anyway.
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*solid, tolerance).triangulate(&mut core);
+        assert!(mesh.triangles().count() > 0);
+
+        Ok(())
+    }
+
+    fn z_circle() -> Circle<3> {
+        Circle::from_center_and_radius([0., 0., 0.], 1.)
+    }
+
+    fn sweep_square(path: SweepPath, core: &mut Core) -> Handle<Solid> {
+        let surface = core.layers.topology.surfaces.xz_plane();
+
+        Sketch::empty(&core.layers.topology)
+            .add_regions(
+                [Region::polygon(
+                    [[1., 0.], [2., 0.], [2., 1.], [1., 1.]],
+                    core.layers.topology.surfaces.space_2d(),
+                    core,
+                )],
+                core,
+            )
+            .sweep_sketch(surface, path, core)
+            .insert(core)
+    }
+}
@@ -1,6 +1,10 @@
 use fj_math::Transform;
 
-use crate::{topology::Face, Core};
+use crate::{
+    operations::{derive::DeriveFrom, insert::Insert, reverse::Reverse},
+    topology::Face,
+    Core,
+};
 
 use super::{TransformCache, TransformObject};
 
@@ -15,11 +19,19 @@ impl TransformObject for Face {
             .surface()
             .clone()
             .transform_with_cache(transform, core, cache);
-        let region = self
+        let mut region = self
             .region()
             .clone()
             .transform_with_cache(transform, core, cache);
 
+        if transform.reverses_orientation() {
+            // The coordinate system of the transformed surface has the
+            // opposite handedness, which would turn the face inside out. We
+            // need to reverse the region to compensate.
+            region =
+                region.reverse(core).insert(core).derive_from(&region, core);
+        }
+
         Self::new(surface, region)
     }
 }
@@ -12,7 +12,7 @@ mod vertex;
 
 use std::collections::{btree_map, BTreeMap};
 
-use fj_math::{Transform, Vector};
+use fj_math::{Plane, Transform, Vector};
 use type_map::TypeMap;
 
 use crate::{
@@ -35,6 +35,10 @@ use super::derive::DeriveFrom;
 /// hasn't been done so far, is that no one has put in the work yet.
 pub trait TransformObject: Sized {
     /// Transform the object
+    ///
+    /// If the transform reverses orientation, like a reflection does, faces
+    /// are reversed to compensate. This makes sure that faces that pointed
+    /// outward before the transform, still do so afterwards.
     fn transform(&self, transform: &Transform, core: &mut Core) -> Self {
         let mut cache = TransformCache::default();
         self.transform_with_cache(transform, core, &mut cache)
@@ -65,6 +69,31 @@ pub trait TransformObject: Sized {
     ) -> Self {
         self.transform(&Transform::rotation(axis_angle), core)
     }
+
+    /// Scale the object, using a separate factor for each axis
+    ///
+    /// Unless all factors are equal, this doesn't preserve angles. Circles
+    /// become ellipses, for example.
+    ///
+    /// Convenience wrapper around [`TransformObject::transform`].
+    fn scale(
+        &self,
+        scaling_factors: impl Into<Vector<3>>,
+        core: &mut Core,
+    ) -> Self {
+        self.transform(&Transform::scale_per_axis(scaling_factors), core)
+    }
+
+    /// Mirror the object across the provided plane
+    ///
+    /// Faces are reversed, so the mirrored object has the same orientation as
+    /// the original one. A mirrored solid has its faces pointing outward, for
+    /// example.
+    ///
+    /// Convenience wrapper around [`TransformObject::transform`].
+    fn mirror(&self, plane: &Plane, core: &mut Core) -> Self {
+        self.transform(&Transform::reflection(plane), core)
+    }
 }
 
 impl<T> TransformObject for Handle<T>
@@ -131,3 +160,187 @@ impl TransformCache {
         map.insert(key.id(), value);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Circle, Plane, Point, Scalar, Transform, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        geometry::SweepPath,
+        operations::{
+            build::{BuildRegion, BuildSketch},
+            fixtures::{assert_close, cylinder, solid_with_hole},
+            insert::Insert,
+            sweep::SweepSketch,
+            update::UpdateSketch,
+        },
+        topology::{Region, Sketch, Solid},
+        Core,
+    };
+
+    use super::TransformObject;
+
+    #[test]
+    fn mirror() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = solid_with_hole(&mut core);
+        let plane = Plane::from_parametric(
+            Point::from([1., 0., 0.]),
+            Vector::unit_y(),
+            Vector::unit_z(),
+        );
+
+        let mirrored = solid.mirror(&plane, &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_close(signed_volume(&mirrored, &mut core)?, 3.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mirror_helical_sweep() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = helical_sweep(&mut core);
+
+        // Mirror across a plane that is perpendicular to the axis of the helix,
+        // and one that contains it.
+        let planes = [
+            Plane::from_parametric(
+                Point::origin(),
+                Vector::unit_x(),
+                Vector::unit_y(),
+            ),
+            Plane::from_parametric(
+                Point::origin(),
+                Vector::unit_y(),
+                Vector::unit_z(),
+            ),
+        ];
+
+        for plane in planes {
+            let mirrored = solid.mirror(&plane, &mut core).insert(&mut core);
+            core.layers.validation.take_errors()?;
+
+            let reflection = Transform::reflection(&plane);
+            let expected = vertex_positions(&solid, &core)
+                .into_iter()
+                .map(|point| reflection.transform_point(&point))
+                .collect::<Vec<_>>();
+            let actual = vertex_positions(&mirrored, &core);
+
+            assert_eq!(actual.len(), expected.len());
+            for point in actual {
+                assert!(expected.iter().any(|expected| {
+                    (point - *expected).magnitude() < Scalar::from(1e-9)
+                }));
+            }
+
+            let volume = signed_volume(&mirrored, &mut core)?;
+            assert!(volume > Scalar::ZERO);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn negative_scale() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let solid = solid_with_hole(&mut core);
+
+        let scaled = solid
+            .transform(&Transform::scale(-2.), &mut core)
+            .insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        assert_close(signed_volume(&scaled, &mut core)?, 24.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_uniform_scale() -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let cylinder = cylinder([0., 0., 0.], 1., 1., &mut core);
+
+        let scaled = cylinder.scale([2., 1., 3.], &mut core).insert(&mut core);
+        core.layers.validation.take_errors()?;
+
+        // The approximation of the curved side is inscribed, so the volume of
+        // the triangulation is a bit smaller than that of the actual shape.
+        let volume = signed_volume(&scaled, &mut core)?;
+        let expected = Scalar::PI * 6.;
+        assert!(volume < expected);
+        assert!(volume > expected * 0.99);
+
+        Ok(())
+    }
+
+    /// A square, swept along a quarter turn of a helix around the z-axis
+    fn helical_sweep(core: &mut Core) -> Solid {
+        let region = Region::polygon(
+            [[1., 0.], [2., 0.], [2., 1.], [1., 1.]],
+            core.layers.topology.surfaces.space_2d(),
+            core,
+        );
+        let path = SweepPath::helix(
+            &Circle::from_center_and_radius([0., 0., 0.], 1.),
+            Scalar::PI / 2.,
+            4.,
+        );
+
+        Sketch::empty(&core.layers.topology)
+            .add_regions([region], core)
+            .sweep_sketch(core.layers.topology.surfaces.xz_plane(), path, core)
+    }
+
+    /// Collect the positions of all vertices of the solid
+    ///
+    /// Every vertex is included once per half-edge that starts at it.
+    fn vertex_positions(solid: &Solid, core: &Core) -> Vec<Point<3>> {
+        let mut positions = Vec::new();
+
+        for shell in solid.shells() {
+            for face in shell.faces() {
+                let surface = core.layers.geometry.of_surface(face.surface());
+
+                for cycle in face.region().all_cycles() {
+                    for half_edge in cycle.half_edges() {
+                        let position = core
+                            .layers
+                            .geometry
+                            .of_half_edge(half_edge)
+                            .start_position();
+                        positions
+                            .push(surface.point_from_surface_coords(position));
+                    }
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Compute the volume of the solid from its triangulation
+    ///
+    /// This is only positive, if all faces of the solid point outward.
+    fn signed_volume(solid: &Solid, core: &mut Core) -> anyhow::Result<Scalar> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (solid, tolerance).triangulate(core);
+
+        let volume = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&b.coords.cross(&c.coords)) / 6.
+            })
+            .fold(Scalar::ZERO, |sum, volume| sum + volume);
+
+        Ok(volume)
+    }
+}
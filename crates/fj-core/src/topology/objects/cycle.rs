@@ -45,32 +45,51 @@ impl Cycle {
             let [a, b] = geometry.boundary.inner;
             let edge_direction_positive = a < b;
 
-            let circle = match geometry.path {
-                SurfacePath::Circle(circle) => circle,
-                SurfacePath::Line(_) => unreachable!(
-                    "Invalid cycle: less than 3 edges, but not all are circles"
-                ),
-            };
-            let cross_positive = circle.a().cross2d(&circle.b()) > Scalar::ZERO;
+            if let SurfacePath::Circle(circle) = &geometry.path {
+                let cross_positive =
+                    circle.a().cross2d(&circle.b()) > Scalar::ZERO;
 
-            if edge_direction_positive == cross_positive {
-                return Winding::Ccw;
-            } else {
-                return Winding::Cw;
+                if edge_direction_positive == cross_positive {
+                    return Winding::Ccw;
+                } else {
+                    return Winding::Cw;
+                }
             }
         }
 
         // Now that we got the special case out of the way, we can treat the
         // cycle as a polygon:
         // https://stackoverflow.com/a/1165943
+        //
+        // Splines can bend arbitrarily, so their start positions alone are not
+        // enough to define that polygon. Let's add some points in between.
+        const POINTS_PER_SPLINE: u32 = 8;
 
-        let mut sum = Scalar::ZERO;
+        let mut points = Vec::new();
+        for half_edge in self.half_edges() {
+            let geometry = geometry.of_half_edge(half_edge);
+            let [a, b] = geometry.boundary.inner;
+
+            let num_points = match geometry.path {
+                SurfacePath::Circle(_) | SurfacePath::Line(_) => 1,
+                SurfacePath::Spline(_) => POINTS_PER_SPLINE,
+            };
+            for i in 0..num_points {
+                let t =
+                    a.t + (b.t - a.t) * (f64::from(i) / f64::from(num_points));
+                points.push(geometry.path.point_from_path_coords([t]));
+            }
+        }
+        assert!(
+            points.len() >= 3,
+            "Invalid cycle: less than 3 edges, but not all are circles or \
+            splines"
+        );
 
-        for (a, b) in self.half_edges().pairs() {
-            let [a, b] = [a, b].map(|half_edge| {
-                geometry.of_half_edge(half_edge).start_position()
-            });
+        let mut sum = Scalar::ZERO;
 
+        for (i, a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
             sum += (b.u - a.u) * (b.v + a.v);
         }
 
@@ -1,7 +1,7 @@
-use fj_math::Winding;
+use fj_math::{Point, Vector, Winding};
 
 use crate::{
-    geometry::Geometry,
+    geometry::{Curvature, Geometry},
     storage::Handle,
     topology::{Region, Surface},
 };
@@ -68,6 +68,79 @@ impl Face {
             Winding::Cw => Handedness::LeftHanded,
         }
     }
+
+    /// Convert a point in surface coordinates to model coordinates
+    ///
+    /// See [`SurfaceGeom`].
+    ///
+    /// [`SurfaceGeom`]: crate::geometry::SurfaceGeom
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+        geometry: &Geometry,
+    ) -> Point<3> {
+        geometry
+            .of_surface(&self.surface)
+            .point_from_surface_coords(point)
+    }
+
+    /// Compute the first derivatives at the provided surface coordinates
+    ///
+    /// See [`SurfaceGeom`].
+    ///
+    /// [`SurfaceGeom`]: crate::geometry::SurfaceGeom
+    pub fn derivatives_at(
+        &self,
+        point: impl Into<Point<2>>,
+        geometry: &Geometry,
+    ) -> [Vector<3>; 2] {
+        geometry.of_surface(&self.surface).derivatives_at(point)
+    }
+
+    /// Compute the normal at the provided surface coordinates
+    ///
+    /// Other than the normal of the surface, this normal always points towards
+    /// the front side of the face. Returns `None`, if the surface is degenerate
+    /// at the provided point.
+    ///
+    /// See [`SurfaceGeom`].
+    ///
+    /// [`SurfaceGeom`]: crate::geometry::SurfaceGeom
+    pub fn normal_at(
+        &self,
+        point: impl Into<Point<2>>,
+        geometry: &Geometry,
+    ) -> Option<Vector<3>> {
+        let normal = geometry.of_surface(&self.surface).normal_at(point)?;
+
+        match self.coord_handedness(geometry) {
+            Handedness::RightHanded => Some(normal),
+            Handedness::LeftHanded => Some(-normal),
+        }
+    }
+
+    /// Compute the curvature at the provided surface coordinates
+    ///
+    /// The curvature is positive, where the face curves away from its front
+    /// side, like the faces of a spherical solid. Returns `None`, if the
+    /// surface is degenerate at the provided point.
+    ///
+    /// See [`SurfaceGeom`].
+    ///
+    /// [`SurfaceGeom`]: crate::geometry::SurfaceGeom
+    pub fn curvature_at(
+        &self,
+        point: impl Into<Point<2>>,
+        geometry: &Geometry,
+    ) -> Option<Curvature> {
+        let curvature =
+            geometry.of_surface(&self.surface).curvature_at(point)?;
+
+        match self.coord_handedness(geometry) {
+            Handedness::RightHanded => Some(curvature),
+            Handedness::LeftHanded => Some(curvature.reverse()),
+        }
+    }
 }
 
 /// The handedness of a face's coordinate system
@@ -81,3 +154,54 @@ pub enum Handedness {
     /// The face's coordinate system is right-handed
     RightHanded,
 }
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Scalar, Vector};
+
+    use crate::{
+        operations::{
+            build::{BuildCycle, BuildFace},
+            insert::Insert,
+            reverse::Reverse,
+            update::{UpdateFace, UpdateRegion},
+        },
+        topology::{Cycle, Face},
+        Core,
+    };
+
+    #[test]
+    fn normal_and_curvature_respect_orientation() {
+        let mut core = Core::new();
+
+        let surface = core.layers.topology.surfaces.xy_plane();
+        let face = Face::unbound(surface.clone(), &mut core).update_region(
+            |region, core| {
+                region.update_exterior(
+                    |_, core| {
+                        Cycle::polygon(
+                            [[0., 0.], [1., 0.], [0., 1.]],
+                            surface,
+                            core,
+                        )
+                    },
+                    core,
+                )
+            },
+            &mut core,
+        );
+        let reversed = face.reverse(&mut core).insert(&mut core);
+        let face = face.insert(&mut core);
+
+        let point = [0.25, 0.25];
+
+        let normal = face.normal_at(point, &core.layers.geometry);
+        let normal_reversed = reversed.normal_at(point, &core.layers.geometry);
+        assert_eq!(normal, Some(Vector::unit_z()));
+        assert_eq!(normal_reversed, Some(-Vector::unit_z()));
+
+        let curvature =
+            face.curvature_at(point, &core.layers.geometry).unwrap();
+        assert_eq!(curvature.principal, [Scalar::ZERO; 2]);
+    }
+}
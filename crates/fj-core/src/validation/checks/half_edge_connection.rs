@@ -1,7 +1,7 @@
 use fj_math::{Point, Scalar};
 
 use crate::{
-    geometry::Geometry,
+    geometry::{Geometry, SurfaceGeom},
     storage::Handle,
     topology::{Cycle, Face, HalfEdge, Region, Sketch},
     validation::{validation_check::ValidationCheck, ValidationConfig},
@@ -17,6 +17,11 @@ use crate::{
 /// However, the *position* of that shared vertex is redundantly defined in both
 /// [`HalfEdge`]s. This check verifies that both positions are the same.
 ///
+/// Within a [`Face`], the positions may also differ, if the surface is
+/// degenerate between them, meaning that both positions, as well as any
+/// position in between, refer to the same point. This is the case at the apex
+/// of a cone, for example, or at the poles of a sphere.
+///
 /// ## Implementation Note
 ///
 /// Having the vertex positions redundantly defined is not desirable, but
@@ -63,7 +68,11 @@ impl ValidationCheck<Face> for AdjacentHalfEdgesNotConnected {
         geometry: &'r Geometry,
         config: &'r ValidationConfig,
     ) -> impl Iterator<Item = Self> + 'r {
-        check_region(object.region(), geometry, config)
+        // Faces in 2D space have no surface geometry that could be degenerate.
+        let surface = (!geometry.is_space_2d(object.surface()))
+            .then(|| geometry.of_surface(object.surface()));
+
+        check_region(object.region(), surface, geometry, config)
     }
 }
 
@@ -76,63 +85,89 @@ impl ValidationCheck<Sketch> for AdjacentHalfEdgesNotConnected {
         object
             .regions()
             .iter()
-            .flat_map(|region| check_region(region, geometry, config))
+            .flat_map(|region| check_region(region, None, geometry, config))
     }
 }
 
 fn check_region<'r>(
     region: &'r Region,
+    surface: Option<&'r SurfaceGeom>,
     geometry: &'r Geometry,
     config: &'r ValidationConfig,
 ) -> impl Iterator<Item = AdjacentHalfEdgesNotConnected> + 'r {
     [region.exterior()]
         .into_iter()
         .chain(region.interiors())
-        .flat_map(|cycle| check_cycle(cycle, geometry, config))
+        .flat_map(move |cycle| check_cycle(cycle, surface, geometry, config))
 }
 
 fn check_cycle<'r>(
     cycle: &'r Cycle,
+    surface: Option<&'r SurfaceGeom>,
     geometry: &'r Geometry,
     config: &'r ValidationConfig,
 ) -> impl Iterator<Item = AdjacentHalfEdgesNotConnected> + 'r {
-    cycle.half_edges().pairs().filter_map(|(first, second)| {
-        let end_pos_of_first_half_edge = {
-            let [_, end] = geometry.of_half_edge(first).boundary.inner;
-            geometry
-                .of_half_edge(first)
-                .path
-                .point_from_path_coords(end)
-        };
-        let start_pos_of_second_half_edge =
-            geometry.of_half_edge(second).start_position();
-
-        let distance_between_positions = (end_pos_of_first_half_edge
-            - start_pos_of_second_half_edge)
-            .magnitude();
-
-        if distance_between_positions > config.identical_max_distance {
-            return Some(AdjacentHalfEdgesNotConnected {
-                end_pos_of_first_half_edge,
-                start_pos_of_second_half_edge,
-                distance_between_positions,
-                unconnected_half_edges: [first.clone(), second.clone()],
+    cycle
+        .half_edges()
+        .pairs()
+        .filter_map(move |(first, second)| {
+            let end_pos_of_first_half_edge = {
+                let [_, end] = geometry.of_half_edge(first).boundary.inner;
+                geometry
+                    .of_half_edge(first)
+                    .path
+                    .point_from_path_coords(end)
+            };
+            let start_pos_of_second_half_edge =
+                geometry.of_half_edge(second).start_position();
+
+            let distance_between_positions = (end_pos_of_first_half_edge
+                - start_pos_of_second_half_edge)
+                .magnitude();
+
+            let is_degenerate = surface.is_some_and(|surface| {
+                let [a, b, middle] = [
+                    end_pos_of_first_half_edge,
+                    start_pos_of_second_half_edge,
+                    end_pos_of_first_half_edge
+                        + (start_pos_of_second_half_edge
+                            - end_pos_of_first_half_edge)
+                            / 2.,
+                ]
+                .map(|point| surface.point_from_surface_coords(point));
+
+                [b, middle].into_iter().all(|point| {
+                    (point - a).magnitude() <= config.identical_max_distance
+                })
             });
-        }
 
-        None
-    })
+            if distance_between_positions > config.identical_max_distance
+                && !is_degenerate
+            {
+                return Some(AdjacentHalfEdgesNotConnected {
+                    end_pos_of_first_half_edge,
+                    start_pos_of_second_half_edge,
+                    distance_between_positions,
+                    unconnected_half_edges: [first.clone(), second.clone()],
+                });
+            }
+
+            None
+        })
 }
 
 #[cfg(test)]
 mod tests {
 
+    use fj_math::Scalar;
+
     use crate::{
         operations::{
-            build::{BuildFace, BuildHalfEdge},
+            build::{BuildFace, BuildHalfEdge, BuildSurface},
+            insert::Insert,
             update::{UpdateCycle, UpdateFace, UpdateRegion},
         },
-        topology::{Face, HalfEdge},
+        topology::{Cycle, Face, HalfEdge, Region, Surface},
         validation::ValidationCheck,
         Core,
     };
@@ -186,4 +221,44 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn adjacent_half_edges_connected_by_degenerate_surface(
+    ) -> anyhow::Result<()> {
+        let mut core = Core::new();
+
+        let surface = Surface::cone([0., 0., 0.], Scalar::PI / 4., &mut core);
+
+        // Faces whose exterior is made up of three of the four sides of a
+        // rectangle, in surface coordinates. The gap is only valid, if it is
+        // located at the apex of the cone.
+        let face = |[u0, u1]: [f64; 2], core: &mut Core| {
+            let half_edges = [
+                [[u0, 0.], [u1, 0.]],
+                [[u1, 0.], [u1, 0.5]],
+                [[u1, 0.5], [u0, 0.5]],
+            ]
+            .map(|points| {
+                HalfEdge::line_segment(points, None, surface.clone(), core)
+                    .insert(core)
+            });
+            let exterior = Cycle::new(half_edges).insert(core);
+
+            Face::new(surface.clone(), Region::new(exterior, []).insert(core))
+        };
+
+        let valid = face([0., 1.], &mut core);
+        AdjacentHalfEdgesNotConnected::check_and_return_first_error(
+            &valid,
+            &core.layers.geometry,
+        )?;
+
+        let invalid = face([1., 2.], &mut core);
+        AdjacentHalfEdgesNotConnected::check_and_expect_one_error(
+            &invalid,
+            &core.layers.geometry,
+        );
+
+        Ok(())
+    }
 }
@@ -1,5 +1,3 @@
-use approx::AbsDiffEq;
-
 use crate::{Aabb, Point, Scalar, Vector};
 
 /// An n-dimensional circle
@@ -33,27 +31,44 @@ impl<const D: usize> Circle<D> {
         let a = a.into();
         let b = b.into();
 
-        assert_eq!(
-            a.magnitude(),
-            b.magnitude(),
-            "`a` and `b` must be of equal length"
-        );
         assert_ne!(
             a.magnitude(),
             Scalar::ZERO,
             "circle radius must not be zero"
         );
-        // Requiring the vector to be *precisely* perpendicular is not
-        // practical, because of numerical inaccuracy. This epsilon value seems
-        // seems to work for now, but maybe it needs to become configurable.
         assert!(
-            a.dot(&b) < Scalar::default_epsilon(),
+            Self::have_equal_length(a, b),
+            "`a` and `b` must be of equal length"
+        );
+        assert!(
+            Self::are_perpendicular(a, b),
             "`a` and `b` must be perpendicular to each other"
         );
 
         Self { center, a, b }
     }
 
+    /// Construct a circle, if the provided vectors define one
+    ///
+    /// Returns `None` in all cases where [`Circle::new`] would panic. This is
+    /// useful for code that computes the vectors, and needs to fall back to
+    /// another representation (like an ellipse), if they don't end up
+    /// defining a circle.
+    pub fn try_new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Option<Self> {
+        let a = a.into();
+        let b = b.into();
+
+        let is_circle = a.magnitude() != Scalar::ZERO
+            && Self::have_equal_length(a, b)
+            && Self::are_perpendicular(a, b);
+
+        is_circle.then(|| Self::new(center, a, b))
+    }
+
     /// Construct a `Circle` from a center point and a radius
     pub fn from_center_and_radius(
         center: impl Into<Point<D>>,
@@ -163,6 +178,27 @@ impl<const D: usize> Circle<D> {
     }
 }
 
+impl<const D: usize> Circle<D> {
+    /// The precision used to check the vectors that define a circle
+    ///
+    /// Requiring the vectors to be *precisely* of equal length and
+    /// perpendicular is not practical. Vectors that result from rotations or
+    /// projections might be off in the last few bits. This is relative to the
+    /// length of the vectors, so the check works the same for circles of any
+    /// size.
+    const RELATIVE_EPSILON: f64 = f64::EPSILON * 16.;
+
+    fn have_equal_length(a: Vector<D>, b: Vector<D>) -> bool {
+        let [a, b] = [a, b].map(|vector| vector.magnitude());
+        (a - b).abs() <= a.max(b) * Self::RELATIVE_EPSILON
+    }
+
+    fn are_perpendicular(a: Vector<D>, b: Vector<D>) -> bool {
+        a.dot(&b).abs()
+            <= a.magnitude() * b.magnitude() * Self::RELATIVE_EPSILON
+    }
+}
+
 impl<const D: usize> approx::AbsDiffEq for Circle<D> {
     type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
 
@@ -181,7 +217,7 @@ impl<const D: usize> approx::AbsDiffEq for Circle<D> {
 mod tests {
     use std::f64::consts::{FRAC_PI_2, PI};
 
-    use crate::{Point, Vector};
+    use crate::{Point, Scalar, Transform, Vector};
 
     use super::Circle;
 
@@ -210,4 +246,35 @@ mod tests {
             Point::from([FRAC_PI_2 * 3.]),
         );
     }
+
+    #[test]
+    fn try_new() {
+        let center = Point::from([0., 0., 0.]);
+
+        // Vectors that are rotated by a full turn should end up where they
+        // started, but are slightly off due to numerical inaccuracy. They must
+        // still define a circle, regardless of its size.
+        let rotation = Transform::rotation(
+            Vector::from([1., 2., 3.]).normalize() * Scalar::TAU,
+        );
+        for radius in [1e-6, 1., 1e6] {
+            let [a, b] = [Vector::unit_x(), Vector::unit_y()]
+                .map(|vector| rotation.transform_vector(&(vector * radius)));
+            assert!(Circle::try_new(center, a, b).is_some());
+        }
+
+        // Vectors that are clearly different in length, or not perpendicular,
+        // don't define a circle.
+        let a = Vector::from([1., 0., 0.]);
+        for b in [[0., 1.001, 0.], [-0.001, 1., 0.], [0.001, 1., 0.]] {
+            assert!(Circle::try_new(center, a, b).is_none());
+        }
+        assert!(Circle::try_new(center, [0., 0., 0.], [0., 0., 0.]).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_with_vectors_of_different_length() {
+        Circle::new([0., 0.], [1., 0.], [0., 1. + 1e-9]);
+    }
 }
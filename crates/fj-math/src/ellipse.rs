@@ -0,0 +1,325 @@
+use crate::{Aabb, Circle, Point, Scalar, Vector};
+
+/// An n-dimensional ellipse
+///
+/// The ellipse is defined by its center and two vectors, `a` and `b`, which
+/// are conjugate semi-diameters of the ellipse. Unlike with [`Circle`], those
+/// vectors don't need to be perpendicular, or of equal length.
+///
+/// The ellipse uses the same coordinate system as [`Circle`]. This makes an
+/// ellipse the result of applying an affine transform to a circle, without
+/// changing its coordinates.
+///
+/// The dimensionality of the ellipse is defined by the const generic `D`
+/// parameter.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Ellipse<const D: usize> {
+    center: Point<D>,
+    a: Vector<D>,
+    b: Vector<D>,
+}
+
+impl<const D: usize> Ellipse<D> {
+    /// Construct an ellipse
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `a` or `b` are zero, or if they are parallel.
+    pub fn new(
+        center: impl Into<Point<D>>,
+        a: impl Into<Vector<D>>,
+        b: impl Into<Vector<D>>,
+    ) -> Self {
+        let center = center.into();
+        let a = a.into();
+        let b = b.into();
+
+        assert!(
+            a.magnitude() != Scalar::ZERO && b.magnitude() != Scalar::ZERO,
+            "`a` and `b` must not be zero"
+        );
+        assert!(
+            a.dot(&b).abs() < a.magnitude() * b.magnitude(),
+            "`a` and `b` must not be parallel"
+        );
+
+        Self { center, a, b }
+    }
+
+    /// Access the center point of the ellipse
+    pub fn center(&self) -> Point<D> {
+        self.center
+    }
+
+    /// Access the vector that defines the starting point of the ellipse
+    ///
+    /// The point where this vector points from the ellipse center, is the
+    /// zero coordinate of the ellipse's coordinate system.
+    pub fn a(&self) -> Vector<D> {
+        self.a
+    }
+
+    /// Access the vector that defines the direction of the ellipse
+    ///
+    /// This is where the vector points, a quarter turn into the ellipse's
+    /// coordinate system.
+    pub fn b(&self) -> Vector<D> {
+        self.b
+    }
+
+    /// Compute the largest distance of any point on the ellipse from its center
+    pub fn max_radius(&self) -> Scalar {
+        // The squared distance of a point from the center is a quadratic form
+        // of `[cos(t), sin(t)]`. Its maximum is the larger eigenvalue of the
+        // form's matrix.
+        let aa = self.a.dot(&self.a);
+        let ab = self.a.dot(&self.b);
+        let bb = self.b.dot(&self.b);
+
+        let mean = (aa + bb) / 2.;
+        let half_difference = (aa - bb) / 2.;
+        let offset = (half_difference * half_difference + ab * ab)
+            .into_f64()
+            .sqrt();
+
+        Scalar::from((mean.into_f64() + offset).sqrt())
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.b = -self.b;
+        self
+    }
+
+    /// Convert a `D`-dimensional point into ellipse coordinates
+    ///
+    /// Returns the coordinate of the point on the ellipse, that is closest to
+    /// the provided point. Points outside of the ellipse's plane are projected
+    /// into that plane first.
+    pub fn point_to_ellipse_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<1> {
+        let vector = point.into() - self.center;
+
+        // Rotate the coordinate system, until `a` and `b` are the principal
+        // semi-axes of the ellipse. In the coordinates of those axes, the
+        // closest point can be computed directly.
+        let aa = self.a.dot(&self.a);
+        let ab = self.a.dot(&self.b);
+        let bb = self.b.dot(&self.b);
+        let offset = Scalar::atan2(ab * 2., aa - bb) / 2.;
+        let (sin, cos) = offset.sin_cos();
+        let axes = [self.a * cos + self.b * sin, self.b * cos - self.a * sin];
+
+        let [e0, e1] = axes.map(|axis| axis.magnitude().into_f64());
+        let [y0, y1] = [(axes[0], e0), (axes[1], e1)]
+            .map(|(axis, length)| vector.dot(&axis).into_f64() / length);
+
+        let [x0, x1] = if e0 >= e1 {
+            closest_point_in_first_quadrant([e0, e1], [y0.abs(), y1.abs()])
+        } else {
+            let [x1, x0] =
+                closest_point_in_first_quadrant([e1, e0], [y1.abs(), y0.abs()]);
+            [x0, x1]
+        };
+
+        let angle = Scalar::atan2(
+            Scalar::from((x1 / e1).copysign(y1)),
+            Scalar::from((x0 / e0).copysign(y0)),
+        ) + offset;
+        let coord = angle - Scalar::TAU * (angle / Scalar::TAU).floor();
+
+        Point::from([coord])
+    }
+
+    /// Convert a point in ellipse coordinates into a `D`-dimensional point
+    pub fn point_from_ellipse_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        self.center + self.vector_from_ellipse_coords(point.into().coords)
+    }
+
+    /// Convert a vector in ellipse coordinates into a `D`-dimensional vector
+    pub fn vector_from_ellipse_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<D> {
+        let angle = vector.into().t;
+        let (sin, cos) = angle.sin_cos();
+
+        self.a * cos + self.b * sin
+    }
+
+    /// Calculate an AABB for the ellipse
+    pub fn aabb(&self) -> Aabb<D> {
+        // Along each axis, the extent of the ellipse is the magnitude of
+        // `a * cos(t) + b * sin(t)`, projected onto that axis.
+        let mut center_to_min_max = [Scalar::ZERO; D];
+        for (i, extent) in center_to_min_max.iter_mut().enumerate() {
+            let a = self.a.components[i];
+            let b = self.b.components[i];
+
+            *extent = Scalar::from((a * a + b * b).into_f64().sqrt());
+        }
+        let center_to_min_max = Vector::from(center_to_min_max);
+
+        Aabb {
+            min: self.center - center_to_min_max,
+            max: self.center + center_to_min_max,
+        }
+    }
+}
+
+/// Compute the closest point on an axis-aligned ellipse
+///
+/// Expects the semi-axes `[e0, e1]`, with `e0 >= e1`, and a point `[y0, y1]`
+/// in the first quadrant. The closest point is also located in the first
+/// quadrant.
+///
+/// This is the method described by David Eberly, in "Distance from a Point to
+/// an Ellipse, an Ellipsoid, or a Hyperellipsoid". It finds the root of a
+/// monotonic function by bisection, which is exact up to floating point
+/// precision.
+fn closest_point_in_first_quadrant(
+    [e0, e1]: [f64; 2],
+    [y0, y1]: [f64; 2],
+) -> [f64; 2] {
+    if y1 > 0. {
+        if y0 == 0. {
+            return [0., e1];
+        }
+
+        let [z0, z1] = [y0 / e0, y1 / e1];
+        let g = z0 * z0 + z1 * z1 - 1.;
+        if g == 0. {
+            return [y0, y1];
+        }
+
+        let r0 = (e0 / e1) * (e0 / e1);
+        let n0 = r0 * z0;
+
+        let mut s0 = z1 - 1.;
+        let mut s1 = if g < 0. {
+            0.
+        } else {
+            (n0 * n0 + z1 * z1).sqrt() - 1.
+        };
+        let mut s = s0;
+
+        // Every iteration halves the interval. Even starting from the largest
+        // possible one, it collapses to neighboring floating point numbers well
+        // within this limit.
+        for _ in 0..2048 {
+            s = (s0 + s1) / 2.;
+            if s == s0 || s == s1 {
+                break;
+            }
+
+            let [ratio0, ratio1] = [n0 / (s + r0), z1 / (s + 1.)];
+            let g = ratio0 * ratio0 + ratio1 * ratio1 - 1.;
+            if g > 0. {
+                s0 = s;
+            } else if g < 0. {
+                s1 = s;
+            } else {
+                break;
+            }
+        }
+
+        return [r0 * y0 / (s + r0), y1 / (s + 1.)];
+    }
+
+    let numerator = e0 * y0;
+    let denominator = e0 * e0 - e1 * e1;
+    if numerator < denominator {
+        let x0 = numerator / denominator;
+        [e0 * x0, e1 * (1. - x0 * x0).sqrt()]
+    } else {
+        [e0, 0.]
+    }
+}
+
+impl<const D: usize> From<Circle<D>> for Ellipse<D> {
+    fn from(circle: Circle<D>) -> Self {
+        Self {
+            center: circle.center(),
+            a: circle.a(),
+            b: circle.b(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar, Vector};
+
+    use super::Ellipse;
+
+    #[test]
+    fn max_radius_and_aabb() {
+        let ellipse = Ellipse::new(
+            Point::from([1., 1.]),
+            Vector::from([3., 0.]),
+            Vector::from([1., 1.]),
+        );
+
+        let max_radius = (0..360)
+            .map(|i| {
+                let t = Scalar::TAU * Scalar::from_u64(i) / 360.;
+                ellipse.vector_from_ellipse_coords([t]).magnitude()
+            })
+            .max()
+            .unwrap();
+        assert!(ellipse.max_radius() >= max_radius);
+        assert!(ellipse.max_radius() - max_radius < Scalar::from(1e-3));
+
+        let aabb = ellipse.aabb();
+        assert_eq!(aabb.min, Point::from([1. - 10_f64.sqrt(), 0.]));
+        assert_eq!(aabb.max, Point::from([1. + 10_f64.sqrt(), 2.]));
+    }
+
+    #[test]
+    fn point_to_ellipse_coords() {
+        let ellipse = Ellipse::new(
+            Point::from([1., 1.]),
+            Vector::from([3., 0.]),
+            Vector::from([1., 1.]),
+        );
+
+        // Points on the ellipse map to their own coordinates.
+        for t in [0., 1., 2.5, 4., 6.] {
+            let point = ellipse.point_from_ellipse_coords([t]);
+            let coord = ellipse.point_to_ellipse_coords(point);
+            assert!((coord.t - t).abs() < Scalar::from(1e-12), "{coord:?}");
+        }
+
+        // Other points map to the closest point on the ellipse. There, the
+        // direction towards the point is perpendicular to the ellipse.
+        for point in [[5., 3.], [1.2, 0.9], [-4., 0.], [1., 1.]] {
+            let point = Point::from(point);
+            let t = ellipse.point_to_ellipse_coords(point).t;
+
+            let closest = ellipse.point_from_ellipse_coords([t]);
+            let tangent =
+                ellipse.vector_from_ellipse_coords([t + Scalar::PI / 2.]);
+            let to_point = point - closest;
+            assert!(
+                to_point.dot(&tangent).abs() < Scalar::from(1e-12),
+                "{point:?}"
+            );
+
+            let distance = to_point.magnitude();
+            let min_distance = (0..3600)
+                .map(|i| {
+                    let t = Scalar::TAU * Scalar::from_u64(i) / 3600.;
+                    (point - ellipse.point_from_ellipse_coords([t])).magnitude()
+                })
+                .min()
+                .unwrap();
+            assert!(distance <= min_distance, "{point:?}");
+        }
+    }
+}
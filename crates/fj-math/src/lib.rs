@@ -35,12 +35,14 @@ mod aabb;
 mod arc;
 mod circle;
 mod coordinates;
+mod ellipse;
 mod line;
 mod plane;
 mod point;
 mod poly_chain;
 mod scalar;
 mod segment;
+mod spline;
 mod transform;
 mod triangle;
 mod vector;
@@ -50,12 +52,14 @@ pub use self::{
     arc::Arc,
     circle::Circle,
     coordinates::{Uv, Xyz, T},
+    ellipse::Ellipse,
     line::Line,
     plane::Plane,
     point::Point,
     poly_chain::PolyChain,
     scalar::{Scalar, Sign},
     segment::Segment,
+    spline::Spline,
     transform::Transform,
     triangle::{Triangle, Winding},
     vector::Vector,
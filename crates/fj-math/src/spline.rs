@@ -0,0 +1,521 @@
+use crate::{Point, Scalar, Vector};
+
+/// An n-dimensional spline
+///
+/// A non-uniform rational B-spline (NURBS), defined by its degree, control
+/// points, weights, and knot vector. If all weights are equal, the spline is a
+/// regular (non-rational) B-spline.
+///
+/// The dimensionality of the spline is defined by the const generic `D`
+/// parameter.
+///
+/// The spline is defined within the range of its knot vector that is covered
+/// by a full set of basis functions. See [`Spline::domain`]. Outside of that
+/// range, the polynomial pieces at either end of the spline are continued.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Spline<const D: usize> {
+    degree: usize,
+    control_points: Vec<Point<D>>,
+    weights: Vec<Scalar>,
+    knots: Vec<Scalar>,
+}
+
+impl<const D: usize> Spline<D> {
+    /// Construct a spline
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - The degree must be at least `1`.
+    /// - There must be more control points than the degree.
+    /// - There must be one weight per control point, and all weights must be
+    ///   positive.
+    /// - The number of knots must equal the number of control points plus the
+    ///   degree plus one.
+    /// - The knots must not be decreasing, and the domain of the spline must
+    ///   not be empty.
+    pub fn new(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+        weights: impl IntoIterator<Item = impl Into<Scalar>>,
+        knots: impl IntoIterator<Item = impl Into<Scalar>>,
+    ) -> Self {
+        let control_points = control_points
+            .into_iter()
+            .map(Into::into)
+            .collect::<Vec<_>>();
+        let weights = weights.into_iter().map(Into::into).collect::<Vec<_>>();
+        let knots = knots.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        assert!(degree >= 1, "spline degree must be at least 1");
+        assert!(
+            control_points.len() > degree,
+            "spline must have more control points than its degree"
+        );
+        assert_eq!(
+            weights.len(),
+            control_points.len(),
+            "spline must have one weight per control point"
+        );
+        assert!(
+            weights.iter().all(|&weight| weight > Scalar::ZERO),
+            "spline weights must be positive"
+        );
+        assert_eq!(
+            knots.len(),
+            control_points.len() + degree + 1,
+            "spline must have `control_points + degree + 1` knots"
+        );
+        assert!(
+            knots.windows(2).all(|knots| knots[0] <= knots[1]),
+            "spline knots must not be decreasing"
+        );
+
+        let self_ = Self {
+            degree,
+            control_points,
+            weights,
+            knots,
+        };
+
+        let [start, end] = self_.domain();
+        assert!(start < end, "spline domain must not be empty");
+
+        self_
+    }
+
+    /// Construct a non-rational spline from its control points
+    ///
+    /// Uses a clamped and uniform knot vector, which means that the spline
+    /// starts at the first control point and ends at the last one. The domain
+    /// of the spline is `0` to `1`.
+    pub fn from_control_points(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Self {
+        Self::from_weighted_control_points(
+            degree,
+            control_points.into_iter().map(|point| (point, Scalar::ONE)),
+        )
+    }
+
+    /// Construct a rational spline from its control points and their weights
+    ///
+    /// Uses the same knot vector as [`Spline::from_control_points`].
+    pub fn from_weighted_control_points(
+        degree: usize,
+        control_points: impl IntoIterator<
+            Item = (impl Into<Point<D>>, impl Into<Scalar>),
+        >,
+    ) -> Self {
+        let (control_points, weights): (Vec<_>, Vec<_>) = control_points
+            .into_iter()
+            .map(|(point, weight)| (point.into(), weight.into()))
+            .unzip();
+
+        let num_spans = control_points.len().saturating_sub(degree).max(1);
+        let knots = (0..control_points.len() + degree + 1).map(|i| {
+            let i = i.saturating_sub(degree).min(num_spans);
+            Scalar::from(i as f64) / num_spans as f64
+        });
+
+        Self::new(degree, control_points, weights, knots.collect::<Vec<_>>())
+    }
+
+    /// Access the degree of the spline
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Access the control points of the spline
+    pub fn control_points(&self) -> &[Point<D>] {
+        &self.control_points
+    }
+
+    /// Access the weights of the control points
+    pub fn weights(&self) -> &[Scalar] {
+        &self.weights
+    }
+
+    /// Access the knot vector of the spline
+    pub fn knots(&self) -> &[Scalar] {
+        &self.knots
+    }
+
+    /// Access the domain of the spline
+    ///
+    /// This is the range of spline coordinates, within which the spline is
+    /// defined by its control points.
+    pub fn domain(&self) -> [Scalar; 2] {
+        [
+            self.knots[self.degree],
+            self.knots[self.control_points.len()],
+        ]
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// The new spline covers the same domain as the original one, but in the
+    /// opposite direction.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        let [start, end] = self.domain();
+
+        self.control_points.reverse();
+        self.weights.reverse();
+        self.knots.reverse();
+        for knot in &mut self.knots {
+            *knot = start + end - *knot;
+        }
+
+        self
+    }
+
+    /// Create a new instance that uses different spline coordinates
+    ///
+    /// The new spline passes through the same points as this one. Its
+    /// coordinates are an affine mapping of the original ones, which maps the
+    /// coordinates `from` to the coordinates `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if either of the ranges is empty.
+    #[must_use]
+    pub fn reparameterize(
+        self,
+        from: [impl Into<Scalar>; 2],
+        to: [impl Into<Scalar>; 2],
+    ) -> Self {
+        let [mut from_a, mut from_b] = from.map(Into::into);
+        let [to_a, to_b] = to.map(Into::into);
+
+        assert!(
+            from_a != from_b && to_a != to_b,
+            "can't reparameterize spline from or to empty range"
+        );
+
+        let mut self_ = self;
+        if (from_b - from_a).sign() != (to_b - to_a).sign() {
+            // The knots must not decrease. Reversing the spline takes care of
+            // that, but changes the coordinates we're mapping from.
+            let [start, end] = self_.domain();
+            self_ = self_.reverse();
+            [from_a, from_b] = [from_a, from_b].map(|t| start + end - t);
+        }
+
+        let scale = (to_b - to_a) / (from_b - from_a);
+        for knot in &mut self_.knots {
+            *knot = to_a + (*knot - from_a) * scale;
+        }
+
+        self_
+    }
+
+    /// Create a new spline by mapping the control points of this one
+    ///
+    /// The weights and knots of the spline are kept as they are. The resulting
+    /// spline is only the image of this one under the mapping, if the mapping
+    /// is affine (like a transform, or the conversion between a plane's
+    /// coordinates and global coordinates).
+    pub fn map_control_points<const T: usize>(
+        &self,
+        f: impl FnMut(Point<D>) -> Point<T>,
+    ) -> Spline<T> {
+        Spline {
+            degree: self.degree,
+            control_points: self
+                .control_points
+                .iter()
+                .copied()
+                .map(f)
+                .collect(),
+            weights: self.weights.clone(),
+            knots: self.knots.clone(),
+        }
+    }
+
+    /// Convert a point in spline coordinates into a `D`-dimensional point
+    pub fn point_from_spline_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let t = point.into().t;
+        let [(point, weight), _, _] = self.homogeneous_derivatives(t);
+
+        Point {
+            coords: point / weight,
+        }
+    }
+
+    /// Compute the first and second derivative at the provided spline coordinate
+    pub fn derivatives_at(&self, point: impl Into<Point<1>>) -> [Vector<D>; 2] {
+        let t = point.into().t;
+        let [(a, w), (a1, w1), (a2, w2)] = self.homogeneous_derivatives(t);
+
+        // The spline is the quotient of the homogeneous curve and the weight
+        // function. Apply the quotient rule to get its derivatives.
+        let point = a / w;
+        let first = (a1 - point * w1) / w;
+        let second = (a2 - first * w1 * 2. - point * w2) / w;
+
+        [first, second]
+    }
+
+    /// Evaluate the homogeneous curve and its first two derivatives
+    ///
+    /// Returns the weighted point and the weight for each.
+    fn homogeneous_derivatives(&self, t: Scalar) -> [(Vector<D>, Scalar); 3] {
+        let p = self.degree;
+        let k = self.span(t);
+
+        let points = (k - p..=k)
+            .map(|i| {
+                let weight = self.weights[i];
+                (self.control_points[i].coords * weight, weight)
+            })
+            .collect::<Vec<_>>();
+
+        // The derivative of a B-spline is a B-spline of a lower degree, whose
+        // control points are the scaled differences of the original ones.
+        let derive = |points: &[(Vector<D>, Scalar)], degree: usize, offset| {
+            points
+                .windows(2)
+                .enumerate()
+                .map(|(j, window)| {
+                    let i = k - p + j;
+                    let [(a, weight_a), (b, weight_b)] = [window[0], window[1]];
+                    let factor = Scalar::from_u64(degree as u64)
+                        / (self.knots[i + p + 1] - self.knots[i + offset]);
+
+                    ((b - a) * factor, (weight_b - weight_a) * factor)
+                })
+                .collect::<Vec<_>>()
+        };
+        let point = de_boor(&self.knots, p, k, points.clone(), t);
+
+        let first_points = derive(&points, p, 1);
+        let first =
+            de_boor(&self.knots[1..], p - 1, k - 1, first_points.clone(), t);
+        if p < 2 {
+            return [point, first, (Vector::default(), Scalar::ZERO)];
+        }
+
+        let second_points = derive(&first_points, p - 1, 2);
+        let second = de_boor(&self.knots[2..], p - 2, k - 2, second_points, t);
+
+        [point, first, second]
+    }
+
+    fn span(&self, t: Scalar) -> usize {
+        let p = self.degree;
+        let n = self.control_points.len();
+
+        (p..n)
+            .rev()
+            .find(|&k| self.knots[k] <= t && self.knots[k] < self.knots[k + 1])
+            .unwrap_or_else(|| {
+                (p..n)
+                    .find(|&k| self.knots[k] < self.knots[k + 1])
+                    .expect("Spline domain is not empty")
+            })
+    }
+}
+
+/// De Boor's algorithm, in homogeneous coordinates to account for the weights
+///
+/// `points` are the `degree + 1` control points that influence the span `k`.
+fn de_boor<const D: usize>(
+    knots: &[Scalar],
+    degree: usize,
+    k: usize,
+    mut points: Vec<(Vector<D>, Scalar)>,
+    t: Scalar,
+) -> (Vector<D>, Scalar) {
+    let p = degree;
+
+    for r in 1..=p {
+        for j in (r..=p).rev() {
+            let i = j + k - p;
+            let alpha = (t - knots[i]) / (knots[i + 1 + p - r] - knots[i]);
+
+            let (a, weight_a) = points[j - 1];
+            let (b, weight_b) = points[j];
+
+            points[j] = (
+                a * (Scalar::ONE - alpha) + b * alpha,
+                weight_a * (Scalar::ONE - alpha) + weight_b * alpha,
+            );
+        }
+    }
+
+    points[p]
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Point, Scalar};
+
+    use super::Spline;
+
+    #[test]
+    fn point_from_spline_coords() {
+        // A quadratic Bézier curve.
+        let spline = Spline::from_control_points(
+            2,
+            [[0., 0.], [1., 2.], [2., 0.]].map(Point::from),
+        );
+
+        assert_eq!(spline.domain(), [Scalar::ZERO, Scalar::ONE]);
+        assert_eq!(
+            spline.point_from_spline_coords([0.]),
+            Point::from([0., 0.])
+        );
+        assert_eq!(
+            spline.point_from_spline_coords([0.5]),
+            Point::from([1., 1.])
+        );
+        assert_eq!(
+            spline.point_from_spline_coords([1.]),
+            Point::from([2., 0.])
+        );
+    }
+
+    #[test]
+    fn point_from_rational_spline_coords() {
+        // A quarter circle, represented as a rational quadratic spline.
+        let weight = 0.5_f64.sqrt();
+        let spline = Spline::from_weighted_control_points(
+            2,
+            [([1., 0.], 1.), ([1., 1.], weight), ([0., 1.], 1.)],
+        );
+
+        for t in [0., 0.1, 0.25, 0.5, 0.75, 0.9, 1.] {
+            let point = spline.point_from_spline_coords([t]);
+            assert_abs_diff_eq!(
+                point.coords.magnitude(),
+                Scalar::ONE,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn point_from_coords_of_spline_with_multiple_spans() {
+        let points =
+            [[0., 0.], [1., 1.], [2., 0.], [3., 1.], [4., 0.]].map(Point::from);
+        let spline = Spline::from_control_points(3, points);
+
+        assert_eq!(spline.knots().len(), 9);
+        assert_eq!(spline.point_from_spline_coords([0.]), points[0]);
+        assert_eq!(spline.point_from_spline_coords([1.]), points[4]);
+
+        // The spline is symmetric around its center.
+        assert_abs_diff_eq!(
+            spline.point_from_spline_coords([0.5]),
+            Point::from([2., 0.5]),
+            epsilon = Scalar::from(1e-12)
+        );
+    }
+
+    #[test]
+    fn derivatives_at() {
+        // A quarter circle, represented as a rational quadratic spline. Its
+        // curvature must be that of a unit circle everywhere.
+        let weight = 0.5_f64.sqrt();
+        let circle = Spline::from_weighted_control_points(
+            2,
+            [([1., 0.], 1.), ([1., 1.], weight), ([0., 1.], 1.)],
+        );
+
+        for t in [0., 0.1, 0.5, 0.9, 1.] {
+            let point = circle.point_from_spline_coords([t]);
+            let [first, second] = circle.derivatives_at([t]);
+
+            let curvature = (first.u * second.v - first.v * second.u)
+                / (first.magnitude() * first.magnitude() * first.magnitude());
+
+            assert_abs_diff_eq!(
+                point.coords.dot(&first),
+                Scalar::ZERO,
+                epsilon = Scalar::from(1e-12)
+            );
+            assert_abs_diff_eq!(
+                curvature,
+                Scalar::ONE,
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+
+        // Compare the derivatives of a spline with multiple spans to finite
+        // differences.
+        let spline = Spline::from_control_points(
+            3,
+            [[0., 0.], [1., 2.], [2., -1.], [3., 3.], [4., 0.], [5., 1.]],
+        );
+
+        let h = 1e-4;
+        for t in [0.1, 0.4, 0.5, 0.8] {
+            let [before, at, after] =
+                [t - h, t, t + h].map(|t| spline.point_from_spline_coords([t]));
+            let [first, second] = spline.derivatives_at([t]);
+
+            assert_abs_diff_eq!(
+                first,
+                (after - before) / (2. * h),
+                epsilon = Scalar::from(1e-5)
+            );
+            assert_abs_diff_eq!(
+                second,
+                (after - at * 2. + before.coords) / (h * h),
+                epsilon = Scalar::from(1e-3)
+            );
+        }
+    }
+
+    #[test]
+    fn reparameterize() {
+        let spline = Spline::from_control_points(
+            2,
+            [[0., 0.], [1., 1.], [2., 0.], [3., 1.]].map(Point::from),
+        );
+
+        for (from, to) in [([0., 1.], [2., 4.]), ([0.25, 0.75], [1., -1.])] {
+            let reparameterized = spline.clone().reparameterize(from, to);
+
+            for [t, u] in [[from[0], to[0]], [from[1], to[1]]] {
+                assert_abs_diff_eq!(
+                    reparameterized.point_from_spline_coords([u]),
+                    spline.point_from_spline_coords([t]),
+                    epsilon = Scalar::from(1e-12)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn reverse() {
+        let spline = Spline::from_weighted_control_points(
+            3,
+            [
+                ([0., 0.], 1.),
+                ([1., 1.], 2.),
+                ([2., 0.], 0.5),
+                ([3., 1.], 1.),
+                ([4., 0.], 1.),
+            ],
+        );
+        let reversed = spline.clone().reverse();
+
+        assert_eq!(reversed.domain(), spline.domain());
+        for t in [0., 0.2, 0.5, 0.7, 1.] {
+            assert_abs_diff_eq!(
+                reversed.point_from_spline_coords([t]),
+                spline.point_from_spline_coords([1. - t]),
+                epsilon = Scalar::from(1e-12)
+            );
+        }
+    }
+}
@@ -2,7 +2,7 @@ use std::ops;
 
 use nalgebra::Perspective3;
 
-use crate::{Circle, Line, Scalar};
+use crate::{Circle, Ellipse, Line, Plane, Scalar, Spline};
 
 use super::{Aabb, Point, Segment, Triangle, Vector};
 
@@ -47,6 +47,59 @@ impl Transform {
         ))
     }
 
+    /// Construct a scaling with a separate factor for each axis
+    ///
+    /// Unless all factors are equal, this doesn't preserve angles. Circles
+    /// become ellipses, for example. See [`Transform::preserves_angles`].
+    pub fn scale_per_axis(scaling_factors: impl Into<Vector<3>>) -> Self {
+        let scaling_factors = scaling_factors.into();
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            nalgebra::OMatrix::new_nonuniform_scaling(&scaling_factors.to_na()),
+        ))
+    }
+
+    /// Construct a reflection across the provided plane
+    pub fn reflection(plane: &Plane) -> Self {
+        let normal = plane.normal().normalize().to_na();
+        let distance = plane.origin().coords.to_na().dot(&normal);
+
+        let linear =
+            nalgebra::Matrix3::identity() - normal * normal.transpose() * 2.;
+        let translation = normal * distance * 2.;
+
+        let mut matrix = linear.to_homogeneous();
+        matrix.fixed_view_mut::<3, 1>(0, 3).copy_from(&translation);
+
+        Self(nalgebra::Transform::from_matrix_unchecked(matrix))
+    }
+
+    /// Determine whether the transform reverses orientation
+    ///
+    /// This is the case for reflections, or scalings by a negative factor. An
+    /// object that is transformed by such a transform becomes its own mirror
+    /// image.
+    pub fn reverses_orientation(&self) -> bool {
+        self.0.matrix().fixed_view::<3, 3>(0, 0).determinant() < 0.
+    }
+
+    /// Determine whether the transform preserves angles
+    ///
+    /// This is the case, if the transform only consists of translations,
+    /// rotations, reflections, and uniform scaling. Such a transform maps
+    /// circles to circles.
+    pub fn preserves_angles(&self) -> bool {
+        let linear = self.0.matrix().fixed_view::<3, 3>(0, 0);
+        let product = linear.transpose() * linear;
+
+        // The product is a multiple of the identity matrix, if the transform
+        // preserves angles.
+        let scale = product.trace() / 3.;
+        let difference = product - nalgebra::Matrix3::identity() * scale;
+
+        difference.amax() <= scale * 1e-12
+    }
+
     /// Transform the given point
     pub fn transform_point(&self, point: &Point<3>) -> Point<3> {
         Point::from(self.0.transform_point(&point.to_na()))
@@ -87,6 +140,11 @@ impl Transform {
     }
 
     /// Transform the given circle
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the transform doesn't preserve angles, as the circle would
+    /// become an ellipse. Use [`Transform::transform_ellipse`] in that case.
     pub fn transform_circle(&self, circle: &Circle<3>) -> Circle<3> {
         Circle::new(
             self.transform_point(&circle.center()),
@@ -95,6 +153,22 @@ impl Transform {
         )
     }
 
+    /// Transform the given ellipse
+    pub fn transform_ellipse(&self, ellipse: &Ellipse<3>) -> Ellipse<3> {
+        Ellipse::new(
+            self.transform_point(&ellipse.center()),
+            self.transform_vector(&ellipse.a()),
+            self.transform_vector(&ellipse.b()),
+        )
+    }
+
+    /// Transform the given spline
+    pub fn transform_spline(&self, spline: &Spline<3>) -> Spline<3> {
+        // Splines are invariant under affine transformations. Transforming
+        // the control points is enough.
+        spline.map_control_points(|point| self.transform_point(&point))
+    }
+
     /// Inverse transform
     pub fn inverse(&self) -> Self {
         Self(self.0.inverse())
@@ -169,7 +243,7 @@ impl ops::Mul<Self> for Transform {
 mod tests {
     use approx::assert_abs_diff_eq;
 
-    use crate::{Line, Point, Scalar, Vector};
+    use crate::{Line, Plane, Point, Scalar, Vector};
 
     use super::Transform;
 
@@ -224,4 +298,38 @@ mod tests {
             epsilon = 1e-8,
         );
     }
+
+    #[test]
+    fn reflection() {
+        let plane = Plane::from_parametric(
+            Point::from([0., 0., 1.]),
+            Vector::from([1., 0., 0.]),
+            Vector::from([0., 2., 0.]),
+        );
+        let transform = Transform::reflection(&plane);
+
+        assert_abs_diff_eq!(
+            transform.transform_point(&Point::from([1., 2., 3.])),
+            Point::from([1., 2., -1.]),
+            epsilon = Scalar::from(1e-12)
+        );
+        assert!(transform.reverses_orientation());
+        assert!(!(transform * transform).reverses_orientation());
+        assert!(Transform::scale(-1.).reverses_orientation());
+        assert!(!Transform::rotation([0., 0., 1.]).reverses_orientation());
+    }
+
+    #[test]
+    fn scale_per_axis() {
+        let transform = Transform::scale_per_axis([1., 2., 3.]);
+
+        assert_eq!(
+            transform.transform_point(&Point::from([1., 1., 1.])),
+            Point::from([1., 2., 3.]),
+        );
+        assert!(!transform.preserves_angles());
+        assert!(Transform::scale_per_axis([2., 2., 2.]).preserves_angles());
+        assert!((Transform::rotation([0., 0., 1.]) * Transform::scale(3.))
+            .preserves_angles());
+    }
 }